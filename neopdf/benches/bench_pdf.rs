@@ -1,6 +1,10 @@
 use criterion::{criterion_group, criterion_main, Criterion};
 
+use neopdf::gridpdf::InterpScratch;
+use neopdf::interpolator::InterpolatorFactory;
+use neopdf::metadata::InterpolatorType;
 use neopdf::pdf::PDF;
+use neopdf::strategy::LogBicubicInterpolation;
 
 fn xfxq2(c: &mut Criterion) {
     let pdf = PDF::load("NNPDF40_nnlo_as_01180", 0);
@@ -10,6 +14,60 @@ fn xfxq2(c: &mut Criterion) {
     });
 }
 
+fn eval_into(c: &mut Criterion) {
+    let pdf = PDF::load("NNPDF40_nnlo_as_01180", 0);
+    let mut scratch = InterpScratch::new();
+
+    // Same query as `xfxq2`, but reusing the coordinate buffer instead of allocating it.
+    c.bench_function("eval_into", |b| {
+        b.iter(|| {
+            pdf.eval_into(
+                std::hint::black_box(21),
+                std::hint::black_box(1e-3),
+                std::hint::black_box(4.0),
+                &mut scratch,
+            )
+        })
+    });
+
+    c.bench_function("eval_all_into", |b| {
+        b.iter(|| {
+            pdf.eval_all_into(
+                std::hint::black_box(1e-3),
+                std::hint::black_box(4.0),
+                &mut scratch,
+            )
+            .len()
+        })
+    });
+
+    // Nearby queries, for which the subgrid and cell cached in the scratch buffer are reused.
+    let points: Vec<(f64, f64)> = (0..1_000)
+        .map(|i| {
+            (
+                1e-3 * (1.0 + 1e-3 * f64::from(i)),
+                4.0 + 1e-2 * f64::from(i),
+            )
+        })
+        .collect();
+    c.bench_function("xfxq2_scan", |b| {
+        b.iter(|| {
+            std::hint::black_box(&points)
+                .iter()
+                .map(|&(x, q2)| pdf.xfxq2(21, &[x, q2]))
+                .sum::<f64>()
+        })
+    });
+    c.bench_function("eval_into_scan", |b| {
+        b.iter(|| {
+            std::hint::black_box(&points)
+                .iter()
+                .map(|&(x, q2)| pdf.eval_into(21, x, q2, &mut scratch))
+                .sum::<f64>()
+        })
+    });
+}
+
 fn xfxq2_cheby(c: &mut Criterion) {
     let pdf = PDF::load("MAP22_grids_FF_Km_N3LL.neopdf.lz4", 0);
 
@@ -71,12 +129,72 @@ fn xfxq2_members(c: &mut Criterion) {
     });
 }
 
+fn subgrid_interpolate_batch(c: &mut Criterion) {
+    let pdf = PDF::load("NNPDF40_nnlo_as_01180", 0);
+    let subgrid = &pdf.subgrids()[1];
+    let points: Vec<[f64; 2]> = (0..10_000)
+        .map(|i| {
+            let x = 1e-5 * 1e4f64.powf(f64::from(i % 100) / 100.0);
+            let q2 = 10.0 * 1e2f64.powf(f64::from(i / 100) / 100.0);
+            [x, q2]
+        })
+        .collect();
+
+    let interpolator = InterpolatorFactory::create(InterpolatorType::LogBicubic, subgrid, 0);
+    c.bench_function("subgrid_interpolate_pointwise", |b| {
+        b.iter(|| {
+            std::hint::black_box(&points)
+                .iter()
+                .map(|&[x, q2]| interpolator.interpolate_point(&[x.ln(), q2.ln()]).unwrap())
+                .collect::<Vec<_>>()
+        })
+    });
+    c.bench_function("subgrid_interpolate_batch", |b| {
+        b.iter(|| {
+            subgrid
+                .interpolate_batch(0, std::hint::black_box(&points))
+                .unwrap()
+        })
+    });
+}
+
+fn bicubic_weights(c: &mut Criterion) {
+    let stencils: Vec<([[f64; 4]; 4], f64)> = (0..1_000)
+        .map(|i| {
+            let stencil = std::array::from_fn(|k| {
+                std::array::from_fn(|r| f64::from(i * 16 + k as i32 * 4 + r as i32).sin())
+            });
+            (stencil, f64::from(i) / 1_000.0)
+        })
+        .collect();
+
+    c.bench_function("bicubic_weights_scalar", |b| {
+        b.iter(|| {
+            std::hint::black_box(&stencils)
+                .iter()
+                .map(|(stencil, t)| LogBicubicInterpolation::apply_weights_scalar(stencil, *t))
+                .collect::<Vec<_>>()
+        })
+    });
+    c.bench_function("bicubic_weights", |b| {
+        b.iter(|| {
+            std::hint::black_box(&stencils)
+                .iter()
+                .map(|(stencil, t)| LogBicubicInterpolation::apply_weights(stencil, *t))
+                .collect::<Vec<_>>()
+        })
+    });
+}
+
 criterion_group!(
     benches,
     xfxq2,
+    eval_into,
     xfxq2s,
     xfxq2_members,
     xfxq2_cheby,
-    xfxq2_cheby_batch
+    xfxq2_cheby_batch,
+    subgrid_interpolate_batch,
+    bicubic_weights
 );
 criterion_main!(benches);
@@ -0,0 +1,113 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+
+use neopdf::interpolator::{InterpolationConfig, InterpolatorFactory};
+use neopdf::metadata::InterpolatorType;
+use neopdf::test_support::{synthetic_points, synthetic_subgrid};
+
+const SEED: u64 = 0x5EED;
+const NUM_POINTS: usize = 1_000;
+
+const CONFIGS: [(InterpolationConfig, InterpolatorType); 8] = [
+    (InterpolationConfig::TwoD, InterpolatorType::LogBicubic),
+    (
+        InterpolationConfig::ThreeDNucleons,
+        InterpolatorType::LogTricubic,
+    ),
+    (
+        InterpolationConfig::ThreeDAlphas,
+        InterpolatorType::LogTricubic,
+    ),
+    (InterpolationConfig::ThreeDKt, InterpolatorType::LogTricubic),
+    (
+        InterpolationConfig::FourDNucleonsAlphas,
+        InterpolatorType::InterpNDLinear,
+    ),
+    (
+        InterpolationConfig::FourDNucleonsKt,
+        InterpolatorType::InterpNDLinear,
+    ),
+    (
+        InterpolationConfig::FourDAlphasKt,
+        InterpolatorType::InterpNDLinear,
+    ),
+    (InterpolationConfig::FiveD, InterpolatorType::InterpNDLinear),
+];
+
+/// Converts the points to the coordinates expected by the interpolator: the `Log*`
+/// interpolators work with the logarithms of the coordinates, `InterpNDLinear` with the
+/// coordinates themselves.
+fn query_coords(interp_type: &InterpolatorType, points: Vec<Vec<f64>>) -> Vec<Vec<f64>> {
+    let use_log = matches!(
+        interp_type,
+        InterpolatorType::LogBilinear
+            | InterpolatorType::LogBicubic
+            | InterpolatorType::LogTricubic
+            | InterpolatorType::LogChebyshev
+    );
+
+    points
+        .into_iter()
+        .map(|point| {
+            if use_log {
+                point.into_iter().map(f64::ln).collect()
+            } else {
+                point
+            }
+        })
+        .collect()
+}
+
+fn interpolate_config(c: &mut Criterion) {
+    let mut single = c.benchmark_group("interpolate_point");
+    for (config, interp_type) in CONFIGS {
+        let subgrid = synthetic_subgrid(config, 2, SEED);
+        let point = query_coords(&interp_type, synthetic_points(config, 1, SEED)).remove(0);
+        let interpolator = InterpolatorFactory::create(interp_type, &subgrid, 0);
+
+        single.bench_function(BenchmarkId::from_parameter(format!("{config:?}")), |b| {
+            b.iter(|| {
+                interpolator
+                    .interpolate_point(std::hint::black_box(&point))
+                    .unwrap()
+            })
+        });
+    }
+    single.finish();
+
+    let mut batch = c.benchmark_group("interpolate_points");
+    for (config, interp_type) in CONFIGS {
+        let subgrid = synthetic_subgrid(config, 2, SEED);
+        let points = query_coords(&interp_type, synthetic_points(config, NUM_POINTS, SEED));
+        let interpolator = InterpolatorFactory::create(interp_type, &subgrid, 0);
+
+        batch.bench_function(BenchmarkId::from_parameter(format!("{config:?}")), |b| {
+            b.iter(|| {
+                std::hint::black_box(&points)
+                    .iter()
+                    .map(|point| interpolator.interpolate_point(point).unwrap())
+                    .sum::<f64>()
+            })
+        });
+    }
+    batch.finish();
+}
+
+fn interpolate_batch_2d(c: &mut Criterion) {
+    let subgrid = synthetic_subgrid(InterpolationConfig::TwoD, 2, SEED);
+    let points: Vec<[f64; 2]> = synthetic_points(InterpolationConfig::TwoD, NUM_POINTS, SEED)
+        .into_iter()
+        .map(|point| [point[0], point[1]])
+        .collect();
+
+    // The batched log-bicubic interpolation, which is only available for 2D subgrids.
+    c.bench_function("interpolate_batch/TwoD", |b| {
+        b.iter(|| {
+            subgrid
+                .interpolate_batch(0, std::hint::black_box(&points))
+                .unwrap()
+        })
+    });
+}
+
+criterion_group!(benches, interpolate_config, interpolate_batch_2d);
+criterion_main!(benches);
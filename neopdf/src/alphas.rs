@@ -8,8 +8,10 @@ use ninterp::prelude::*;
 use std::collections::HashMap;
 use thiserror::Error;
 
-use super::metadata::MetaData;
+use super::gridpdf::ExtrapolationPolicy;
+use super::metadata::{FlavorScheme, MetaData};
 use super::strategy::AlphaSCubicInterpolation;
+use super::utils::find_interval_index;
 
 /// Errors that can occur during the analytical computations of `alpha_s`.
 #[derive(Debug, Error)]
@@ -29,19 +31,31 @@ pub enum Error {
         /// Order to compute the Beta function.
         order: u32,
     },
+    /// Error indicating that no reference value is available to solve the RGE.
+    #[error("No reference value of alpha_s found to solve the RGE")]
+    ReferenceValueNotFound,
+    /// Error indicating that the scale is outside of the range of the `alpha_s` table.
+    #[error("Q2 value {q2} is outside of the alpha_s table")]
+    OutOfRange {
+        /// The energy scale squared.
+        q2: f64,
+    },
 }
 
 /// Enum representing the different methods for alpha_s calculation.
 pub enum AlphaS {
     Analytic(AlphaSAnalytic),
     Interpol(AlphaSInterpol),
+    Ode(AlphaSOde),
 }
 
 impl AlphaS {
     /// Creates a new `AlphaS` calculator from PDF metadata.
     pub fn from_metadata(meta: &MetaData) -> Result<Self, String> {
-        // TODO: Use `meta.alphas_type` for the logics.
-        if meta.alphas_vals.is_empty() {
+        // TODO: Use `meta.alphas_type` for the remaining logics.
+        if meta.alphas_type.to_lowercase() == "ode" {
+            Ok(AlphaS::Ode(AlphaSOde::from_metadata(meta)?))
+        } else if meta.alphas_vals.is_empty() {
             Ok(AlphaS::Analytic(AlphaSAnalytic::from_metadata(meta)?))
         } else {
             Ok(AlphaS::Interpol(AlphaSInterpol::from_metadata(meta)?))
@@ -53,19 +67,47 @@ impl AlphaS {
         match self {
             AlphaS::Analytic(analytic) => analytic.alphas_q2(q2),
             AlphaS::Interpol(interpol) => interpol.alphas_q2(q2),
+            AlphaS::Ode(ode) => ode.alphas_q2(q2),
         }
     }
 }
 
+/// Returns the maximum number of active flavors, given by the `NumFlavors` entry of the
+/// metadata, or six if the entry is missing.
+fn max_flavors(meta: &MetaData) -> u32 {
+    if meta.number_flavors == 0 {
+        6
+    } else {
+        meta.number_flavors
+    }
+}
+
+/// Computes the coefficients of the QCD beta function in the `LHAPDF` normalization.
+fn betas(bto: u32, nf: u32) -> Result<f64, Error> {
+    // Copied from https://gitlab.com/hepcedar/lhapdf/-/blob/main/src/AlphaS.cc
+    let nf = nf as f64;
+    let (nf2, nf3, nf4) = (nf * nf, nf * nf * nf, nf * nf * nf * nf);
+    match bto {
+        0 => Ok(0.875352187 - 0.053051647 * nf),
+        1 => Ok(0.6459225457 - 0.0802126037 * nf),
+        2 => Ok(0.719864327 - 0.140904490 * nf + 0.00303291339 * nf2),
+        3 => Ok(1.172686 - 0.2785458 * nf + 0.01624467 * nf2 + 0.0000601247 * nf3),
+        4 => Ok(1.714138 - 0.5940794 * nf + 0.05607482 * nf2
+            - 0.0007380571 * nf3
+            - 0.00000587968 * nf4),
+        _ => Err(Error::BetaOrderValueError { order: bto }),
+    }
+}
+
 /// Strong coupling calculator using the analytic formulas.
 pub struct AlphaSAnalytic {
     qcd_order: u32,
-    fl_scheme: String,
+    fl_scheme: FlavorScheme,
+    nf_max: u32,
     lambda_maps: HashMap<u32, f64>,
     mc_sq: f64,
     mb_sq: f64,
     mt_sq: f64,
-    num_fl: u32,
 }
 
 impl AlphaSAnalytic {
@@ -88,27 +130,28 @@ impl AlphaSAnalytic {
             mc_sq: meta.m_charm * meta.m_charm,
             mb_sq: meta.m_bottom * meta.m_bottom,
             mt_sq: meta.m_top * meta.m_top,
-            num_fl: meta.number_flavors,
-            fl_scheme: meta.flavor_scheme.clone(),
+            fl_scheme: meta.normalize_flavor_scheme(),
+            nf_max: max_flavors(meta),
         })
     }
 
     fn number_flavors_q2(&self, q2: f64) -> u32 {
-        match () {
-            _ if self.fl_scheme.to_uppercase() == "FIXED" => self.num_fl,
+        let nf = match self.fl_scheme {
+            FlavorScheme::FixedFlavorNumber(nf) => nf,
             _ if q2 > self.mt_sq && self.mt_sq > 0.0 => 6,
             _ if q2 > self.mb_sq && self.mb_sq > 0.0 => 5,
             _ if q2 > self.mc_sq && self.mc_sq > 0.0 => 4,
             _ => 3,
-        }
+        };
+        nf.min(self.nf_max)
     }
 
     fn lambda_qcd(&self, nf: u32) -> Result<f64, Error> {
         // NOTE: This is better be checked using `alphas_type`.
-        match self.fl_scheme.to_uppercase().as_str() {
-            "FIXED" => match self.lambda_maps.get(&self.num_fl) {
+        match self.fl_scheme {
+            FlavorScheme::FixedFlavorNumber(num_fl) => match self.lambda_maps.get(&num_fl) {
                 Some(lambda_value) => Ok(*lambda_value),
-                None => Err(Error::LambdaQCDValueNotFound { nf: self.num_fl }),
+                None => Err(Error::LambdaQCDValueNotFound { nf: num_fl }),
             },
             _ => {
                 if nf == 0 {
@@ -122,22 +165,6 @@ impl AlphaSAnalytic {
         }
     }
 
-    fn betas(&self, bto: u32, nf: u32) -> Result<f64, Error> {
-        // Copied from https://gitlab.com/hepcedar/lhapdf/-/blob/main/src/AlphaS.cc
-        let nf = nf as f64;
-        let (nf2, nf3, nf4) = (nf * nf, nf * nf * nf, nf * nf * nf * nf);
-        match bto {
-            0 => Ok(0.875352187 - 0.053051647 * nf),
-            1 => Ok(0.6459225457 - 0.0802126037 * nf),
-            2 => Ok(0.719864327 - 0.140904490 * nf + 0.00303291339 * nf2),
-            3 => Ok(1.172686 - 0.2785458 * nf + 0.01624467 * nf2 + 0.0000601247 * nf3),
-            4 => Ok(1.714138 - 0.5940794 * nf + 0.05607482 * nf2
-                - 0.0007380571 * nf3
-                - 0.00000587968 * nf4),
-            _ => Err(Error::BetaOrderValueError { order: bto }),
-        }
-    }
-
     /// Calculates alpha_s(Q2) using the analytic running formula.
     pub fn alphas_q2(&self, q2: f64) -> f64 {
         // Copied from https://gitlab.com/hepcedar/lhapdf/-/blob/main/src/AlphaS_Analytic.cc
@@ -155,8 +182,8 @@ impl AlphaSAnalytic {
         };
         let y = 1.0 / lnx;
 
-        let beta0 = self.betas(0, nf).unwrap();
-        let beta1 = self.betas(1, nf).unwrap();
+        let beta0 = betas(0, nf).unwrap();
+        let beta1 = betas(1, nf).unwrap();
         let (beta02, beta12) = (beta0 * beta0, beta1 * beta1);
         let prefac = 1.0 / beta0;
         let mut tmp = 1.0;
@@ -171,7 +198,7 @@ impl AlphaSAnalytic {
         }
 
         if self.qcd_order > 2 {
-            let beta2 = self.betas(2, nf).unwrap();
+            let beta2 = betas(2, nf).unwrap();
 
             let prefac_b = beta12 / (beta02 * beta02);
             let a_20 = lnlnx2 - lnlnx;
@@ -181,8 +208,8 @@ impl AlphaSAnalytic {
         }
 
         if self.qcd_order > 3 {
-            let beta2 = self.betas(2, nf).unwrap();
-            let beta3 = self.betas(3, nf).unwrap();
+            let beta2 = betas(2, nf).unwrap();
+            let beta3 = betas(3, nf).unwrap();
 
             let prefac_c = 1. / (beta02 * beta02 * beta02);
             let a_30 = (beta12 * beta1) * (lnlnx3 - (5.0 / 2.0) * lnlnx2 - 2.0 * lnlnx + 0.5);
@@ -195,12 +222,152 @@ impl AlphaSAnalytic {
     }
 }
 
+/// Strong coupling calculator solving the renormalization group equation (RGE).
+///
+/// The running is anchored to the reference value `AlphaS_MZ` given at the scale
+/// `AlphaS_MassReference` (which defaults to `MZ`). When the set does not provide
+/// `AlphaS_MZ`, the reference value is read off the `alpha_s` table at `MZ`.
+pub struct AlphaSOde {
+    qcd_order: u32,
+    q2_ref: f64,
+    alphas_ref: f64,
+    fl_scheme: FlavorScheme,
+    nf_max: u32,
+    mc_sq: f64,
+    mb_sq: f64,
+    mt_sq: f64,
+}
+
+impl AlphaSOde {
+    /// Maximum step size in `ln(Q^2)` used to integrate the RGE.
+    const MAX_STEP: f64 = 0.01;
+
+    pub fn from_metadata(meta: &MetaData) -> Result<Self, String> {
+        let m_ref =
+            meta.alphas_mass_reference()
+                .unwrap_or(if meta.m_z > 0.0 { meta.m_z } else { 91.1876 });
+        let q2_ref = m_ref * m_ref;
+
+        let alphas_ref = match meta.alphas_mz() {
+            Some(alphas_mz) => alphas_mz,
+            None if !meta.alphas_vals.is_empty() => {
+                AlphaSInterpol::from_metadata(meta)?.alphas_q2(q2_ref)
+            }
+            None => return Err(Error::ReferenceValueNotFound.to_string()),
+        };
+
+        // `AlphaS_OrderQCD` counts the loops beyond LO, as in `LHAPDF`.
+        let alphas_order_qcd = if meta.alphas_order_qcd == 0 {
+            meta.order_qcd
+        } else {
+            meta.alphas_order_qcd
+        };
+
+        Ok(Self {
+            qcd_order: alphas_order_qcd + 1,
+            q2_ref,
+            alphas_ref,
+            fl_scheme: meta.normalize_flavor_scheme(),
+            nf_max: max_flavors(meta),
+            mc_sq: meta.m_charm * meta.m_charm,
+            mb_sq: meta.m_bottom * meta.m_bottom,
+            mt_sq: meta.m_top * meta.m_top,
+        })
+    }
+
+    fn number_flavors_q2(&self, q2: f64) -> u32 {
+        let nf = match self.fl_scheme {
+            FlavorScheme::FixedFlavorNumber(nf) => nf,
+            _ if q2 > self.mt_sq && self.mt_sq > 0.0 => 6,
+            _ if q2 > self.mb_sq && self.mb_sq > 0.0 => 5,
+            _ if q2 > self.mc_sq && self.mc_sq > 0.0 => 4,
+            _ => 3,
+        };
+        nf.min(self.nf_max)
+    }
+
+    /// Computes the derivative `d alpha_s / d ln(Q^2)` for `nf` active flavors.
+    fn derivative(&self, alphas: f64, nf: u32) -> f64 {
+        -(0..self.qcd_order.min(5))
+            .map(|order| betas(order, nf).unwrap() * alphas.powi(order as i32 + 2))
+            .sum::<f64>()
+    }
+
+    /// Evolves `alphas` from `t0` to `t1` (in `ln(Q^2)`) with a fixed number of flavors
+    /// using the fourth-order Runge-Kutta method.
+    fn evolve(&self, alphas: f64, t0: f64, t1: f64, nf: u32) -> f64 {
+        let nsteps = ((t1 - t0).abs() / Self::MAX_STEP).ceil().max(1.0);
+        let h = (t1 - t0) / nsteps;
+
+        (0..nsteps as usize).fold(alphas, |a, _| {
+            let k1 = self.derivative(a, nf);
+            let k2 = self.derivative(a + 0.5 * h * k1, nf);
+            let k3 = self.derivative(a + 0.5 * h * k2, nf);
+            let k4 = self.derivative(a + h * k3, nf);
+            a + h * (k1 + 2.0 * k2 + 2.0 * k3 + k4) / 6.0
+        })
+    }
+
+    /// Calculates alpha_s(Q2) by integrating the RGE from the reference scale.
+    pub fn alphas_q2(&self, q2: f64) -> f64 {
+        if self.qcd_order == 0 {
+            return self.alphas_ref;
+        }
+
+        // Split the integration path at the flavor thresholds crossed between the scales.
+        let (lo, hi) = (self.q2_ref.min(q2), self.q2_ref.max(q2));
+        let mut knots: Vec<f64> = [self.mc_sq, self.mb_sq, self.mt_sq]
+            .into_iter()
+            .filter(|&m2| m2 > lo && m2 < hi)
+            .collect();
+        if q2 < self.q2_ref {
+            knots.reverse();
+        }
+        knots.push(q2);
+
+        let mut alphas = self.alphas_ref;
+        let mut q2_start = self.q2_ref;
+        for q2_end in knots {
+            let nf = self.number_flavors_q2(0.5 * (q2_start + q2_end));
+            alphas = self.evolve(alphas, q2_start.ln(), q2_end.ln(), nf);
+            q2_start = q2_end;
+        }
+
+        alphas
+    }
+}
+
+/// The minimum number of knots of the `alpha_s` table for the cubic interpolation.
+const MIN_CUBIC_KNOTS: usize = 4;
+
 /// Strong coupling calculator using interpolation.
+///
+/// Within the table, `alpha_s` is interpolated with cubic splines in `ln(Q2)` as in `LHAPDF`,
+/// or linearly in `ln(Q2)` for tables with fewer than four knots. Outside of the table, the
+/// result follows the extrapolation policy:
+///
+/// - [`ExtrapolationPolicy::LogLinear`] (the default) mirrors `LHAPDF`: `ln(alpha_s)` is
+///   extrapolated linearly in `ln(Q2)` below the table, and `alpha_s` is frozen above it.
+/// - [`ExtrapolationPolicy::Clamp`] freezes `alpha_s` at the closest end of the table.
+/// - [`ExtrapolationPolicy::Constant`] returns the fill value.
+/// - [`ExtrapolationPolicy::Nan`] returns `NaN`.
+/// - [`ExtrapolationPolicy::Error`] returns `Error::OutOfRange` from
+///   [`AlphaSInterpol::try_alphas_q2`].
 pub struct AlphaSInterpol {
-    interpolator: Interp1DOwned<f64, AlphaSCubicInterpolation>,
+    log_q2s: Vec<f64>,
+    alphas: Vec<f64>,
+    interpolator: Option<Interp1DOwned<f64, AlphaSCubicInterpolation>>,
+    extrapolation_policy: ExtrapolationPolicy,
 }
 
 impl AlphaSInterpol {
+    /// Creates the interpolator from the `AlphaS_Qs` and `AlphaS_Vals` tables of the metadata.
+    ///
+    /// Repeated `Q` values, which mark the flavor thresholds, are only kept once.
+    ///
+    /// # Returns
+    ///
+    /// The `AlphaSInterpol`, or an error if the table has fewer than two distinct knots.
     pub fn from_metadata(meta: &MetaData) -> Result<Self, String> {
         let (q_values, alphas_vals): (Vec<_>, Vec<_>) = meta
             .alphas_q_values
@@ -211,20 +378,226 @@ impl AlphaSInterpol {
             .map(|(_, (&q, &alpha))| (q, alpha))
             .unzip();
 
-        let q2_values: Vec<f64> = q_values.iter().map(|&q| (q * q).ln()).collect();
+        let log_q2s: Vec<f64> = q_values.iter().map(|&q| (q * q).ln()).collect();
+        if log_q2s.len() < 2 {
+            return Err("The alpha_s table needs at least two distinct knots".to_string());
+        }
 
-        let interpolator = Interp1D::new(
-            q2_values.into(),
-            alphas_vals.into(),
-            AlphaSCubicInterpolation,
-            Extrapolate::Error,
-        )
-        .map_err(|e| e.to_string())?;
+        let interpolator = if log_q2s.len() >= MIN_CUBIC_KNOTS {
+            let interpolator = Interp1D::new(
+                log_q2s.clone().into(),
+                alphas_vals.clone().into(),
+                AlphaSCubicInterpolation,
+                Extrapolate::Error,
+            )
+            .map_err(|e| e.to_string())?;
+            Some(interpolator)
+        } else {
+            None
+        };
 
-        Ok(Self { interpolator })
+        Ok(Self {
+            log_q2s,
+            alphas: alphas_vals,
+            interpolator,
+            extrapolation_policy: ExtrapolationPolicy::LogLinear,
+        })
     }
 
+    /// Sets the policy for scales outside of the table.
+    ///
+    /// # Arguments
+    ///
+    /// * `policy` - The `ExtrapolationPolicy` used by all subsequent queries.
+    pub fn set_extrapolation_policy(&mut self, policy: ExtrapolationPolicy) {
+        self.extrapolation_policy = policy;
+    }
+
+    /// Calculates the strong coupling `alpha_s` at a given `Q^2`.
+    ///
+    /// # Returns
+    ///
+    /// The value of `alpha_s`, or `NaN` where [`AlphaSInterpol::try_alphas_q2`] fails.
     pub fn alphas_q2(&self, q2: f64) -> f64 {
-        self.interpolator.interpolate(&[q2.ln()]).unwrap_or(0.0)
+        self.try_alphas_q2(q2).unwrap_or(f64::NAN)
+    }
+
+    /// Calculates the strong coupling `alpha_s` at a given `Q^2`.
+    ///
+    /// # Arguments
+    ///
+    /// * `q2` - The energy scale squared.
+    ///
+    /// # Returns
+    ///
+    /// The value of `alpha_s`, or `Error::OutOfRange` if `q2` is outside of the table and the
+    /// extrapolation policy is `ExtrapolationPolicy::Error`.
+    pub fn try_alphas_q2(&self, q2: f64) -> Result<f64, Error> {
+        let log_q2 = q2.ln();
+        let (first, last) = (self.log_q2s[0], self.log_q2s[self.log_q2s.len() - 1]);
+        let (alphas_first, alphas_last) = (self.alphas[0], self.alphas[self.alphas.len() - 1]);
+
+        if log_q2 < first || log_q2 > last {
+            return match self.extrapolation_policy {
+                ExtrapolationPolicy::Error => Err(Error::OutOfRange { q2 }),
+                ExtrapolationPolicy::Nan => Ok(f64::NAN),
+                ExtrapolationPolicy::Constant(value) => Ok(value),
+                ExtrapolationPolicy::Clamp if log_q2 < first => Ok(alphas_first),
+                ExtrapolationPolicy::LogLinear if log_q2 < first => {
+                    let gradient = (self.alphas[1] / alphas_first).ln() / (self.log_q2s[1] - first);
+                    Ok(alphas_first * (gradient * (log_q2 - first)).exp())
+                }
+                ExtrapolationPolicy::Clamp | ExtrapolationPolicy::LogLinear => Ok(alphas_last),
+            };
+        }
+
+        if let Some(interpolator) = &self.interpolator {
+            return Ok(interpolator.interpolate(&[log_q2]).unwrap_or(f64::NAN));
+        }
+
+        let i = find_interval_index(&self.log_q2s, log_q2).map_err(|_| Error::OutOfRange { q2 })?;
+        let t = (log_q2 - self.log_q2s[i]) / (self.log_q2s[i + 1] - self.log_q2s[i]);
+        Ok(self.alphas[i] + t * (self.alphas[i + 1] - self.alphas[i]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INFO: &str = "
+SetDesc: Test set
+SetIndex: 0
+NumMembers: 1
+XMin: 1.0e-9
+XMax: 1
+QMin: 1.0
+QMax: 1.0e5
+Flavors: [21, 1, 2]
+Format: lhagrid1
+AlphaS_Type: ode
+AlphaS_OrderQCD: 2
+FlavorScheme: variable
+MZ: 91.1876
+MCharm: 1.51
+MBottom: 4.92
+MTop: 172.5
+";
+
+    #[test]
+    fn test_alphas_ode_anchored_to_alphas_mz() {
+        let meta: MetaData = serde_yaml::from_str(&format!("{INFO}AlphaS_MZ: 0.1180\n")).unwrap();
+        assert_eq!(meta.alphas_mz(), Some(0.118));
+
+        let alphas = AlphaS::from_metadata(&meta).unwrap();
+        assert!(matches!(alphas, AlphaS::Ode(_)));
+
+        let mz2 = 91.1876 * 91.1876;
+        assert!((alphas.alphas_q2(mz2) - 0.118).abs() < 1e-12);
+        assert!(alphas.alphas_q2(10.0) > alphas.alphas_q2(mz2));
+        assert!(alphas.alphas_q2(1e6) < alphas.alphas_q2(mz2));
+
+        // Running down and back up again recovers the reference value.
+        let ode = AlphaSOde::from_metadata(&meta).unwrap();
+        let alphas_low = ode.alphas_q2(2.0);
+        let back = ode.evolve(alphas_low, 2.0f64.ln(), 1.51f64.powi(2).ln(), 3);
+        assert!((back - ode.alphas_q2(1.51 * 1.51)).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_alphas_interpol() {
+        let info = format!(
+            "{INFO}AlphaS_Qs: [1.0, 1.51, 1.51, 4.92, 4.92, 10.0, 91.1876, 1000.0]\n\
+             AlphaS_Vals: [0.47, 0.35, 0.35, 0.22, 0.22, 0.178, 0.118, 0.0885]\n"
+        )
+        .replace("AlphaS_Type: ode\n", "AlphaS_Type: ipol\n");
+        let meta: MetaData = serde_yaml::from_str(&info).unwrap();
+        let mut alphas = AlphaSInterpol::from_metadata(&meta).unwrap();
+
+        let mz2 = 91.1876f64 * 91.1876;
+        assert!((alphas.alphas_q2(mz2) - 0.118).abs() < 1e-12);
+        let between = alphas.alphas_q2(50.0 * 50.0);
+        assert!(between > 0.118 && between < 0.178);
+
+        // Below the table, ln(alpha_s) is extrapolated linearly in ln(Q2)
+        let gradient = (0.35f64 / 0.47).ln() / (1.51f64 * 1.51).ln();
+        assert!((alphas.alphas_q2(0.25) - 0.47 * (gradient * 0.25f64.ln()).exp()).abs() < 1e-12);
+        assert_eq!(alphas.alphas_q2(1e8), 0.0885);
+
+        alphas.set_extrapolation_policy(ExtrapolationPolicy::Clamp);
+        assert_eq!(alphas.alphas_q2(0.25), 0.47);
+        alphas.set_extrapolation_policy(ExtrapolationPolicy::Constant(0.0));
+        assert_eq!(alphas.alphas_q2(0.25), 0.0);
+        alphas.set_extrapolation_policy(ExtrapolationPolicy::Error);
+        assert!(matches!(
+            alphas.try_alphas_q2(1e8),
+            Err(Error::OutOfRange { .. })
+        ));
+        assert!(alphas.alphas_q2(1e8).is_nan());
+
+        // Tables with fewer than four knots are interpolated linearly in ln(Q2)
+        let short = info
+            .replace(
+                "[1.0, 1.51, 1.51, 4.92, 4.92, 10.0, 91.1876, 1000.0]",
+                "[10.0, 1000.0]",
+            )
+            .replace(
+                "[0.47, 0.35, 0.35, 0.22, 0.22, 0.178, 0.118, 0.0885]",
+                "[0.18, 0.09]",
+            );
+        let meta: MetaData = serde_yaml::from_str(&short).unwrap();
+        let alphas = AlphaSInterpol::from_metadata(&meta).unwrap();
+        assert!((alphas.alphas_q2(100.0 * 100.0) - 0.135).abs() < 1e-12);
+        assert!(alphas.alphas_q2(1.0).is_finite());
+    }
+
+    #[test]
+    fn test_alphas_ode_flavor_thresholds() {
+        let info = format!("{INFO}AlphaS_MZ: 0.1180\n")
+            .replace("AlphaS_OrderQCD: 2", "AlphaS_OrderQCD: 3");
+        let meta: MetaData = serde_yaml::from_str(&info).unwrap();
+        let ode = AlphaSOde::from_metadata(&meta).unwrap();
+        let mz2 = 91.1876 * 91.1876;
+        assert!((ode.alphas_q2(mz2) - 0.118).abs() < 1e-12);
+
+        // alpha_s is continuous across the bottom threshold, while its slope is not
+        let mb2 = 4.92f64 * 4.92;
+        let (below, above) = (
+            ode.alphas_q2(mb2 * (1.0 - 1e-9)),
+            ode.alphas_q2(mb2 * (1.0 + 1e-9)),
+        );
+        assert!((below - above).abs() < 1e-9);
+        assert_eq!(ode.number_flavors_q2(mb2 * 0.99), 4);
+        assert_eq!(ode.number_flavors_q2(mb2 * 1.01), 5);
+
+        // `NumFlavors` caps the number of active flavors
+        let capped: MetaData = serde_yaml::from_str(&format!("{info}NumFlavors: 4\n")).unwrap();
+        let capped = AlphaSOde::from_metadata(&capped).unwrap();
+        assert_eq!(capped.number_flavors_q2(mz2), 4);
+        assert_eq!(capped.number_flavors_q2(1e6), 4);
+        assert!((capped.alphas_q2(mz2) - 0.118).abs() < 1e-12);
+        assert!((capped.alphas_q2(mb2) - ode.alphas_q2(mb2)).abs() > 1e-4);
+    }
+
+    #[test]
+    fn test_alphas_ode_reference_fallbacks() {
+        let info = format!(
+            "{INFO}AlphaS_MZ: 0.1200\nAlphaS_MassReference: 10.0\n\
+             AlphaS_Qs: [1.0, 10.0, 91.1876, 1000.0]\n\
+             AlphaS_Vals: [0.4, 0.18, 0.125, 0.09]\n"
+        );
+        let meta: MetaData = serde_yaml::from_str(&info).unwrap();
+        let alphas = AlphaS::from_metadata(&meta).unwrap();
+        assert!((alphas.alphas_q2(100.0) - 0.12).abs() < 1e-12);
+
+        // Without `AlphaS_MZ`, the value at `MZ` is read from the table.
+        let info = info.replace("AlphaS_MZ: 0.1200\nAlphaS_MassReference: 10.0\n", "");
+        let meta: MetaData = serde_yaml::from_str(&info).unwrap();
+        assert_eq!(meta.alphas_mz(), None);
+        let alphas = AlphaS::from_metadata(&meta).unwrap();
+        assert!((alphas.alphas_q2(91.1876 * 91.1876) - 0.125).abs() < 1e-10);
+
+        let meta: MetaData = serde_yaml::from_str(INFO).unwrap();
+        assert!(AlphaS::from_metadata(&meta).is_err());
     }
 }
@@ -6,16 +6,21 @@
 //! - [`GridArray`]: Stores the full set of subgrids and flavor IDs.
 
 use core::panic;
-use ndarray::{Array1, Array2};
+use ndarray::{s, Array1, Array2};
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use thiserror::Error;
 
 use super::alphas::AlphaS;
-use super::interpolator::{DynInterpolator, InterpolatorFactory};
-use super::metadata::{InterpolatorType, MetaData};
+use super::interpolator::{Axis, DynInterpolator, InterpolationConfig, InterpolatorFactory};
+use super::metadata::{validate_interpolator, InterpolatorType, MetaData, SetType};
 use super::parser::SubgridData;
-use super::subgrid::{ParamRange, RangeParameters, SubGrid};
+use super::parton::canonical_pid;
+use super::subgrid::{select_subgrid_index, ParamRange, RangeParameters, SubGrid};
+use super::utils::find_interval_index;
 
 /// Errors that can occur during PDF grid operations.
 #[derive(Debug, Error)]
@@ -31,10 +36,57 @@ pub enum Error {
     /// Error indicating invalid interpolation parameters, with a descriptive message.
     #[error("Invalid interpolation parameters: {0}")]
     InterpolationError(String),
+    /// Error indicating that paired `x` and `q2` inputs do not have the same length.
+    #[error("Mismatched input lengths: xs has {xs} elements but q2s has {q2s}")]
+    MismatchedLengths {
+        /// The number of `x` values.
+        xs: usize,
+        /// The number of `q2` values.
+        q2s: usize,
+    },
+    /// Error indicating that the requested flavor is not part of the grid.
+    #[error("Flavor with PID {pid} not found in the grid")]
+    FlavorNotFound {
+        /// The particle flavor ID.
+        pid: i32,
+    },
+    /// Error indicating that the interpolator does not support the grid dimensions.
+    #[error("Interpolator {0:?} is not compatible with the grid dimensions")]
+    IncompatibleInterpolator(InterpolatorType),
+    /// Error indicating that two grids do not share the same axes or flavors.
+    #[error("Incompatible grids: {0}")]
+    IncompatibleGrids(String),
+    /// Error indicating that the morphing parameter is outside of `[0, 1]`.
+    #[error("Morphing parameter t={t} is outside of [0, 1]")]
+    MorphParameterOutOfRange {
+        /// The morphing parameter.
+        t: f64,
+    },
+    /// Error indicating that the requested node is outside of the grid.
+    #[error("Node (x_index={x_index}, q2_index={q2_index}) is outside of the grid")]
+    NodeIndexOutOfBounds {
+        /// The index along the `x` axis.
+        x_index: usize,
+        /// The index along the `q2` axis.
+        q2_index: usize,
+    },
+    /// Error indicating that the subgrid selected for a point does not have its axes.
+    #[error("The point requires a {expected:?} subgrid, but subgrid {subgrid} is {found:?}")]
+    AxisMismatch {
+        /// The index of the selected subgrid.
+        subgrid: usize,
+        /// The configuration matching the axes of the point.
+        expected: InterpolationConfig,
+        /// The configuration of the selected subgrid.
+        found: InterpolationConfig,
+    },
 }
 
+/// The conventional partons: the gluon and the light and heavy (anti)quarks up to the bottom.
+const STANDARD_FLAVORS: [i32; 11] = [-5, -4, -3, -2, -1, 1, 2, 3, 4, 5, 21];
+
 /// Stores the complete PDF grid data, including all subgrids and flavor information.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct GridArray {
     /// An array of particle flavor IDs (PIDs).
     pub pids: Array1<i32>,
@@ -108,6 +160,9 @@ impl GridArray {
 
     /// Finds the index of the subgrid that contains the given point.
     ///
+    /// If no subgrid contains the point, the index of the nearest one is returned, see
+    /// [`crate::subgrid::select_subgrid`].
+    ///
     /// # Arguments
     ///
     /// * `points` - A slice of coordinates for the point.
@@ -116,29 +171,319 @@ impl GridArray {
     ///
     /// An `Option<usize>` containing the index of the subgrid if found, otherwise `None`.
     pub fn find_subgrid(&self, points: &[f64]) -> Option<usize> {
+        select_subgrid_index(&self.subgrids, points)
+    }
+
+    /// Checks whether the subgrids tile the `q2` axis in increasing order, i.e. whether each
+    /// subgrid starts where the previous one ends.
+    pub fn is_sorted_q2_tiling(&self) -> bool {
+        self.subgrids
+            .windows(2)
+            .all(|pair| pair[0].q2_range.max == pair[1].q2_range.min)
+    }
+
+    /// Finds the index of the first subgrid whose `q2` range contains a given value.
+    ///
+    /// The subgrids are binary searched if they are sorted, see
+    /// [`GridArray::is_sorted_q2_tiling`], and scanned linearly otherwise. Checking the order
+    /// of the subgrids is itself a scan, which [`GridPDF::find_subgrid_by_q2`] avoids by
+    /// caching it.
+    ///
+    /// # Arguments
+    ///
+    /// * `q2` - The energy scale squared.
+    ///
+    /// # Returns
+    ///
+    /// The index of the subgrid, or `None` if `q2` is outside of the grid.
+    pub fn find_subgrid_by_q2(&self, q2: f64) -> Option<usize> {
+        self.find_subgrid_by_q2_in(q2, self.is_sorted_q2_tiling())
+    }
+
+    /// Finds the index of the first subgrid whose `q2` range contains a given value, knowing
+    /// whether the subgrids are sorted, see [`GridArray::find_subgrid_by_q2`].
+    pub(crate) fn find_subgrid_by_q2_in(&self, q2: f64, sorted: bool) -> Option<usize> {
+        if !sorted {
+            return self.subgrids.iter().position(|sg| sg.q2_range.contains(q2));
+        }
+
+        let idx = self.subgrids.partition_point(|sg| sg.q2_range.max < q2);
         self.subgrids
+            .get(idx)
+            .filter(|sg| sg.q2_range.contains(q2))
+            .map(|_| idx)
+    }
+
+    /// Multiplies all the grid values of a given flavor by a constant factor.
+    ///
+    /// The values of all the other flavors are left untouched.
+    ///
+    /// # Arguments
+    ///
+    /// * `flavor_id` - The particle flavor ID.
+    /// * `factor` - The multiplicative factor.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` on success, or `Error::FlavorNotFound` if the flavor is not in the grid.
+    pub fn scale_flavor(&mut self, flavor_id: i32, factor: f64) -> Result<(), Error> {
+        let pid_idx = self
+            .pid_index(flavor_id)
+            .ok_or(Error::FlavorNotFound { pid: flavor_id })?;
+
+        for subgrid in &mut self.subgrids {
+            subgrid
+                .grid
+                .slice_mut(s![.., .., pid_idx, .., .., ..])
+                .mapv_inplace(|v| v * factor);
+        }
+
+        Ok(())
+    }
+
+    /// Retrieves the value stored at a node of a 2D grid, without any interpolation.
+    ///
+    /// The `q2` nodes are indexed across the subgrids in the order in which they are stored,
+    /// i.e. `q2_index` runs over the knots of the first subgrid and then over the ones of the
+    /// following subgrids.
+    ///
+    /// # Arguments
+    ///
+    /// * `flavor_id` - The flavor ID.
+    /// * `x_index` - The index along the `x` axis.
+    /// * `q2_index` - The index along the `q2` axis.
+    ///
+    /// # Returns
+    ///
+    /// The value at the node, or an error if the flavor or the node is not part of the grid.
+    pub fn node_value(
+        &self,
+        flavor_id: i32,
+        x_index: usize,
+        q2_index: usize,
+    ) -> Result<f64, Error> {
+        let pid_idx = self
+            .pid_index(flavor_id)
+            .ok_or(Error::FlavorNotFound { pid: flavor_id })?;
+
+        let mut offset = 0;
+        for subgrid in &self.subgrids {
+            if !matches!(subgrid.interpolation_config(), InterpolationConfig::TwoD) {
+                return Err(Error::InterpolationError(
+                    "node values are only available for 2D grids".to_string(),
+                ));
+            }
+            if q2_index < offset + subgrid.q2s.len() {
+                if x_index >= subgrid.xs.len() {
+                    break;
+                }
+                return Ok(subgrid.grid[[0, 0, pid_idx, 0, x_index, q2_index - offset]]);
+            }
+            offset += subgrid.q2s.len();
+        }
+
+        Err(Error::NodeIndexOutOfBounds { x_index, q2_index })
+    }
+
+    /// Finds the index of the `x` knot closest to a given value.
+    ///
+    /// The knots are the ones of the first subgrid covering `x`, or of the first subgrid if
+    /// `x` is outside of the grid, in which case the index of the closest boundary is returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The momentum fraction.
+    ///
+    /// # Returns
+    ///
+    /// The index of the closest `x` knot.
+    pub fn nearest_x_index(&self, x: f64) -> usize {
+        let subgrid = self
+            .subgrids
             .iter()
-            .position(|sg| sg.contains_point(points))
-            .or_else(|| {
-                self.subgrids
-                    .iter()
-                    .enumerate()
-                    .min_by(|(_, a), (_, b)| {
-                        a.distance_to_point(points)
-                            .partial_cmp(&b.distance_to_point(points))
-                            .unwrap()
-                    })
-                    .map(|(idx, _)| idx)
+            .find(|sg| sg.x_range.contains(x))
+            .unwrap_or(&self.subgrids[0]);
+
+        nearest_knot(subgrid.xs.as_slice().unwrap(), x)
+    }
+
+    /// Finds the index of the `q2` knot closest to a given value.
+    ///
+    /// As for [`GridArray::node_value`], the `q2` knots are indexed across the subgrids in
+    /// the order in which they are stored. Values outside of the grid are snapped to the
+    /// closest boundary.
+    ///
+    /// # Arguments
+    ///
+    /// * `q2` - The energy scale squared.
+    ///
+    /// # Returns
+    ///
+    /// The index of the closest `q2` knot.
+    pub fn nearest_q2_index(&self, q2: f64) -> usize {
+        let subgrid_idx = self
+            .subgrids
+            .iter()
+            .position(|sg| sg.q2_range.contains(q2))
+            .unwrap_or(if q2 < self.subgrids[0].q2_range.min {
+                0
+            } else {
+                self.subgrids.len() - 1
+            });
+        let offset: usize = self.subgrids[..subgrid_idx]
+            .iter()
+            .map(|sg| sg.q2s.len())
+            .sum();
+
+        offset + nearest_knot(self.subgrids[subgrid_idx].q2s.as_slice().unwrap(), q2)
+    }
+
+    /// Iterates over the `q2` knots of a 2D grid, yielding the values of all the flavors and
+    /// `x` knots at each of them.
+    ///
+    /// The `q2` knots are visited across the subgrids in the order in which they are stored,
+    /// as for [`GridArray::node_value`].
+    ///
+    /// # Returns
+    ///
+    /// An iterator over the pairs `(q2, values)`, where `values` has the shape `(nflav, nx)`,
+    /// or an error if the grid is not 2D.
+    pub fn q2_slices(&self) -> Result<impl Iterator<Item = (f64, Array2<f64>)> + '_, Error> {
+        if self
+            .subgrids
+            .iter()
+            .any(|sg| !matches!(sg.interpolation_config(), InterpolationConfig::TwoD))
+        {
+            return Err(Error::InterpolationError(
+                "Q2 slices are only available for 2D grids".to_string(),
+            ));
+        }
+
+        Ok(self.subgrids.iter().flat_map(|subgrid| {
+            subgrid.q2s.iter().enumerate().map(move |(q2_idx, &q2)| {
+                let values = subgrid.grid.slice(s![0, 0, .., 0, .., q2_idx]).to_owned();
+                (q2, values)
+            })
+        }))
+    }
+
+    /// Blends the grid values with the ones of another grid sharing the same axes.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The grid to blend with.
+    /// * `t` - The morphing parameter in `[0, 1]`.
+    ///
+    /// # Returns
+    ///
+    /// A new `GridArray` with the node-wise values `(1 - t) * self + t * other`, or an error if
+    /// the two grids do not have the same flavors and axes.
+    pub fn morph(&self, other: &GridArray, t: f64) -> Result<GridArray, Error> {
+        if !(0.0..=1.0).contains(&t) {
+            return Err(Error::MorphParameterOutOfRange { t });
+        }
+        if self.pids.mapv(canonical_pid) != other.pids.mapv(canonical_pid) {
+            return Err(Error::IncompatibleGrids("different flavors".to_string()));
+        }
+        if self.subgrids.len() != other.subgrids.len() {
+            return Err(Error::IncompatibleGrids(
+                "different number of subgrids".to_string(),
+            ));
+        }
+
+        let subgrids = self
+            .subgrids
+            .iter()
+            .zip(&other.subgrids)
+            .enumerate()
+            .map(|(idx, (a, b))| {
+                let same_axes = a.nucleons == b.nucleons
+                    && a.alphas == b.alphas
+                    && a.kts == b.kts
+                    && a.xs == b.xs
+                    && a.q2s == b.q2s;
+                if !same_axes {
+                    return Err(Error::IncompatibleGrids(format!(
+                        "different axes in subgrid {idx}"
+                    )));
+                }
+
+                let mut subgrid = a.clone();
+                subgrid.grid = (1.0 - t) * &a.grid + t * &b.grid;
+                Ok(subgrid)
             })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(GridArray {
+            pids: self.pids.clone(),
+            subgrids,
+        })
     }
 
     /// Gets the index corresponding to a given flavor ID.
-    fn pid_index(&self, flavor_id: i32) -> Option<usize> {
-        let normalize_pid = |pid| if pid == 0 { 21 } else { pid };
-        let normalized_pids = normalize_pid(flavor_id);
+    ///
+    /// The index refers to the flavor axis shared by all the subgrids, e.g. the `pid_index`
+    /// of [`SubGrid::flavor_grid`]. The gluon can be given either as `21` or `0`.
+    ///
+    /// # Arguments
+    ///
+    /// * `flavor_id` - The PDG ID of the flavor.
+    ///
+    /// # Returns
+    ///
+    /// The index of the flavor, or `None` if the flavor is not part of the grid.
+    pub fn pid_index(&self, flavor_id: i32) -> Option<usize> {
+        let flavor_id = canonical_pid(flavor_id);
         self.pids
             .iter()
-            .position(|&pid| normalize_pid(pid) == normalized_pids)
+            .position(|&pid| canonical_pid(pid) == flavor_id)
+    }
+
+    /// Extracts the `x`-`Q²` grid of a flavor, given by its PDG ID, from a subgrid.
+    ///
+    /// This is the counterpart of [`SubGrid::flavor_grid`] that does not require the
+    /// position of the flavor in the grid.
+    ///
+    /// # Arguments
+    ///
+    /// * `subgrid_idx` - The index of the subgrid.
+    /// * `flavor_id` - The PDG ID of the flavor.
+    /// * `fixed` - The knot indices of the other active parameters, see
+    ///   [`SubGrid::flavor_grid`].
+    ///
+    /// # Returns
+    ///
+    /// The grid of shape `(xs.len(), q2s.len())`, `Error::FlavorNotFound` if the flavor is not
+    /// part of the grid, or `Error::InterpolationError` if an index is not valid.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `subgrid_idx` is out of bounds.
+    pub fn flavor_grid(
+        &self,
+        subgrid_idx: usize,
+        flavor_id: i32,
+        fixed: &[usize],
+    ) -> Result<Array2<f64>, Error> {
+        let pid_idx = self
+            .pid_index(flavor_id)
+            .ok_or(Error::FlavorNotFound { pid: flavor_id })?;
+
+        self.subgrids[subgrid_idx].flavor_grid(pid_idx, fixed)
+    }
+
+    /// Lists the conventional partons that are not part of the grid.
+    ///
+    /// The gluon can be stored either as `21` or `0`.
+    ///
+    /// # Returns
+    ///
+    /// The PDG IDs of the gluon and of the (anti)quarks up to the bottom that are missing.
+    pub fn missing_standard_flavors(&self) -> Vec<i32> {
+        STANDARD_FLAVORS
+            .into_iter()
+            .filter(|&pid| self.pid_index(pid).is_none())
+            .collect()
     }
 
     /// Gets the overall parameter ranges across all subgrids.
@@ -176,9 +521,29 @@ impl GridArray {
     }
 }
 
+/// Returns the index of the knot closest to `value`, clamping values outside of the knots.
+fn nearest_knot(knots: &[f64], value: f64) -> usize {
+    match find_interval_index(knots, value) {
+        Ok(idx) if value - knots[idx] <= knots[idx + 1] - value => idx,
+        Ok(idx) => idx + 1,
+        Err(_) if value < knots[0] => 0,
+        Err(_) => knots.len() - 1,
+    }
+}
+
+/// Returns the indices of the two knots bracketing `value`, or of the closest edge cell if
+/// `value` is outside of the knots.
+fn bracketing_knots(knots: &[f64], value: f64) -> [usize; 2] {
+    match find_interval_index(knots, value) {
+        Ok(idx) => [idx, idx + 1],
+        Err(_) if value < knots[0] => [0, 1],
+        Err(_) => [knots.len() - 2, knots.len() - 1],
+    }
+}
+
 /// Defines the methods for handling negative or small PDF values.
 #[repr(C)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub enum ForcePositive {
     /// If the calculated PDF value is negative, it is forced to 0.
     ClipNegative,
@@ -188,6 +553,92 @@ pub enum ForcePositive {
     NoClipping,
 }
 
+/// Defines how queries outside of the grid coverage are handled.
+///
+/// The policy applied to a query is resolved in the following order: the policy passed
+/// explicitly to [`GridPDF::xfxq2_with_policy`], then the policy set on the instance with
+/// [`GridPDF::set_extrapolation_policy`], and finally the default, [`ExtrapolationPolicy::Clamp`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Deserialize, Serialize)]
+pub enum ExtrapolationPolicy {
+    /// Out-of-range queries return `Error::SubgridNotFound`.
+    Error,
+    /// Out-of-range queries are clamped to the closest boundary of the nearest subgrid.
+    #[default]
+    Clamp,
+    /// Out-of-range queries return `f64::NAN`, such that batches are not aborted.
+    Nan,
+    /// Out-of-range queries return the given fill value.
+    Constant(f64),
+    /// Queries below the smallest `x` of the nearest subgrid are extrapolated linearly in
+    /// `ln(x)` from its first two `x` knots, while the other out-of-range coordinates are
+    /// clamped. [`GridPDF::xfxq2_cheby_batch`] and [`GridPDF::interpolation_error_map`] clamp
+    /// these queries instead. For `TimeLike` sets the queries below `x` return
+    /// `Error::SubgridNotFound` instead, unless enabled with
+    /// [`GridPDF::set_low_x_extrapolation`].
+    LogLinear,
+}
+
+impl ExtrapolationPolicy {
+    /// The value returned for out-of-range queries that are not interpolated.
+    fn fill_value(self) -> f64 {
+        match self {
+            Self::Constant(value) => value,
+            _ => f64::NAN,
+        }
+    }
+}
+
+/// Defines the unit of the scale passed to the point-based interpolation methods.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ScaleUnit {
+    /// The scale is given as the energy `Q`, in GeV.
+    Q,
+    /// The scale is given as the energy squared `Q2`, in GeV².
+    #[default]
+    Q2,
+}
+
+/// Reusable scratch buffer for the non-allocating interpolation methods.
+///
+/// The scratch buffer also caches the subgrid and the interpolation cell of the last query,
+/// which are checked first by the next query. As consecutive queries are usually close to each
+/// other, this skips most of the subgrid and cell searches. The cache is only a starting point
+/// of the searches, such that the results do not depend on it.
+///
+/// See [`GridPDF::eval_into`] and [`GridPDF::eval_all_into`].
+#[derive(Debug, Default)]
+pub struct InterpScratch {
+    coords: Vec<f64>,
+    values: Vec<f64>,
+    /// The point moved to the `x` knots with `ExtrapolationPolicy::LogLinear`.
+    edge: Vec<f64>,
+    /// The index of the subgrid of the last query.
+    subgrid: Option<usize>,
+    /// The lower knot indices along `x` and `Q2` of the cell of the last query.
+    cell: [Option<usize>; 2],
+}
+
+impl InterpScratch {
+    /// Creates a new, empty, `InterpScratch`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// The provenance of an interpolated value, see [`GridPDF::interpolate_traced`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct InterpTrace {
+    /// The index of the subgrid the value is interpolated from, or `None` if the point is out
+    /// of range and evaluates to the fill value of the extrapolation policy.
+    pub subgrid: Option<usize>,
+    /// The indices of the knots bracketing the point along each axis of the subgrid, in the
+    /// order of [`InterpolationConfig::axis_order`]. Along the axes on which the point is
+    /// outside of the subgrid, these are the knots of the closest edge cell.
+    pub brackets: Vec<(Axis, [usize; 2])>,
+    /// The interpolator of the flavor, see [`GridPDF::set_interpolator_overrides`].
+    pub interpolator: InterpolatorType,
+}
+
 /// The main PDF grid interface, providing high-level methods for interpolation.
 pub struct GridPDF {
     /// The metadata associated with the PDF set.
@@ -196,30 +647,134 @@ pub struct GridPDF {
     pub knot_array: GridArray,
     /// A nested vector of interpolators for each subgrid and flavor.
     interpolators: Vec<Vec<Box<dyn DynInterpolator>>>,
+    /// The interpolators overriding the one of the metadata, by PDG ID.
+    interpolator_overrides: HashMap<i32, InterpolatorType>,
+    /// The interpolator of each flavor, in the order of the PIDs of the grid.
+    interpolator_types: Vec<InterpolatorType>,
     /// Calculator for the running of alpha_s.
     alphas: AlphaS,
     /// Clip the values to positive definite numbers if negatives.
     pub force_positive: Option<ForcePositive>,
+    /// The policy for queries outside of the grid coverage.
+    extrapolation_policy: ExtrapolationPolicy,
+    /// The unit of the scale of the queries.
+    query_scale: ScaleUnit,
+    /// Whether `ExtrapolationPolicy::LogLinear` extrapolates the queries below `x`.
+    low_x_extrapolation: bool,
+    /// The number of points of the last batch that were clamped to the grid coverage.
+    last_batch_clamped: AtomicUsize,
+    /// Whether the subgrids tile the `q2` axis in increasing order.
+    sorted_q2_tiling: bool,
 }
 
 impl GridPDF {
     /// Creates a new `GridPDF` instance.
     ///
+    /// The evaluation settings stored in the metadata, if any, are applied to the instance.
+    ///
     /// # Arguments
     ///
     /// * `info` - The `MetaData` for the PDF set.
     /// * `knot_array` - The `GridArray` containing the grid data.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the interpolator of the metadata does not support the dimensions of one of
+    /// the subgrids, see [`validate_interpolator`].
     pub fn new(info: MetaData, knot_array: GridArray) -> Self {
         let interpolators = Self::build_interpolators(&info, &knot_array);
         let alphas = AlphaS::from_metadata(&info).expect("Failed to create AlphaS calculator");
+        let config = info.interpolation_config();
+        let low_x_extrapolation = !matches!(info.set_type, SetType::TimeLike);
+        let interpolator_types = vec![info.interpolator_type.clone(); knot_array.pids.len()];
+        let sorted_q2_tiling = knot_array.is_sorted_q2_tiling();
 
         Self {
             info,
             knot_array,
             interpolators,
+            interpolator_overrides: HashMap::new(),
+            interpolator_types,
             alphas,
-            force_positive: None,
+            force_positive: config.force_positive,
+            extrapolation_policy: config.extrapolation_policy,
+            query_scale: ScaleUnit::default(),
+            low_x_extrapolation,
+            last_batch_clamped: AtomicUsize::new(0),
+            sorted_q2_tiling,
+        }
+    }
+
+    /// Copies the evaluation options (clipping, extrapolation policies, scale unit and
+    /// interpolator overrides) of another `GridPDF`.
+    ///
+    /// The interpolator overrides are dropped if the subgrids of `self` do not support them.
+    pub(crate) fn inherit_options(&mut self, other: &GridPDF) {
+        self.force_positive = other.force_positive.clone();
+        self.extrapolation_policy = other.extrapolation_policy;
+        self.query_scale = other.query_scale;
+        self.low_x_extrapolation = other.low_x_extrapolation;
+        if !other.interpolator_overrides.is_empty() {
+            let _ = self.set_interpolator_overrides(other.interpolator_overrides.clone());
+        }
+    }
+
+    /// Sets the interpolators of specific flavors, overriding the one of the metadata.
+    ///
+    /// This allows e.g. a gentler `LogBilinear` interpolation of the gluon or the photon near
+    /// `x -> 1`, where `LogBicubic` may overshoot. The flavors that are not listed (including
+    /// the ones of previous overrides) use the `interpolator_type` of the metadata, and the
+    /// flavors that are not part of the grid are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `overrides` - The interpolators of the overridden flavors, by PDG ID.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())`, or `Error::IncompatibleInterpolator` if an interpolator does not support the
+    /// dimensions of one of the subgrids, in which case the interpolators are left unchanged.
+    pub fn set_interpolator_overrides(
+        &mut self,
+        overrides: HashMap<i32, InterpolatorType>,
+    ) -> Result<(), Error> {
+        if let Some(unsupported) = overrides.values().find(|interpolator| {
+            !self
+                .knot_array
+                .subgrids
+                .iter()
+                .all(|sg| sg.interpolation_config().supports(interpolator))
+        }) {
+            return Err(Error::IncompatibleInterpolator(unsupported.clone()));
+        }
+
+        for (pid_idx, &pid) in self.knot_array.pids.iter().enumerate() {
+            let interpolator = overrides
+                .iter()
+                .find(|(&flavor_id, _)| canonical_pid(flavor_id) == canonical_pid(pid))
+                .map_or(&self.info.interpolator_type, |(_, interpolator)| {
+                    interpolator
+                });
+            if *interpolator == self.interpolator_types[pid_idx] {
+                continue;
+            }
+
+            for (subgrid, interpolators) in
+                self.knot_array.subgrids.iter().zip(&mut self.interpolators)
+            {
+                interpolators[pid_idx] =
+                    InterpolatorFactory::create(interpolator.clone(), subgrid, pid_idx);
+            }
+            self.interpolator_types[pid_idx] = interpolator.clone();
         }
+        self.interpolator_overrides = overrides;
+
+        Ok(())
+    }
+
+    /// Returns the interpolators overriding the one of the metadata, by PDG ID.
+    pub fn interpolator_overrides(&self) -> &HashMap<i32, InterpolatorType> {
+        &self.interpolator_overrides
     }
 
     /// Sets the method for handling negative or small PDF values.
@@ -231,6 +786,67 @@ impl GridPDF {
         self.force_positive = Some(flag);
     }
 
+    /// Sets the policy for queries outside of the grid coverage.
+    ///
+    /// # Arguments
+    ///
+    /// * `policy` - The `ExtrapolationPolicy` used by all subsequent queries.
+    pub fn set_extrapolation_policy(&mut self, policy: ExtrapolationPolicy) {
+        self.extrapolation_policy = policy;
+    }
+
+    /// Returns the policy for queries outside of the grid coverage.
+    pub fn extrapolation_policy(&self) -> ExtrapolationPolicy {
+        self.extrapolation_policy
+    }
+
+    /// Sets whether `ExtrapolationPolicy::LogLinear` extrapolates the queries below `x`.
+    ///
+    /// Fragmentation functions (`TimeLike` sets) do not follow the low-`x` behavior assumed
+    /// by the extrapolation, such that it is disabled by default for them and these queries
+    /// return `Error::SubgridNotFound`. It is enabled by default for `SpaceLike` sets.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether the queries below `x` are extrapolated.
+    pub fn set_low_x_extrapolation(&mut self, enabled: bool) {
+        self.low_x_extrapolation = enabled;
+    }
+
+    /// Returns whether `ExtrapolationPolicy::LogLinear` extrapolates the queries below `x`.
+    pub fn low_x_extrapolation(&self) -> bool {
+        self.low_x_extrapolation
+    }
+
+    /// Sets the unit of the scale passed to the point-based interpolation methods.
+    ///
+    /// The scale is the last coordinate of the points passed to [`GridPDF::xfxq2`] and the
+    /// methods built on it, [`GridPDF::xfxq2_with_policy`] and
+    /// [`GridPDF::xfxq2_cheby_batch`]. Methods taking an explicit `q2` argument, such as
+    /// [`GridPDF::xfxq2_batch`] or [`GridPDF::evaluate_on_grid`], are not affected.
+    ///
+    /// # Arguments
+    ///
+    /// * `unit` - The `ScaleUnit` of the queries.
+    pub fn set_query_scale(&mut self, unit: ScaleUnit) {
+        self.query_scale = unit;
+    }
+
+    /// Returns the unit of the scale passed to the point-based interpolation methods.
+    pub fn query_scale(&self) -> ScaleUnit {
+        self.query_scale
+    }
+
+    /// Converts the scale of a point given in the query unit into `Q2`.
+    fn points_in_q2(&self, points: &[f64]) -> Vec<f64> {
+        let mut converted = points.to_vec();
+        if let (ScaleUnit::Q, Some(scale)) = (self.query_scale, converted.last_mut()) {
+            *scale *= *scale;
+        }
+
+        converted
+    }
+
     /// Applies the configured clipping method to a given PDF value.
     ///
     /// # Arguments
@@ -258,6 +874,9 @@ impl GridPDF {
             .subgrids
             .iter()
             .map(|subgrid| {
+                if let Err(err) = validate_interpolator(info, subgrid) {
+                    panic!("{err}");
+                }
                 (0..knot_array.pids.len())
                     .map(|pid_idx| {
                         InterpolatorFactory::create(
@@ -282,41 +901,412 @@ impl GridPDF {
     ///
     /// A `Result` containing the interpolated PDF value or an `Error`.
     pub fn xfxq2(&self, flavor_id: i32, points: &[f64]) -> Result<f64, Error> {
-        let subgrid_idx = self.knot_array.find_subgrid(points).ok_or_else(|| {
-            let (x, q2) = self.get_x_q2(points);
-            Error::SubgridNotFound { x, q2 }
-        })?;
+        self.xfxq2_with_policy(flavor_id, points, self.extrapolation_policy)
+    }
+
+    /// Interpolates the PDF value for a given flavor, overriding the extrapolation policy.
+    ///
+    /// # Arguments
+    ///
+    /// * `flavor_id` - The particle flavor ID.
+    /// * `points` - A slice containing the collection of points to interpolate on.
+    /// * `policy` - The `ExtrapolationPolicy` used for this query only.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the interpolated PDF value or an `Error`.
+    pub fn xfxq2_with_policy(
+        &self,
+        flavor_id: i32,
+        points: &[f64],
+        policy: ExtrapolationPolicy,
+    ) -> Result<f64, Error> {
+        let converted;
+        let points = match self.query_scale {
+            ScaleUnit::Q2 => points,
+            ScaleUnit::Q => {
+                converted = self.points_in_q2(points);
+                &converted
+            }
+        };
+        self.xfxq2_in_q2(flavor_id, points, policy)
+    }
+
+    /// Interpolates the PDF value for a given flavor at a point whose scale is always `Q2`,
+    /// independently of the [`GridPDF::query_scale`].
+    pub(crate) fn xfxq2_in_q2(
+        &self,
+        flavor_id: i32,
+        points: &[f64],
+        policy: ExtrapolationPolicy,
+    ) -> Result<f64, Error> {
+        let Some(subgrid_idx) = self.subgrid_index(points, policy)? else {
+            return Ok(policy.fill_value());
+        };
 
         let pid_idx = match self.knot_array.pid_index(flavor_id) {
             Some(idx) => idx,
             None => return Ok(0.0),
         };
 
-        let use_log = matches!(
-            self.info.interpolator_type,
-            InterpolatorType::LogBilinear
-                | InterpolatorType::LogBicubic
-                | InterpolatorType::LogTricubic
-                | InterpolatorType::LogChebyshev
-        );
-
-        self.interpolators[subgrid_idx][pid_idx]
-            .interpolate_point(
-                &points
-                    .iter()
-                    .map(|&p| if use_log { p.ln() } else { p })
-                    .collect::<Vec<_>>(),
-            )
-            .map_err(|e| Error::InterpolationError(e.to_string()))
-            .map(|result| self.apply_force_positive(result))
+        let mut coords = Vec::with_capacity(points.len());
+        self.interpolate_into(
+            subgrid_idx,
+            pid_idx,
+            points,
+            policy,
+            &mut coords,
+            &mut Vec::new(),
+            &mut [None; 2],
+        )
     }
 
-    /// Interpolates PDF values for multiple points in parallel.
+    /// Interpolates the PDF value for a given flavor, recording where it comes from.
+    ///
+    /// The value is the one of [`GridPDF::xfxq2`]. The trace is computed separately, such that
+    /// this method is meant for debugging (e.g. of artifacts at the subgrid boundaries) rather
+    /// than for tight loops.
     ///
     /// # Arguments
     ///
-    /// * `flavors` - A vector of flavor IDs.
-    /// * `slice_points` - A slice containing the collection of knots to interpolate on.
+    /// * `flavor_id` - The particle flavor ID.
+    /// * `points` - A slice containing the collection of points to interpolate on.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the interpolated PDF value and its `InterpTrace`, or an `Error`.
+    pub fn interpolate_traced(
+        &self,
+        flavor_id: i32,
+        points: &[f64],
+    ) -> Result<(f64, InterpTrace), Error> {
+        let value = self.xfxq2(flavor_id, points)?;
+
+        let points = self.points_in_q2(points);
+        let subgrid = self.subgrid_index(&points, self.extrapolation_policy)?;
+        let brackets = subgrid.map_or_else(Vec::new, |subgrid_idx| {
+            let subgrid = &self.knot_array.subgrids[subgrid_idx];
+            subgrid
+                .interpolation_config()
+                .axis_order()
+                .into_iter()
+                .zip(&points)
+                .map(|(axis, &value)| {
+                    let knots = subgrid
+                        .knots(axis)
+                        .as_slice()
+                        .expect("The knots are contiguous");
+                    (axis, bracketing_knots(knots, value))
+                })
+                .collect()
+        });
+
+        let interpolator = self
+            .knot_array
+            .pid_index(flavor_id)
+            .map_or(&self.info.interpolator_type, |pid_idx| {
+                &self.interpolator_types[pid_idx]
+            });
+        let trace = InterpTrace {
+            subgrid,
+            brackets,
+            interpolator: interpolator.clone(),
+        };
+        Ok((value, trace))
+    }
+
+    /// Interpolates the PDF values of all the flavors for a given point.
+    ///
+    /// The values are returned in the order given by [`GridPDF::flavor_order`], such that the
+    /// `i`-th value is the one of `flavor_order()[i]`.
+    ///
+    /// # Arguments
+    ///
+    /// * `points` - A slice containing the collection of points to interpolate on.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the interpolated PDF values or an `Error`.
+    pub fn xfxq2_all(&self, points: &[f64]) -> Result<Vec<f64>, Error> {
+        self.flavor_order()
+            .iter()
+            .map(|&pid| self.xfxq2(pid, points))
+            .collect()
+    }
+
+    /// Returns the order of the flavors in the output of [`GridPDF::xfxq2_all`].
+    ///
+    /// This is the order of the `Flavors` entry of the metadata, which is stored by all the
+    /// formats and metadata versions, or the order of the PIDs of the grid if the metadata
+    /// does not list any flavor.
+    pub fn flavor_order(&self) -> &[i32] {
+        if self.info.flavors.is_empty() {
+            self.knot_array
+                .pids
+                .as_slice()
+                .expect("The PIDs are stored contiguously")
+        } else {
+            &self.info.flavors
+        }
+    }
+
+    /// Finds the index of the first subgrid whose `q2` range contains a given value.
+    ///
+    /// Abstraction to the `GridArray::find_subgrid_by_q2` method, with the order of the
+    /// subgrids checked once at construction.
+    ///
+    /// # Arguments
+    ///
+    /// * `q2` - The energy scale squared.
+    ///
+    /// # Returns
+    ///
+    /// The index of the subgrid, or `None` if `q2` is outside of the grid.
+    pub fn find_subgrid_by_q2(&self, q2: f64) -> Option<usize> {
+        self.knot_array
+            .find_subgrid_by_q2_in(q2, self.sorted_q2_tiling)
+    }
+
+    /// Interpolates the PDF value for `(x, q2)` and a given flavor, reusing a scratch buffer.
+    ///
+    /// This is the non-allocating counterpart of [`GridPDF::xfxq2`] for grids that only
+    /// depend on `x` and `q2`, intended for tight loops.
+    ///
+    /// # Arguments
+    ///
+    /// * `flavor_id` - The particle flavor ID.
+    /// * `x` - The momentum fraction.
+    /// * `q2` - The energy scale squared.
+    /// * `scratch` - The scratch buffer owned by the caller.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the interpolated PDF value or an `Error`.
+    pub fn eval_into(
+        &self,
+        flavor_id: i32,
+        x: f64,
+        q2: f64,
+        scratch: &mut InterpScratch,
+    ) -> Result<f64, Error> {
+        let points = [x, q2];
+        let policy = self.extrapolation_policy;
+        let Some(subgrid_idx) = self.cached_subgrid_index(&points, policy, scratch)? else {
+            return Ok(policy.fill_value());
+        };
+
+        let InterpScratch {
+            coords, edge, cell, ..
+        } = scratch;
+        match self.knot_array.pid_index(flavor_id) {
+            Some(pid_idx) => {
+                self.interpolate_into(subgrid_idx, pid_idx, &points, policy, coords, edge, cell)
+            }
+            None => Ok(0.0),
+        }
+    }
+
+    /// Interpolates the PDF values of all the flavors for `(x, q2)`, reusing a scratch buffer.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The momentum fraction.
+    /// * `q2` - The energy scale squared.
+    /// * `scratch` - The scratch buffer owned by the caller.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the interpolated PDF values, ordered as the PIDs of the grid, or
+    /// an `Error`.
+    pub fn eval_all_into<'a>(
+        &self,
+        x: f64,
+        q2: f64,
+        scratch: &'a mut InterpScratch,
+    ) -> Result<&'a [f64], Error> {
+        let points = [x, q2];
+        let policy = self.extrapolation_policy;
+        let subgrid_idx = self.cached_subgrid_index(&points, policy, scratch)?;
+
+        let InterpScratch {
+            coords,
+            values,
+            edge,
+            cell,
+            ..
+        } = scratch;
+        values.clear();
+        let Some(subgrid_idx) = subgrid_idx else {
+            values.resize(self.knot_array.pids.len(), policy.fill_value());
+            return Ok(values);
+        };
+        for pid_idx in 0..self.knot_array.pids.len() {
+            values.push(self.interpolate_into(
+                subgrid_idx,
+                pid_idx,
+                &points,
+                policy,
+                coords,
+                edge,
+                cell,
+            )?);
+        }
+
+        Ok(values)
+    }
+
+    /// Finds the index of the subgrid containing the point, according to `policy`.
+    ///
+    /// Returns `Ok(None)` if the point is out of range and `policy` is
+    /// `ExtrapolationPolicy::Nan` or `ExtrapolationPolicy::Constant`, in which case the
+    /// query evaluates to the fill value of the policy.
+    fn subgrid_index(
+        &self,
+        points: &[f64],
+        policy: ExtrapolationPolicy,
+    ) -> Result<Option<usize>, Error> {
+        let out_of_range = self.is_out_of_range(points);
+        let subgrid_idx = match policy {
+            ExtrapolationPolicy::Nan | ExtrapolationPolicy::Constant(_) if out_of_range => {
+                return Ok(None)
+            }
+            ExtrapolationPolicy::Error if out_of_range => None,
+            _ => self.knot_array.find_subgrid(points),
+        };
+
+        subgrid_idx.map(Some).ok_or_else(|| {
+            let (x, q2) = self.get_x_q2(points);
+            Error::SubgridNotFound { x, q2 }
+        })
+    }
+
+    /// Finds the index of the subgrid containing the point, trying the subgrid of the previous
+    /// query stored in `scratch` first.
+    ///
+    /// The cached subgrid is used if it is the first one containing the point, in which case
+    /// it is also the one found by [`GridPDF::subgrid_index`]. Otherwise the full search is
+    /// performed and, if the subgrid changes, the cached interpolation cell is invalidated.
+    fn cached_subgrid_index(
+        &self,
+        points: &[f64],
+        policy: ExtrapolationPolicy,
+        scratch: &mut InterpScratch,
+    ) -> Result<Option<usize>, Error> {
+        let subgrids = &self.knot_array.subgrids;
+        let cached = scratch.subgrid.filter(|&idx| {
+            subgrids
+                .get(idx)
+                .is_some_and(|sg| sg.contains_point(points))
+                && !subgrids[..idx].iter().any(|sg| sg.contains_point(points))
+        });
+        if cached.is_some() {
+            return Ok(cached);
+        }
+
+        let subgrid_idx = self.subgrid_index(points, policy)?;
+        if subgrid_idx != scratch.subgrid {
+            scratch.subgrid = subgrid_idx;
+            scratch.cell = [None; 2];
+        }
+        Ok(subgrid_idx)
+    }
+
+    /// Whether a point, with the scale given in `Q2`, is outside of the coverage of all the
+    /// subgrids.
+    fn is_out_of_range(&self, points: &[f64]) -> bool {
+        self.knot_array.subgrids.iter().all(|sg| {
+            points.len() == sg.interpolation_config().num_active_dims()
+                && !sg.contains_point(points)
+        })
+    }
+
+    /// Interpolates a given subgrid and flavor, using `coords` to store the coordinates.
+    ///
+    /// The search of the interpolation cell starts from `cell`, which is updated, see
+    /// [`DynInterpolator::interpolate_point_from`]. With `ExtrapolationPolicy::LogLinear`,
+    /// points below the smallest `x` of the subgrid are extrapolated from its first two `x`
+    /// knots, using `edge` to store the point moved to these knots.
+    #[allow(clippy::too_many_arguments)]
+    fn interpolate_into(
+        &self,
+        subgrid_idx: usize,
+        pid_idx: usize,
+        points: &[f64],
+        policy: ExtrapolationPolicy,
+        coords: &mut Vec<f64>,
+        edge: &mut Vec<f64>,
+        cell: &mut [Option<usize>; 2],
+    ) -> Result<f64, Error> {
+        let subgrid = &self.knot_array.subgrids[subgrid_idx];
+        let (x, q2) = self.get_x_q2(points);
+        if policy == ExtrapolationPolicy::LogLinear && x < subgrid.x_range.min {
+            if !self.low_x_extrapolation {
+                return Err(Error::SubgridNotFound { x, q2 });
+            }
+
+            let x_idx = points.len() - 2;
+            let (x0, x1) = (subgrid.xs[0], subgrid.xs[1]);
+            edge.clear();
+            edge.extend_from_slice(points);
+            edge[x_idx] = x0;
+            let f0 = self.interpolate_raw(subgrid_idx, pid_idx, edge, coords, cell)?;
+            edge[x_idx] = x1;
+            let f1 = self.interpolate_raw(subgrid_idx, pid_idx, edge, coords, cell)?;
+
+            let result = f0 + (f1 - f0) * (x / x0).ln() / (x1 / x0).ln();
+            return Ok(self.apply_force_positive(result));
+        }
+
+        self.interpolate_raw(subgrid_idx, pid_idx, points, coords, cell)
+            .map(|result| self.apply_force_positive(result))
+    }
+
+    /// Interpolates a given subgrid and flavor without clipping the result.
+    fn interpolate_raw(
+        &self,
+        subgrid_idx: usize,
+        pid_idx: usize,
+        points: &[f64],
+        coords: &mut Vec<f64>,
+        cell: &mut [Option<usize>; 2],
+    ) -> Result<f64, Error> {
+        self.fill_coords(pid_idx, points, coords);
+
+        self.interpolators[subgrid_idx][pid_idx]
+            .interpolate_point_from(coords, cell)
+            .map_err(|e| Error::InterpolationError(e.to_string()))
+    }
+
+    /// Whether an interpolator operates on the logarithm of the coordinates.
+    fn is_log_interpolator(interpolator: &InterpolatorType) -> bool {
+        matches!(
+            interpolator,
+            InterpolatorType::LogBilinear
+                | InterpolatorType::LogBicubic
+                | InterpolatorType::LogTricubic
+                | InterpolatorType::LogChebyshev
+        )
+    }
+
+    /// Whether the interpolation of a flavor is performed on the logarithm of the coordinates.
+    fn uses_log_coords(&self, pid_idx: usize) -> bool {
+        Self::is_log_interpolator(&self.interpolator_types[pid_idx])
+    }
+
+    /// Fills `coords` with the interpolation coordinates of `points` for a given flavor.
+    fn fill_coords(&self, pid_idx: usize, points: &[f64], coords: &mut Vec<f64>) {
+        let use_log = self.uses_log_coords(pid_idx);
+
+        coords.clear();
+        coords.extend(points.iter().map(|&p| if use_log { p.ln() } else { p }));
+    }
+
+    /// Interpolates PDF values for multiple points in parallel.
+    ///
+    /// # Arguments
+    ///
+    /// * `flavors` - A vector of flavor IDs.
+    /// * `slice_points` - A slice containing the collection of knots to interpolate on.
     ///   A knot is a collection of points containing `(nucleon, alphas, x, Q2)`.
     ///
     /// # Returns
@@ -337,6 +1327,197 @@ impl GridPDF {
         Array2::from_shape_vec(grid_shape, data).unwrap()
     }
 
+    /// Interpolates PDF values for a batch of paired `(x, Q2)` points.
+    ///
+    /// The `i`-th result corresponds to the point `(xs[i], q2s[i])`. An empty input returns
+    /// an empty vector. With [`ExtrapolationPolicy::Clamp`], the number of points outside of
+    /// the grid coverage is recorded, see [`GridPDF::last_batch_clamped_count`].
+    ///
+    /// # Arguments
+    ///
+    /// * `flavor_id` - The flavor ID.
+    /// * `xs` - The momentum fractions `x`.
+    /// * `q2s` - The energy scales `Q2`, one per `x` value.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<f64>` of interpolated PDF values, or `Error::MismatchedLengths` if `xs` and
+    /// `q2s` do not have the same length.
+    pub fn xfxq2_batch(&self, flavor_id: i32, xs: &[f64], q2s: &[f64]) -> Result<Vec<f64>, Error> {
+        if xs.len() != q2s.len() {
+            return Err(Error::MismatchedLengths {
+                xs: xs.len(),
+                q2s: q2s.len(),
+            });
+        }
+
+        // Only the clamping policy pays for the range checks.
+        let clamped = if self.extrapolation_policy == ExtrapolationPolicy::Clamp {
+            xs.iter()
+                .zip(q2s)
+                .filter(|(&x, &q2)| self.is_out_of_range(&[x, q2]))
+                .count()
+        } else {
+            0
+        };
+        self.last_batch_clamped.store(clamped, Ordering::Relaxed);
+
+        xs.iter()
+            .zip(q2s)
+            .map(|(&x, &q2)| self.xfxq2_in_q2(flavor_id, &[x, q2], self.extrapolation_policy))
+            .collect()
+    }
+
+    /// Returns the number of points of the last call to [`GridPDF::xfxq2_batch`] that were
+    /// clamped to the grid coverage.
+    ///
+    /// The points are only counted with [`ExtrapolationPolicy::Clamp`], such that the count
+    /// is zero for the other policies.
+    pub fn last_batch_clamped_count(&self) -> usize {
+        self.last_batch_clamped.load(Ordering::Relaxed)
+    }
+
+    /// Interpolates PDF values on the mesh spanned by the `x` and `Q2` values.
+    ///
+    /// Unlike [`GridPDF::xfxq2_batch`], the inputs are not paired and can therefore have
+    /// different lengths. An empty `xs` or `q2s` returns an empty array.
+    ///
+    /// # Arguments
+    ///
+    /// * `flavor_id` - The flavor ID.
+    /// * `xs` - The momentum fractions `x` defining the rows of the mesh.
+    /// * `q2s` - The energy scales `Q2` defining the columns of the mesh.
+    ///
+    /// # Returns
+    ///
+    /// A 2D array of interpolated PDF values with shape `[xs.len(), q2s.len()]`.
+    pub fn evaluate_on_grid(
+        &self,
+        flavor_id: i32,
+        xs: &[f64],
+        q2s: &[f64],
+    ) -> Result<Array2<f64>, Error> {
+        let mut mesh = Array2::zeros((xs.len(), q2s.len()));
+        for ((ix, iq2), value) in mesh.indexed_iter_mut() {
+            *value = self.xfxq2_in_q2(flavor_id, &[xs[ix], q2s[iq2]], self.extrapolation_policy)?;
+        }
+
+        Ok(mesh)
+    }
+
+    /// Estimates the interpolation error on the mesh spanned by the `x` and `Q2` values.
+    ///
+    /// The error at each point is estimated as the absolute difference between the
+    /// configured interpolator and a (log-)bilinear interpolation of the same knots. It
+    /// vanishes at the knots and grows with the curvature of the PDF between them.
+    ///
+    /// # Arguments
+    ///
+    /// * `flavor_id` - The flavor ID.
+    /// * `xs` - The momentum fractions `x` defining the rows of the mesh.
+    /// * `q2s` - The energy scales `Q2` defining the columns of the mesh.
+    ///
+    /// # Returns
+    ///
+    /// A 2D array of estimated errors with shape `[xs.len(), q2s.len()]`, or
+    /// `Error::IncompatibleInterpolator` if the grid does not only depend on `x` and `Q2`.
+    pub fn interpolation_error_map(
+        &self,
+        flavor_id: i32,
+        xs: &[f64],
+        q2s: &[f64],
+    ) -> Result<Array2<f64>, Error> {
+        let pid_idx = self.knot_array.pid_index(flavor_id);
+        let interpolator = pid_idx.map_or(&self.info.interpolator_type, |pid_idx| {
+            &self.interpolator_types[pid_idx]
+        });
+        let reference_type = if Self::is_log_interpolator(interpolator) {
+            InterpolatorType::LogBilinear
+        } else {
+            InterpolatorType::Bilinear
+        };
+        if !self
+            .knot_array
+            .subgrids
+            .iter()
+            .all(|sg| sg.interpolation_config().supports(&reference_type))
+        {
+            return Err(Error::IncompatibleInterpolator(reference_type));
+        }
+
+        let mut mesh = Array2::zeros((xs.len(), q2s.len()));
+        let Some(pid_idx) = pid_idx else {
+            return Ok(mesh);
+        };
+        let references: Vec<Box<dyn DynInterpolator>> = self
+            .knot_array
+            .subgrids
+            .iter()
+            .map(|sg| InterpolatorFactory::create(reference_type.clone(), sg, pid_idx))
+            .collect();
+
+        let mut coords = Vec::with_capacity(2);
+        for ((ix, iq2), error) in mesh.indexed_iter_mut() {
+            let points = [xs[ix], q2s[iq2]];
+            let Some(subgrid_idx) = self.subgrid_index(&points, self.extrapolation_policy)? else {
+                *error = f64::NAN;
+                continue;
+            };
+            self.fill_coords(pid_idx, &points, &mut coords);
+
+            let value = self.interpolators[subgrid_idx][pid_idx].interpolate_point(&coords);
+            let reference = references[subgrid_idx].interpolate_point(&coords);
+            *error = match (value, reference) {
+                (Ok(value), Ok(reference)) => (value - reference).abs(),
+                (Err(e), _) | (_, Err(e)) => return Err(Error::InterpolationError(e.to_string())),
+            };
+        }
+
+        Ok(mesh)
+    }
+
+    /// Interpolates the PDF values on an `(x, q2)` mesh, in parallel over the `x` values.
+    ///
+    /// The rows of the mesh are distributed over the `rayon` threads, such that the speedup
+    /// with respect to [`GridPDF::evaluate_on_grid`] is close to linear in the number of
+    /// threads for meshes with many more rows than threads. The results are identical to the
+    /// serial version.
+    ///
+    /// # Arguments
+    ///
+    /// * `flavor_id` - The flavor ID.
+    /// * `xs` - The momentum fractions `x` defining the rows of the mesh.
+    /// * `q2s` - The energy scales `q2` defining the columns of the mesh.
+    ///
+    /// # Returns
+    ///
+    /// A 2D array of interpolated PDF values with shape `[xs.len(), q2s.len()]`.
+    #[cfg(feature = "rayon")]
+    pub fn par_evaluate_on_grid(
+        &self,
+        flavor_id: i32,
+        xs: &[f64],
+        q2s: &[f64],
+    ) -> Result<Array2<f64>, Error> {
+        let mut mesh = Array2::zeros((xs.len(), q2s.len()));
+        if mesh.is_empty() {
+            return Ok(mesh);
+        }
+
+        mesh.as_slice_mut()
+            .expect("The mesh is in standard layout")
+            .par_chunks_mut(q2s.len())
+            .zip(xs)
+            .try_for_each(|(row, &x)| {
+                row.iter_mut().zip(q2s).try_for_each(|(value, &q2)| {
+                    *value = self.xfxq2_in_q2(flavor_id, &[x, q2], self.extrapolation_policy)?;
+                    Ok(())
+                })
+            })?;
+
+        Ok(mesh)
+    }
+
     /// Interpolates PDF values for multiple points in parallel using Chebyshev batch interpolation.
     ///
     /// # Arguments
@@ -358,27 +1539,38 @@ impl GridPDF {
             None => return Ok(vec![0.0; points.len()]),
         };
 
-        if !matches!(self.info.interpolator_type, InterpolatorType::LogChebyshev) {
+        if !matches!(
+            self.interpolator_types[pid_idx],
+            InterpolatorType::LogChebyshev
+        ) {
             return Err(Error::InterpolationError(
                 "xfxq2_cheby_batch only supports LogChebyshev interpolator".to_string(),
             ));
         }
 
+        let converted: Vec<Vec<f64>>;
+        let scaled: Vec<&[f64]>;
+        let points = match self.query_scale {
+            ScaleUnit::Q2 => points,
+            ScaleUnit::Q => {
+                converted = points.iter().map(|p| self.points_in_q2(p)).collect();
+                scaled = converted.iter().map(Vec::as_slice).collect();
+                &scaled
+            }
+        };
+
+        let mut all_results: Vec<(usize, f64)> = Vec::new();
         let mut subgrid_groups: HashMap<usize, Vec<(usize, &[f64])>> = HashMap::new();
         for (i, point) in points.iter().enumerate() {
-            let subgrid_idx = self.knot_array.find_subgrid(point).ok_or_else(|| {
-                let (x, q2) = self.get_x_q2(point);
-                Error::SubgridNotFound { x, q2 }
-            })?;
-
-            subgrid_groups
-                .entry(subgrid_idx)
-                .or_default()
-                .push((i, *point));
+            match self.subgrid_index(point, self.extrapolation_policy)? {
+                Some(subgrid_idx) => subgrid_groups
+                    .entry(subgrid_idx)
+                    .or_default()
+                    .push((i, *point)),
+                None => all_results.push((i, self.extrapolation_policy.fill_value())),
+            }
         }
 
-        let mut all_results: Vec<(usize, f64)> = Vec::new();
-
         for (subgrid_idx, group) in subgrid_groups {
             let subgrid = &self.knot_array.subgrids[subgrid_idx];
 
@@ -398,16 +1590,13 @@ impl GridPDF {
                 .map_err(|e| Error::InterpolationError(e.to_string()))?;
 
             for (original_index, result) in indices.into_iter().zip(results) {
-                all_results.push((original_index, result));
+                all_results.push((original_index, self.apply_force_positive(result)));
             }
         }
 
         // sort the results according to the original index
         all_results.sort_by_key(|&(i, _)| i);
-        let final_results = all_results
-            .into_iter()
-            .map(|(_, r)| self.apply_force_positive(r))
-            .collect();
+        let final_results = all_results.into_iter().map(|(_, r)| r).collect();
 
         Ok(final_results)
     }
@@ -455,6 +1644,163 @@ impl GridPDF {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::metadata::MetaDataV1;
+    use crate::test_support::xq2_subgrid_data;
+
+    fn test_gridpdf() -> GridPDF {
+        let xs = vec![1e-3, 1e-2, 1e-1, 1.0];
+        let q2s = vec![1.0, 10.0, 100.0, 1000.0];
+        let pids = vec![21, 2];
+        let grid_data = xs
+            .iter()
+            .flat_map(|&x| {
+                q2s.iter()
+                    .flat_map(move |&q2: &f64| [x * (1.0 + q2.ln()), x * x])
+            })
+            .collect();
+        let subgrid_data = vec![xq2_subgrid_data(xs, q2s, grid_data)];
+        let info = MetaData::new_v1(MetaDataV1 {
+            flavors: pids.clone(),
+            interpolator_type: InterpolatorType::LogBilinear,
+            ..Default::default()
+        });
+
+        GridPDF::new(info, GridArray::new(subgrid_data, pids))
+    }
+
+    #[test]
+    fn test_nearest_q2_index_across_subgrids() {
+        let subgrid_data = [vec![1.0, 2.0, 4.0], vec![4.0, 8.0, 16.0]]
+            .into_iter()
+            .map(|q2s| {
+                let grid_data = vec![0.0; 2 * q2s.len()];
+                xq2_subgrid_data(vec![0.1, 0.5], q2s, grid_data)
+            })
+            .collect();
+        let grid = GridArray::new(subgrid_data, vec![21]);
+
+        assert_eq!(grid.nearest_q2_index(1.4), 0);
+        assert_eq!(grid.nearest_q2_index(3.5), 2);
+        assert_eq!(grid.nearest_q2_index(7.0), 4);
+        assert_eq!(grid.nearest_q2_index(16.0), 5);
+        assert_eq!(grid.nearest_q2_index(1e3), 5);
+        assert_eq!(grid.nearest_q2_index(0.1), 0);
+        assert_eq!(grid.nearest_x_index(0.35), 1);
+    }
+
+    #[test]
+    fn test_find_subgrid_by_q2() {
+        let subgrid_data = (0..16)
+            .map(|i| {
+                let q2s = vec![2f64.powi(i), 1.5 * 2f64.powi(i), 2f64.powi(i + 1)];
+                xq2_subgrid_data(vec![0.1, 0.5], q2s, vec![0.0; 6])
+            })
+            .collect();
+        let grid = GridArray::new(subgrid_data, vec![21]);
+        assert!(grid.is_sorted_q2_tiling());
+
+        let boundaries = (0..=16).map(|i| 2f64.powi(i));
+        let samples = (0..1000).map(|i| 10f64.powf(-0.5 + 6.0 * f64::from(i) / 999.0));
+        for q2 in boundaries.chain(samples) {
+            let linear = grid.subgrids.iter().position(|sg| sg.q2_range.contains(q2));
+            assert_eq!(grid.find_subgrid_by_q2(q2), linear, "q2 = {q2}");
+        }
+        assert_eq!(grid.find_subgrid_by_q2(0.5), None);
+        assert_eq!(grid.find_subgrid_by_q2(1e6), None);
+
+        // The subgrids in reverse order are scanned linearly, also in release builds
+        let mut reversed = grid.clone();
+        reversed.subgrids.reverse();
+        assert!(!reversed.is_sorted_q2_tiling());
+        let info = MetaData::new_v1(MetaDataV1 {
+            flavors: vec![21],
+            interpolator_type: InterpolatorType::LogBilinear,
+            ..Default::default()
+        });
+        let gpdf = GridPDF::new(info, reversed.clone());
+        for q2 in [1.0, 3.0, 2f64.powi(8), 5e4] {
+            let linear = reversed
+                .subgrids
+                .iter()
+                .position(|sg| sg.q2_range.contains(q2));
+            assert!(linear.is_some());
+            assert_eq!(reversed.find_subgrid_by_q2(q2), linear, "q2 = {q2}");
+            assert_eq!(gpdf.find_subgrid_by_q2(q2), linear, "q2 = {q2}");
+        }
+    }
+
+    #[test]
+    fn test_q2_slices() {
+        let subgrid_data = [vec![1.0, 2.0, 4.0], vec![4.0, 8.0, 16.0]]
+            .into_iter()
+            .map(|q2s| {
+                let grid_data = (0..4 * q2s.len()).map(|v| v as f64).collect();
+                xq2_subgrid_data(vec![0.1, 0.5], q2s, grid_data)
+            })
+            .collect();
+        let grid = GridArray::new(subgrid_data, vec![1, 21]);
+
+        let slices: Vec<_> = grid.q2_slices().unwrap().collect();
+        assert_eq!(slices.len(), 6);
+        for (q2_idx, (q2, values)) in slices.iter().enumerate() {
+            assert_eq!(*q2, [1.0, 2.0, 4.0, 4.0, 8.0, 16.0][q2_idx]);
+            assert_eq!(values.dim(), (2, 2));
+            for (pid_idx, &pid) in [1, 21].iter().enumerate() {
+                for x_idx in 0..2 {
+                    assert_eq!(
+                        values[[pid_idx, x_idx]],
+                        grid.node_value(pid, x_idx, q2_idx).unwrap()
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_node_value_out_of_bounds() {
+        let subgrid_data = [vec![0.1, 0.5], vec![0.1, 0.3, 0.5]]
+            .into_iter()
+            .zip([vec![1.0, 2.0, 4.0], vec![4.0, 8.0, 16.0]])
+            .map(|(xs, q2s)| {
+                let grid_data = vec![1.0; xs.len() * q2s.len()];
+                xq2_subgrid_data(xs, q2s, grid_data)
+            })
+            .collect();
+        let grid = GridArray::new(subgrid_data, vec![21]);
+
+        // The `x` index is checked against the knots of the subgrid owning the `q2` index
+        assert!(matches!(
+            grid.node_value(21, 2, 0),
+            Err(Error::NodeIndexOutOfBounds {
+                x_index: 2,
+                q2_index: 0
+            })
+        ));
+        assert_eq!(grid.node_value(21, 2, 3).unwrap(), 1.0);
+        assert!(grid.node_value(21, 0, 6).is_err());
+    }
+
+    #[test]
+    fn test_pid_index_and_flavor_grid() {
+        let subgrid_data = vec![xq2_subgrid_data(
+            vec![0.1, 0.5],
+            vec![1.0, 10.0],
+            (0..12).map(f64::from).collect(),
+        )];
+        let grid = GridArray::new(subgrid_data, vec![-1, 21, 22]);
+
+        assert_eq!(grid.pid_index(21), Some(1));
+        assert_eq!(grid.pid_index(0), Some(1));
+        assert_eq!(grid.pid_index(22), Some(2));
+        assert_eq!(grid.pid_index(3), None);
+
+        let photon = grid.flavor_grid(0, 22, &[]).unwrap();
+        assert_eq!(photon, grid.subgrids[0].grid_slice(2));
+        assert!(matches!(
+            grid.flavor_grid(0, 3, &[]),
+            Err(Error::FlavorNotFound { pid: 3 })
+        ));
+    }
 
     #[test]
     fn test_grid_array_creation() {
@@ -474,4 +1820,221 @@ mod tests {
         assert_eq!(grid_array.subgrids[0].grid.shape(), &[1, 1, 2, 1, 3, 2]);
         assert!(grid_array.find_subgrid(&[1.5, 4.5]).is_some());
     }
+
+    #[test]
+    fn test_xfxq2_batch_empty_and_single() {
+        let gpdf = test_gridpdf();
+
+        assert!(gpdf.xfxq2_batch(21, &[], &[]).unwrap().is_empty());
+
+        let single = gpdf.xfxq2_batch(21, &[1e-2], &[10.0]).unwrap();
+        assert_eq!(single.len(), 1);
+        assert_eq!(single[0], gpdf.xfxq2(21, &[1e-2, 10.0]).unwrap());
+    }
+
+    #[test]
+    fn test_xfxq2_batch_mismatched_lengths() {
+        let gpdf = test_gridpdf();
+
+        let err = gpdf.xfxq2_batch(21, &[1e-2, 1e-1], &[10.0]).unwrap_err();
+        assert!(matches!(err, Error::MismatchedLengths { xs: 2, q2s: 1 }));
+        assert_eq!(
+            err.to_string(),
+            "Mismatched input lengths: xs has 2 elements but q2s has 1"
+        );
+    }
+
+    #[test]
+    fn test_eval_into() {
+        let mut gpdf = test_gridpdf();
+        let mut scratch = InterpScratch::new();
+
+        for (x, q2) in [(1e-3, 1.0), (2e-2, 5.0), (0.5, 500.0), (1.0, 1000.0)] {
+            for pid in [21, 2, 3] {
+                assert_eq!(
+                    gpdf.eval_into(pid, x, q2, &mut scratch).unwrap(),
+                    gpdf.xfxq2(pid, &[x, q2]).unwrap()
+                );
+            }
+
+            let values = gpdf.eval_all_into(x, q2, &mut scratch).unwrap();
+            assert_eq!(
+                values,
+                [
+                    gpdf.xfxq2(21, &[x, q2]).unwrap(),
+                    gpdf.xfxq2(2, &[x, q2]).unwrap()
+                ]
+            );
+        }
+
+        // The extrapolated queries reuse the edge buffer of the scratch
+        gpdf.set_extrapolation_policy(ExtrapolationPolicy::LogLinear);
+        gpdf.eval_into(21, 5e-4, 5.0, &mut scratch).unwrap();
+        let edge = scratch.edge.as_ptr();
+        for x in [1e-4, 2e-4, 5e-4] {
+            assert_eq!(
+                gpdf.eval_into(21, x, 5.0, &mut scratch).unwrap(),
+                gpdf.xfxq2(21, &[x, 5.0]).unwrap()
+            );
+            assert_eq!(scratch.edge.as_ptr(), edge);
+        }
+    }
+
+    #[test]
+    fn test_interpolate_traced() {
+        let mut gpdf = test_gridpdf();
+
+        let (value, trace) = gpdf.interpolate_traced(21, &[2e-2, 50.0]).unwrap();
+        assert_eq!(value, gpdf.xfxq2(21, &[2e-2, 50.0]).unwrap());
+        assert_eq!(
+            trace,
+            InterpTrace {
+                subgrid: Some(0),
+                brackets: vec![(Axis::X, [1, 2]), (Axis::Q2, [1, 2])],
+                interpolator: InterpolatorType::LogBilinear,
+            }
+        );
+
+        // The knots themselves, including the last ones, are bracketed by their cell.
+        let (_, trace) = gpdf.interpolate_traced(2, &[1e-3, 1000.0]).unwrap();
+        assert_eq!(trace.brackets, vec![(Axis::X, [0, 1]), (Axis::Q2, [2, 3])]);
+
+        gpdf.set_extrapolation_policy(ExtrapolationPolicy::Clamp);
+        let (value, trace) = gpdf.interpolate_traced(21, &[0.5, 5000.0]).unwrap();
+        assert_eq!(value, gpdf.xfxq2(21, &[0.5, 1000.0]).unwrap());
+        assert_eq!(trace.brackets, vec![(Axis::X, [2, 3]), (Axis::Q2, [2, 3])]);
+
+        gpdf.set_extrapolation_policy(ExtrapolationPolicy::Nan);
+        let (value, trace) = gpdf.interpolate_traced(21, &[0.5, 5000.0]).unwrap();
+        assert!(value.is_nan());
+        assert_eq!((trace.subgrid, trace.brackets), (None, vec![]));
+
+        let subgrid_data = [vec![1.0, 2.0, 4.0], vec![4.0, 8.0, 16.0]]
+            .into_iter()
+            .map(|q2s| {
+                let grid_data = vec![1.0; 3 * q2s.len()];
+                xq2_subgrid_data(vec![0.1, 0.2, 0.5], q2s, grid_data)
+            })
+            .collect();
+        let info = MetaData::new_v1(MetaDataV1 {
+            flavors: vec![21],
+            interpolator_type: InterpolatorType::Bilinear,
+            ..Default::default()
+        });
+        let gpdf = GridPDF::new(info, GridArray::new(subgrid_data, vec![21]));
+        let (value, trace) = gpdf.interpolate_traced(21, &[0.3, 10.0]).unwrap();
+        assert_eq!(value, 1.0);
+        assert_eq!(trace.subgrid, Some(1));
+        assert_eq!(trace.brackets, vec![(Axis::X, [1, 2]), (Axis::Q2, [1, 2])]);
+        assert_eq!(trace.interpolator, InterpolatorType::Bilinear);
+    }
+
+    #[test]
+    fn test_eval_into_cache() {
+        let xs: Vec<f64> = (0..8)
+            .map(|i| 10f64.powf(-4.0 + 4.0 * i as f64 / 7.0))
+            .collect();
+        let subgrid_data = [vec![1.0, 2.0, 4.0, 8.0, 16.0], vec![16.0, 64.0, 256.0, 1e3]]
+            .into_iter()
+            .map(|q2s| {
+                let grid_data = xs
+                    .iter()
+                    .flat_map(|&x| {
+                        q2s.iter()
+                            .flat_map(move |&q2: &f64| [x.powf(-0.3) * (1.0 - x), x * q2.ln()])
+                    })
+                    .collect();
+                xq2_subgrid_data(xs.clone(), q2s, grid_data)
+            })
+            .collect();
+        let info = MetaData::new_v1(MetaDataV1 {
+            flavors: vec![21, 2],
+            interpolator_type: InterpolatorType::LogBicubic,
+            ..Default::default()
+        });
+        let gpdf = GridPDF::new(info, GridArray::new(subgrid_data, vec![21, 2]));
+
+        // Nearby queries crossing the boundary between the subgrids back and forth, the edges
+        // of the grid, and jumps across it
+        let mut points: Vec<(f64, f64)> = (0..200)
+            .map(|i| {
+                let t = f64::from(i) / 199.0;
+                (
+                    1e-3 * 10f64.powf(2.0 * t),
+                    16.0 * 4f64.powf((8.0 * t).sin()),
+                )
+            })
+            .collect();
+        points.extend([
+            (1e-4, 1.0),
+            (1.0, 1e3),
+            (0.5, 16.0),
+            (1e-4, 5.0),
+            (0.2, 999.0),
+        ]);
+        points.extend([(1e-2, 3.0), (1.1e-2, 3.1), (1e-2, 300.0), (1.1e-2, 310.0)]);
+
+        let mut scratch = InterpScratch::new();
+        for &(x, q2) in &points {
+            for pid in [21, 2] {
+                assert_eq!(
+                    gpdf.eval_into(pid, x, q2, &mut scratch).unwrap(),
+                    gpdf.xfxq2(pid, &[x, q2]).unwrap(),
+                    "x = {x}, q2 = {q2}"
+                );
+            }
+            let cold = gpdf.xfxq2_all(&[x, q2]).unwrap();
+            assert_eq!(gpdf.eval_all_into(x, q2, &mut scratch).unwrap(), cold);
+        }
+    }
+
+    #[test]
+    fn test_evaluate_on_grid() {
+        let gpdf = test_gridpdf();
+
+        assert_eq!(
+            gpdf.evaluate_on_grid(2, &[], &[10.0]).unwrap().shape(),
+            &[0, 1]
+        );
+        assert_eq!(
+            gpdf.evaluate_on_grid(2, &[1e-2], &[]).unwrap().shape(),
+            &[1, 0]
+        );
+
+        let single = gpdf.evaluate_on_grid(2, &[1e-2], &[10.0]).unwrap();
+        assert_eq!(single.shape(), &[1, 1]);
+        assert_eq!(single[[0, 0]], gpdf.xfxq2(2, &[1e-2, 10.0]).unwrap());
+
+        let xs = [1e-3, 5e-2, 1.0];
+        let q2s = [1.0, 50.0];
+        let mesh = gpdf.evaluate_on_grid(21, &xs, &q2s).unwrap();
+        assert_eq!(mesh.shape(), &[3, 2]);
+        for (ix, &x) in xs.iter().enumerate() {
+            for (iq2, &q2) in q2s.iter().enumerate() {
+                assert_eq!(mesh[[ix, iq2]], gpdf.xfxq2(21, &[x, q2]).unwrap());
+            }
+        }
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_par_evaluate_on_grid() {
+        let gpdf = test_gridpdf();
+        let xs: Vec<f64> = (0..64)
+            .map(|i| 10f64.powf(-3.0 + 3.0 * i as f64 / 63.0))
+            .collect();
+        let q2s: Vec<f64> = (0..32).map(|i| 10f64.powf(3.0 * i as f64 / 31.0)).collect();
+
+        for pid in [21, 2] {
+            let serial = gpdf.evaluate_on_grid(pid, &xs, &q2s).unwrap();
+            let parallel = gpdf.par_evaluate_on_grid(pid, &xs, &q2s).unwrap();
+            assert_eq!(serial.shape(), parallel.shape());
+            assert!(serial.iter().zip(&parallel).all(|(s, p)| s == p));
+        }
+
+        assert_eq!(
+            gpdf.par_evaluate_on_grid(2, &xs, &[]).unwrap().shape(),
+            &[64, 0]
+        );
+    }
 }
@@ -11,7 +11,7 @@
 //! Interpolation strategies are defined in `strategy.rs`.
 //! The [`SubGrid`] struct is defined in `subgrid.rs`.
 
-use ndarray::{s, OwnedRepr};
+use ndarray::{s, Array1, IxDyn, OwnedRepr};
 use ninterp::data::{InterpData2D, InterpData3D};
 use ninterp::error::InterpolateError;
 use ninterp::interpolator::{
@@ -28,11 +28,26 @@ use super::strategy::{
 };
 use super::subgrid::SubGrid;
 
+/// Represents a coordinate axis of the PDF grids.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Axis {
+    /// The nucleon numbers `A`.
+    Nucleons,
+    /// The strong coupling `alpha_s`.
+    AlphaS,
+    /// The transverse momentum `kT`.
+    Kt,
+    /// The momentum fraction `x`.
+    X,
+    /// The energy scale `Q²`.
+    Q2,
+}
+
 /// Represents the dimensionality and structure of interpolation needed.
 ///
 /// This enum is used to select the appropriate interpolation strategy based on the
 /// dimensions of the PDF grid data.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum InterpolationConfig {
     /// 2D interpolation, typically in `x` (momentum fraction) and `Q²` (energy scale).
     TwoD,
@@ -73,17 +88,205 @@ impl InterpolationConfig {
             (true, true, true) => Self::FiveD,
         }
     }
+
+    /// Checks whether an interpolator can be built for this configuration.
+    pub fn supports(&self, interp_type: &InterpolatorType) -> bool {
+        match self {
+            Self::TwoD => matches!(
+                interp_type,
+                InterpolatorType::Bilinear
+                    | InterpolatorType::LogBilinear
+                    | InterpolatorType::LogBicubic
+                    | InterpolatorType::LogChebyshev
+            ),
+            Self::ThreeDNucleons | Self::ThreeDAlphas | Self::ThreeDKt => matches!(
+                interp_type,
+                InterpolatorType::LogTricubic | InterpolatorType::LogChebyshev
+            ),
+            Self::FourDNucleonsAlphas
+            | Self::FourDNucleonsKt
+            | Self::FourDAlphasKt
+            | Self::FiveD => matches!(interp_type, InterpolatorType::InterpNDLinear),
+        }
+    }
+
+    /// Returns the number of coordinates expected by this configuration.
+    pub fn num_active_dims(&self) -> usize {
+        match self {
+            Self::TwoD => 2,
+            Self::ThreeDNucleons | Self::ThreeDAlphas | Self::ThreeDKt => 3,
+            Self::FourDNucleonsAlphas | Self::FourDNucleonsKt | Self::FourDAlphasKt => 4,
+            Self::FiveD => 5,
+        }
+    }
+
+    /// Returns the axes of this configuration, in the order in which the coordinates of a
+    /// point are expected.
+    pub fn axis_order(&self) -> Vec<Axis> {
+        let mut axes = match self {
+            Self::TwoD => vec![],
+            Self::ThreeDNucleons => vec![Axis::Nucleons],
+            Self::ThreeDAlphas => vec![Axis::AlphaS],
+            Self::ThreeDKt => vec![Axis::Kt],
+            Self::FourDNucleonsAlphas => vec![Axis::Nucleons, Axis::AlphaS],
+            Self::FourDNucleonsKt => vec![Axis::Nucleons, Axis::Kt],
+            Self::FourDAlphasKt => vec![Axis::AlphaS, Axis::Kt],
+            Self::FiveD => vec![Axis::Nucleons, Axis::AlphaS, Axis::Kt],
+        };
+        axes.extend([Axis::X, Axis::Q2]);
+        axes
+    }
+}
+
+/// A query point with named coordinates.
+///
+/// The momentum fraction and the scale are always required, while the other coordinates are
+/// only needed by the grids depending on them. [`Point::to_ordered`] produces the slice
+/// expected by the point-based interpolation methods for a given [`InterpolationConfig`].
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Point {
+    /// The nucleon number `A`.
+    pub nucleons: Option<f64>,
+    /// The strong coupling `alpha_s`.
+    pub alphas: Option<f64>,
+    /// The transverse momentum `kT`.
+    pub kt: Option<f64>,
+    /// The momentum fraction `x`.
+    pub x: f64,
+    /// The energy scale `Q²`.
+    pub q2: f64,
+}
+
+impl Point {
+    /// Creates a point in `x` and `Q²` only.
+    pub fn new(x: f64, q2: f64) -> Self {
+        Self {
+            x,
+            q2,
+            ..Default::default()
+        }
+    }
+
+    /// Sets the nucleon number `A` of the point.
+    pub fn nucleons(mut self, nucleons: f64) -> Self {
+        self.nucleons = Some(nucleons);
+        self
+    }
+
+    /// Sets the strong coupling `alpha_s` of the point.
+    pub fn alphas(mut self, alphas: f64) -> Self {
+        self.alphas = Some(alphas);
+        self
+    }
+
+    /// Sets the transverse momentum `kT` of the point.
+    pub fn kt(mut self, kt: f64) -> Self {
+        self.kt = Some(kt);
+        self
+    }
+
+    /// Returns the coordinate of the point along an axis, if it is set.
+    pub fn coordinate(&self, axis: Axis) -> Option<f64> {
+        match axis {
+            Axis::Nucleons => self.nucleons,
+            Axis::AlphaS => self.alphas,
+            Axis::Kt => self.kt,
+            Axis::X => Some(self.x),
+            Axis::Q2 => Some(self.q2),
+        }
+    }
+
+    /// Arranges the coordinates in the order expected by a given configuration.
+    ///
+    /// The coordinates along the axes that are not part of `config` are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - The configuration of the grid that is queried.
+    ///
+    /// # Returns
+    ///
+    /// The coordinates in the order of [`InterpolationConfig::axis_order`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if one of the axes of `config` is not set on the point.
+    pub fn to_ordered(&self, config: InterpolationConfig) -> Vec<f64> {
+        config
+            .axis_order()
+            .into_iter()
+            .map(|axis| {
+                self.coordinate(axis)
+                    .unwrap_or_else(|| panic!("The point has no coordinate along {axis:?}"))
+            })
+            .collect()
+    }
+}
+
+impl From<(f64, f64)> for Point {
+    fn from((x, q2): (f64, f64)) -> Self {
+        Self::new(x, q2)
+    }
 }
 
 /// A trait for dynamic interpolation across different dimensions.
 pub trait DynInterpolator: Send + Sync {
     fn interpolate_point(&self, point: &[f64]) -> Result<f64, InterpolateError>;
+
+    /// Interpolates a point, starting the search of the interpolation cell from `cell`.
+    ///
+    /// `cell` holds the lower knot indices along `x` and `Q2` of the cell of a previous
+    /// point, and is updated with the ones of `point`. The result is the one of
+    /// [`DynInterpolator::interpolate_point`]; the interpolators that do not search the cell
+    /// themselves ignore `cell`.
+    fn interpolate_point_from(
+        &self,
+        point: &[f64],
+        cell: &mut [Option<usize>; 2],
+    ) -> Result<f64, InterpolateError> {
+        let _ = cell;
+        self.interpolate_point(point)
+    }
+}
+
+/// 2D interpolation strategies that can start the search of the interpolation cell from the
+/// cell of a previous point.
+pub trait CellSearch2D: Strategy2D<OwnedRepr<f64>> {
+    /// Interpolates a point inside the grid, trying `cell` first and updating it.
+    ///
+    /// The default implementation ignores `cell` and calls [`Strategy2D::interpolate`].
+    fn interpolate_from_cell(
+        &self,
+        data: &InterpData2D<OwnedRepr<f64>>,
+        point: &[f64; 2],
+        cell: &mut [Option<usize>; 2],
+    ) -> Result<f64, InterpolateError> {
+        let _ = cell;
+        self.interpolate(data, point)
+    }
+}
+
+impl CellSearch2D for BilinearInterpolation {}
+
+impl CellSearch2D for LogBilinearInterpolation {}
+
+impl CellSearch2D for LogChebyshevInterpolation<2> {}
+
+impl CellSearch2D for LogBicubicInterpolation {
+    fn interpolate_from_cell(
+        &self,
+        data: &InterpData2D<OwnedRepr<f64>>,
+        point: &[f64; 2],
+        cell: &mut [Option<usize>; 2],
+    ) -> Result<f64, InterpolateError> {
+        self.interpolate_from(data, point, cell)
+    }
 }
 
 // Implement `DynInterpolator` for 2D interpolators.
 impl<S> DynInterpolator for Interp2DOwned<f64, S>
 where
-    S: Strategy2D<OwnedRepr<f64>> + 'static + Clone + Send + Sync,
+    S: CellSearch2D + 'static + Clone + Send + Sync,
 {
     fn interpolate_point(&self, point: &[f64]) -> Result<f64, InterpolateError> {
         let [x, y] = point
@@ -91,6 +294,25 @@ where
             .map_err(|_| InterpolateError::Other("Expected 2D point".to_string()))?;
         self.interpolate(&[x, y])
     }
+
+    fn interpolate_point_from(
+        &self,
+        point: &[f64],
+        cell: &mut [Option<usize>; 2],
+    ) -> Result<f64, InterpolateError> {
+        let [x, y] = point
+            .try_into()
+            .map_err(|_| InterpolateError::Other("Expected 2D point".to_string()))?;
+        if !matches!(self.extrapolate, Extrapolate::Clamp) {
+            return self.interpolate(&[x, y]);
+        }
+
+        // Same clamping as `Interp2D::interpolate` with `Extrapolate::Clamp`
+        let clamp = |value: f64, knots: &Array1<f64>| value.clamp(knots[0], knots[knots.len() - 1]);
+        let point = [clamp(x, &self.data.grid[0]), clamp(y, &self.data.grid[1])];
+        self.strategy
+            .interpolate_from_cell(&self.data, &point, cell)
+    }
 }
 
 // Implement `DynInterpolator` for 3D interpolators.
@@ -159,7 +381,8 @@ impl InterpolatorFactory {
         subgrid: &SubGrid,
         pid_index: usize,
     ) -> Box<dyn DynInterpolator> {
-        match subgrid.interpolation_config() {
+        let config = subgrid.interpolation_config();
+        match config {
             InterpolationConfig::TwoD => Self::interpolator_xfxq2(interp_type, subgrid, pid_index),
             InterpolationConfig::ThreeDNucleons => {
                 Self::interpolator_xfxq2_nucleons(interp_type, subgrid, pid_index)
@@ -170,18 +393,17 @@ impl InterpolatorFactory {
             InterpolationConfig::ThreeDKt => {
                 Self::interpolator_xfxq2_kts(interp_type, subgrid, pid_index)
             }
-            InterpolationConfig::FourDNucleonsAlphas => {
-                Self::interpolator_xfxq2_nucleons_alphas(interp_type, subgrid, pid_index)
-            }
-            InterpolationConfig::FourDNucleonsKt => {
-                Self::interpolator_xfxq2_nucleons_kts(interp_type, subgrid, pid_index)
-            }
-            InterpolationConfig::FourDAlphasKt => {
-                Self::interpolator_xfxq2_alphas_kts(interp_type, subgrid, pid_index)
-            }
-            InterpolationConfig::FiveD => {
-                Self::interpolator_xfxq2_5dim(interp_type, subgrid, pid_index)
-            }
+            InterpolationConfig::FourDNucleonsAlphas
+            | InterpolationConfig::FourDNucleonsKt
+            | InterpolationConfig::FourDAlphasKt
+            | InterpolationConfig::FiveD => match interp_type {
+                InterpolatorType::InterpNDLinear => Self::interpolator_ndlinear(subgrid, pid_index),
+                _ => panic!(
+                    "Unsupported {}D interpolator: {:?}",
+                    config.num_active_dims(),
+                    interp_type
+                ),
+            },
         }
     }
 
@@ -357,138 +579,32 @@ impl InterpolatorFactory {
         }
     }
 
-    fn interpolator_xfxq2_nucleons_alphas(
-        interp_type: InterpolatorType,
-        subgrid: &SubGrid,
-        pid_index: usize,
-    ) -> Box<dyn DynInterpolator> {
-        let grid_data = subgrid
-            .grid
-            .slice(s![.., .., pid_index, 0, .., ..])
-            .to_owned();
-        let coords = vec![
-            subgrid.nucleons.to_owned(),
-            subgrid.alphas.to_owned(),
-            subgrid.xs.to_owned(),
-            subgrid.q2s.to_owned(),
-        ];
-        let reshaped_data = grid_data
-            .into_shape_with_order((
-                subgrid.nucleons.len(),
-                subgrid.alphas.len(),
-                subgrid.xs.len(),
-                subgrid.q2s.len(),
-            ))
-            .expect("Failed to reshape 4D data");
-
-        match interp_type {
-            InterpolatorType::InterpNDLinear => Box::new(
-                InterpND::new(coords, reshaped_data.into_dyn(), Linear, Extrapolate::Clamp)
-                    .expect("Failed to create 4D interpolator"),
-            ),
-            _ => panic!("Unsupported 4D interpolator: {:?}", interp_type),
-        }
-    }
-
-    fn interpolator_xfxq2_nucleons_kts(
-        interp_type: InterpolatorType,
-        subgrid: &SubGrid,
-        pid_index: usize,
-    ) -> Box<dyn DynInterpolator> {
-        let grid_data = subgrid
-            .grid
-            .slice(s![.., 0, pid_index, .., .., ..])
-            .to_owned();
-        let coords = vec![
-            subgrid.nucleons.mapv(f64::ln),
-            subgrid.kts.mapv(f64::ln),
-            subgrid.xs.mapv(f64::ln),
-            subgrid.q2s.mapv(f64::ln),
-        ];
-        let reshaped_data = grid_data
-            .into_shape_with_order((
-                subgrid.nucleons.len(),
-                subgrid.kts.len(),
-                subgrid.xs.len(),
-                subgrid.q2s.len(),
-            ))
-            .expect("Failed to reshape 4D data");
-
-        match interp_type {
-            InterpolatorType::InterpNDLinear => Box::new(
-                InterpND::new(coords, reshaped_data.into_dyn(), Linear, Extrapolate::Clamp)
-                    .expect("Failed to create 4D interpolator"),
-            ),
-            _ => panic!("Unsupported 4D interpolator: {:?}", interp_type),
-        }
-    }
-
-    fn interpolator_xfxq2_alphas_kts(
-        interp_type: InterpolatorType,
-        subgrid: &SubGrid,
-        pid_index: usize,
-    ) -> Box<dyn DynInterpolator> {
+    /// Creates a multilinear interpolator over the axes of the subgrid with more than one
+    /// knot, in the order of [`InterpolationConfig::axis_order`].
+    ///
+    /// The interpolation is performed on the coordinates themselves, not their logarithms,
+    /// and the axes with a single knot are treated as constant.
+    fn interpolator_ndlinear(subgrid: &SubGrid, pid_index: usize) -> Box<dyn DynInterpolator> {
+        let axes = subgrid.interpolation_config().axis_order();
+        let coords: Vec<_> = axes
+            .iter()
+            .map(|&axis| subgrid.knots(axis).to_owned())
+            .collect();
+        let shape: Vec<usize> = coords.iter().map(|knots| knots.len()).collect();
+        // The axes that are not part of the configuration have a single knot, such that the
+        // values of the flavor can be reshaped onto the active axes only.
         let grid_data = subgrid
             .grid
-            .slice(s![0, .., pid_index, .., .., ..])
-            .to_owned();
-        let coords = vec![
-            subgrid.alphas.mapv(f64::ln),
-            subgrid.kts.mapv(f64::ln),
-            subgrid.xs.mapv(f64::ln),
-            subgrid.q2s.mapv(f64::ln),
-        ];
-        let reshaped_data = grid_data
-            .into_shape_with_order((
-                subgrid.alphas.len(),
-                subgrid.kts.len(),
-                subgrid.xs.len(),
-                subgrid.q2s.len(),
-            ))
-            .expect("Failed to reshape 4D data");
-
-        match interp_type {
-            InterpolatorType::InterpNDLinear => Box::new(
-                InterpND::new(coords, reshaped_data.into_dyn(), Linear, Extrapolate::Clamp)
-                    .expect("Failed to create 4D interpolator"),
-            ),
-            _ => panic!("Unsupported 4D interpolator: {:?}", interp_type),
-        }
-    }
-
-    fn interpolator_xfxq2_5dim(
-        interp_type: InterpolatorType,
-        subgrid: &SubGrid,
-        pid_index: usize,
-    ) -> Box<dyn DynInterpolator> {
-        let grid_data = subgrid
-            .grid
-            .slice(s![.., .., pid_index, .., .., ..])
-            .to_owned();
-        let coords = vec![
-            subgrid.nucleons.mapv(f64::ln),
-            subgrid.alphas.mapv(f64::ln),
-            subgrid.kts.mapv(f64::ln),
-            subgrid.xs.mapv(f64::ln),
-            subgrid.q2s.mapv(f64::ln),
-        ];
-        let reshaped_data = grid_data
-            .into_shape_with_order((
-                subgrid.nucleons.len(),
-                subgrid.alphas.len(),
-                subgrid.kts.len(),
-                subgrid.xs.len(),
-                subgrid.q2s.len(),
-            ))
-            .expect("Failed to reshape 5D data");
-
-        match interp_type {
-            InterpolatorType::InterpNDLinear => Box::new(
-                InterpND::new(coords, reshaped_data.into_dyn(), Linear, Extrapolate::Clamp)
-                    .expect("Failed to create 5D interpolator"),
-            ),
-            _ => panic!("Unsupported 5D interpolator: {:?}", interp_type),
-        }
+            .index_axis(ndarray::Axis(2), pid_index)
+            .as_standard_layout()
+            .into_owned()
+            .into_shape_with_order(IxDyn(&shape))
+            .expect("Failed to reshape N-D data");
+
+        Box::new(
+            InterpND::new(coords, grid_data, Linear, Extrapolate::Clamp)
+                .expect("Failed to create N-D interpolator"),
+        )
     }
 
     pub fn create_batch_interpolator(
@@ -587,6 +703,56 @@ mod tests {
 
     const MAXDIFF: f64 = 1e-15;
 
+    #[test]
+    fn test_axis_order() {
+        for config in [
+            InterpolationConfig::TwoD,
+            InterpolationConfig::ThreeDKt,
+            InterpolationConfig::FourDAlphasKt,
+            InterpolationConfig::FiveD,
+        ] {
+            assert_eq!(config.axis_order().len(), config.num_active_dims());
+        }
+        assert_eq!(
+            InterpolationConfig::FiveD.axis_order(),
+            vec![Axis::Nucleons, Axis::AlphaS, Axis::Kt, Axis::X, Axis::Q2]
+        );
+        assert_eq!(
+            InterpolationConfig::FourDNucleonsKt.axis_order(),
+            vec![Axis::Nucleons, Axis::Kt, Axis::X, Axis::Q2]
+        );
+    }
+
+    #[test]
+    fn test_point_to_ordered() {
+        let point = Point::from((1e-3, 10.0));
+        assert_eq!(point, Point::new(1e-3, 10.0));
+        assert_eq!(point.to_ordered(InterpolationConfig::TwoD), [1e-3, 10.0]);
+
+        let point = point.nucleons(208.0).alphas(0.118).kt(2.5);
+        assert_eq!(point.to_ordered(InterpolationConfig::TwoD), [1e-3, 10.0]);
+        assert_eq!(
+            point.to_ordered(InterpolationConfig::ThreeDAlphas),
+            [0.118, 1e-3, 10.0]
+        );
+        assert_eq!(
+            point.to_ordered(InterpolationConfig::FourDNucleonsKt),
+            [208.0, 2.5, 1e-3, 10.0]
+        );
+        assert_eq!(
+            point.to_ordered(InterpolationConfig::FiveD),
+            [208.0, 0.118, 2.5, 1e-3, 10.0]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "The point has no coordinate along Kt")]
+    fn test_point_to_ordered_missing_axis() {
+        Point::new(1e-3, 10.0)
+            .alphas(0.118)
+            .to_ordered(InterpolationConfig::FourDAlphasKt);
+    }
+
     fn mock_subgrid_2d() -> SubGrid {
         let xs = vec![0.1, 0.2];
         let q2s = vec![1.0, 2.0];
@@ -714,6 +880,90 @@ mod tests {
         assert!((result - 8.5).abs() < MAXDIFF);
     }
 
+    #[test]
+    fn test_ndlinear_trilinear() {
+        // `A * x * Q2` is multilinear, and therefore reproduced exactly in between the knots.
+        let nucleons = vec![1.0, 4.0, 12.0];
+        let xs = vec![0.1, 0.2, 0.5];
+        let q2s = vec![1.0, 2.0, 10.0];
+        let mut grid_data = Vec::new();
+        for &a in &nucleons {
+            for &x in &xs {
+                grid_data.extend(q2s.iter().map(|&q2| a * x * q2));
+            }
+        }
+        let subgrid = SubGrid::new(nucleons, vec![0.118], vec![0.0], xs, q2s, 1, grid_data);
+
+        let interpolator = InterpolatorFactory::interpolator_ndlinear(&subgrid, 0);
+        // In the cell [4, 12] x [0.2, 0.5] x [2, 10], with the weights (3/8, 1/3, 1/4).
+        let result = interpolator.interpolate_point(&[7.0, 0.3, 4.0]).unwrap();
+        assert!((result - 7.0 * 0.3 * 4.0).abs() < 1e-13);
+    }
+
+    #[test]
+    fn test_ndlinear_quadrilinear() {
+        let nucleons = vec![1.0, 2.0];
+        let kts = vec![0.5, 1.5];
+        let xs = vec![0.1, 0.3];
+        let q2s = vec![1.0, 5.0];
+        // The values at the corners are `1..=16`, in the order `[A, kT, x, Q2]`.
+        let grid_data = (1..=16).map(f64::from).collect();
+        let subgrid = SubGrid::new(nucleons, vec![0.118], kts, xs, q2s, 1, grid_data);
+        let interpolator =
+            InterpolatorFactory::create(InterpolatorType::InterpNDLinear, &subgrid, 0);
+
+        // The weights of the upper corners along each axis are (1/4, 1/2, 3/4, 1/4), and the
+        // values increase by (8, 4, 2, 1) along them.
+        let result = interpolator
+            .interpolate_point(&[1.25, 1.0, 0.25, 2.0])
+            .unwrap();
+        let expected = 1.0 + 8.0 * 0.25 + 4.0 * 0.5 + 2.0 * 0.75 + 0.25;
+        assert!((result - expected).abs() < MAXDIFF);
+
+        // The corners are reproduced exactly.
+        let result = interpolator
+            .interpolate_point(&[2.0, 0.5, 0.3, 5.0])
+            .unwrap();
+        assert!((result - 12.0).abs() < MAXDIFF);
+    }
+
+    #[test]
+    fn test_log_chebyshev_convergence() {
+        let f = |x: f64, q2: f64| x.sqrt() * (1.0 - x).powi(2) * q2.ln();
+        let nodes = |n: usize, min: f64, max: f64| -> Vec<f64> {
+            crate::strategy::chebyshev_lobatto_points(n)
+                .into_iter()
+                .map(|t| (min.ln() + (max / min).ln() * (t + 1.0) / 2.0).exp())
+                .collect()
+        };
+        let xs = nodes(20, 1e-3, 0.9);
+        let q2s = nodes(10, 2.0, 1e4);
+        let grid_data = xs
+            .iter()
+            .flat_map(|&x| q2s.iter().map(move |&q2| f(x, q2)))
+            .collect();
+        let subgrid = SubGrid::new(vec![0.0], vec![0.0], vec![0.0], xs, q2s, 1, grid_data);
+
+        let max_error = |interp_type| {
+            let interpolator = InterpolatorFactory::create(interp_type, &subgrid, 0);
+            let mut max_error: f64 = 0.0;
+            for i in 0..=50 {
+                for j in 0..=10 {
+                    let x = 1.1e-3 * (0.8f64 / 1.1e-3).powf(f64::from(i) / 50.0);
+                    let q2 = 3.0 * (5e3f64 / 3.0).powf(f64::from(j) / 10.0);
+                    let value = interpolator.interpolate_point(&[x.ln(), q2.ln()]).unwrap();
+                    max_error = max_error.max((value - f(x, q2)).abs());
+                }
+            }
+            max_error
+        };
+
+        let chebyshev = max_error(InterpolatorType::LogChebyshev);
+        let bilinear = max_error(InterpolatorType::LogBilinear);
+        assert!(chebyshev < 1e-6, "{chebyshev}");
+        assert!(chebyshev * 1e3 < bilinear, "{chebyshev} vs {bilinear}");
+    }
+
     #[test]
     #[should_panic]
     fn test_unsupported_interpolator() {
@@ -26,9 +26,13 @@
 //! - [`manage`]: Management utilities for PDF set installation, download, and path resolution.
 //! - [`metadata`]: Metadata structures and types for describing PDF sets.
 //! - [`parser`]: Parsing utilities for reading and interpreting PDF set data files.
+//! - [`parton`]: Typed identifiers for the partons commonly stored in PDF sets.
 //! - [`pdf`]: High-level interface for working with PDF sets and interpolation.
+//! - [`pdfset`]: Full PDF sets and the computation of PDF uncertainties.
 //! - [`strategy`]: Interpolation strategy implementations (bilinear, log-bicubic, etc.).
 //! - [`subgrid`]: Subgrid data structures and parameter range logic.
+//! - `test_support`: Deterministic synthetic grids for the tests and the benchmarks, with the
+//!   `testing` feature.
 //! - [`utils`]: Utility functions for interpolation and grid operations.
 //! - [`writer`]: Utilities for serializing, compressing, and accessing PDF grid data.
 //!
@@ -52,8 +56,12 @@ pub mod interpolator;
 pub mod manage;
 pub mod metadata;
 pub mod parser;
+pub mod parton;
 pub mod pdf;
+pub mod pdfset;
 pub mod strategy;
 pub mod subgrid;
+#[cfg(any(test, feature = "testing"))]
+pub mod test_support;
 pub mod utils;
 pub mod writer;
@@ -2,12 +2,14 @@
 //!
 //! It defines types and methods for ensuring that PDF sets are available locally, downloading them if
 //! necessary, and handling different PDF set formats (LHAPDF, NeoPDF).
+#[cfg(feature = "gzip")]
 use flate2::read::GzDecoder;
 use indicatif::{ProgressBar, ProgressStyle};
 use serde::{Deserialize, Serialize};
 use std::error::Error;
 use std::io::Read;
 use std::path::{Path, PathBuf};
+#[cfg(feature = "gzip")]
 use tar::Archive;
 
 /// TODO
@@ -115,14 +117,27 @@ impl ManageData {
         let mut decorated_response = pb.wrap_read(response);
         decorated_response.read_to_end(&mut response_bytes)?;
 
-        let tar = GzDecoder::new(&response_bytes[..]);
-        let mut archive = Archive::new(tar);
-
-        archive.unpack(&self.neopdf_path)?;
+        Self::unpack_tar_gz(&response_bytes, &self.neopdf_path)
+    }
 
+    /// Extracts a `.tar.gz` archive held in memory into `destination`.
+    #[cfg(feature = "gzip")]
+    fn unpack_tar_gz(bytes: &[u8], destination: &Path) -> Result<(), Box<dyn Error>> {
+        Archive::new(GzDecoder::new(bytes)).unpack(destination)?;
         Ok(())
     }
 
+    /// Extracts a `.tar.gz` archive held in memory into `destination`.
+    #[cfg(not(feature = "gzip"))]
+    fn unpack_tar_gz(_bytes: &[u8], destination: &Path) -> Result<(), Box<dyn Error>> {
+        Err(format!(
+            "Cannot extract the PDF set into {}: unpacking `.tar.gz` archives requires the \
+             `gzip` feature",
+            destination.display()
+        )
+        .into())
+    }
+
     /// Check that the PDF set is installed in the correct path.
     pub fn is_pdf_installed(&self) -> bool {
         match self.pdfset_format {
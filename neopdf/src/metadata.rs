@@ -3,8 +3,107 @@
 //! It includes the `MetaData` struct (deserialized from .info files), PDF set
 //! and interpolator type enums, and related utilities for handling PDF set information.
 use serde::{Deserialize, Deserializer, Serialize};
+use serde_yaml::{Mapping, Value};
+use std::collections::BTreeMap;
 use std::fmt;
+use std::fs;
 use std::ops::{Deref, DerefMut};
+use std::path::Path;
+use std::str::FromStr;
+use thiserror::Error;
+
+use super::gridpdf::{ExtrapolationPolicy, ForcePositive};
+use super::interpolator::InterpolationConfig;
+use super::subgrid::SubGrid;
+
+/// Errors that can occur when converting or validating metadata.
+#[derive(Debug, Error, PartialEq)]
+pub enum MetaDataError {
+    /// Error indicating that converting to an older version would drop some entries.
+    #[error("Converting the metadata to V1 would drop the entries {keys:?}")]
+    LossyDowngrade {
+        /// The keys of the entries that cannot be represented.
+        keys: Vec<String>,
+    },
+    /// Error indicating that the metadata is not of the requested version.
+    #[error("Expected metadata version {expected} but found version {found}")]
+    VersionMismatch {
+        /// The requested version.
+        expected: u32,
+        /// The version of the metadata.
+        found: u32,
+    },
+    /// Error indicating that the lower bound of a range exceeds its upper bound.
+    #[error("The range of {entry} is inverted: {min} > {max}")]
+    InvertedRange {
+        /// The name of the range (e.g. `X` or `Q`).
+        entry: &'static str,
+        /// The lower bound.
+        min: f64,
+        /// The upper bound.
+        max: f64,
+    },
+    /// Error indicating that the metadata does not list any flavor.
+    #[error("The metadata does not list any flavor")]
+    NoFlavors,
+    /// Error indicating that a quark flavor below `NumFlavors` is not listed.
+    #[error("NumFlavors is {number_flavors} but the flavor {pid} is not listed")]
+    MissingFlavor {
+        /// The PDG ID of the missing quark or antiquark.
+        pid: i32,
+        /// The number of active flavors of the set.
+        number_flavors: u32,
+    },
+    /// Error indicating that a quark is listed without its antiquark, or vice versa.
+    #[error("The flavor {pid} is listed without its antiparticle {}", -pid)]
+    UnpairedFlavor {
+        /// The PDG ID of the listed quark or antiquark.
+        pid: i32,
+    },
+    /// Error indicating that the interpolator cannot be applied to the dimensions of a subgrid.
+    #[error("The interpolator {interpolator:?} does not support {config:?} subgrids")]
+    IncompatibleInterpolator {
+        /// The interpolator requested by the metadata.
+        interpolator: InterpolatorType,
+        /// The interpolation configuration of the subgrid.
+        config: InterpolationConfig,
+    },
+}
+
+/// Checks that the interpolator of the metadata can be applied to a given subgrid.
+///
+/// # Arguments
+///
+/// * `meta` - The metadata specifying the interpolator.
+/// * `subgrid` - The subgrid to interpolate.
+///
+/// # Returns
+///
+/// `Ok(())` if the interpolator supports the dimensions of the subgrid, and
+/// `MetaDataError::IncompatibleInterpolator` otherwise.
+pub fn validate_interpolator(meta: &MetaData, subgrid: &SubGrid) -> Result<(), MetaDataError> {
+    let config = subgrid.interpolation_config();
+    if config.supports(&meta.interpolator_type) {
+        Ok(())
+    } else {
+        Err(MetaDataError::IncompatibleInterpolator {
+            interpolator: meta.interpolator_type.clone(),
+            config,
+        })
+    }
+}
+
+/// Represents the scheme for the treatment of heavy flavors, see
+/// [`MetaData::normalize_flavor_scheme`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FlavorScheme {
+    /// The number of active flavors changes at the heavy quark thresholds.
+    VariableFlavorNumber,
+    /// The number of active flavors is fixed to the given value.
+    FixedFlavorNumber(u32),
+    /// A scheme that could not be recognized, holding the original string.
+    Unknown(String),
+}
 
 /// Represents the type of PDF set.
 #[repr(C)]
@@ -19,7 +118,7 @@ pub enum SetType {
 /// Represents the type of interpolator used for the PDF.
 /// WARNING: When adding elements, always append to the end!!!
 #[repr(C)]
-#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
 pub enum InterpolatorType {
     Bilinear,
     LogBilinear,
@@ -30,11 +129,28 @@ pub enum InterpolatorType {
     LogChebyshev,
 }
 
+impl FromStr for InterpolatorType {
+    type Err = String;
+
+    /// Parses the (case-insensitive) name of an interpolator, e.g. `"logbicubic"`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "bilinear" => Ok(Self::Bilinear),
+            "logbilinear" => Ok(Self::LogBilinear),
+            "logbicubic" => Ok(Self::LogBicubic),
+            "logtricubic" => Ok(Self::LogTricubic),
+            "ndlinear" | "interpndlinear" => Ok(Self::InterpNDLinear),
+            "logchebyshev" => Ok(Self::LogChebyshev),
+            _ => Err(format!("Unknown interpolator type: {s}")),
+        }
+    }
+}
+
 /// Represents the information block of a given set.
 ///
 /// In order to support LHAPDF formats, the fields here are very much influenced by the
 /// LHAPDF `.info` file. This struct is generally deserialized from a YAML-like format.
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct MetaDataV1 {
     /// Description of the PDF set.
     #[serde(rename = "SetDesc")]
@@ -131,11 +247,401 @@ pub struct MetaDataV1 {
     pub number_flavors: u32,
 }
 
+/// Represents the information block of a given set, extended with the `alpha_s` reference
+/// values.
+///
+/// All the fields of [`MetaDataV1`] are flattened into this struct, such that the YAML
+/// representation of the `.info` file is unchanged. The new fields are optional and are
+/// simply absent for sets that do not provide them.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct MetaDataV2 {
+    /// The fields inherited from the first version of the metadata.
+    #[serde(flatten)]
+    pub base: MetaDataV1,
+    /// Reference value of `alpha_s` at the mass scale `AlphaS_MassReference`.
+    #[serde(rename = "AlphaS_MZ", default, skip_serializing_if = "Option::is_none")]
+    pub alphas_mz: Option<f64>,
+    /// Mass scale at which the reference value `AlphaS_MZ` is given (defaults to `MZ`).
+    #[serde(
+        rename = "AlphaS_MassReference",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub alphas_mass_reference: Option<f64>,
+    /// Evaluation settings intended by the authors of the set.
+    #[serde(
+        rename = "InterpolationConfig",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub interpolation_config: Option<InterpolationConfigV2>,
+    /// Tags used to categorize the set (e.g. the perturbative order or the collaboration).
+    #[serde(rename = "Tags", default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    /// Annotations of the member described by this metadata, if any.
+    #[serde(
+        rename = "MemberInfo",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub member_info: Option<MemberInfo>,
+    /// Additional keys that are not part of the typed metadata (e.g. `Authors`).
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+/// Evaluation settings stored in the header of a set, such that it fully describes how it
+/// is meant to be evaluated.
+///
+/// Contrary to [`crate::interpolator::InterpolationConfig`], which follows from the
+/// dimensions of the subgrids, these settings cannot be inferred from the grid. The settings
+/// missing from the header take their default values.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct InterpolationConfigV2 {
+    /// The policy for queries outside of the grid coverage.
+    #[serde(rename = "ExtrapolationPolicy")]
+    pub extrapolation_policy: ExtrapolationPolicy,
+    /// The method used to clip negative values, if any.
+    #[serde(rename = "ForcePositive", skip_serializing_if = "Option::is_none")]
+    pub force_positive: Option<ForcePositive>,
+}
+
+/// Represents the role of a member within its set.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub enum MemberType {
+    /// The central member.
+    Central,
+    /// The upward variation of an error set.
+    ErrorPlus,
+    /// The downward variation of an error set.
+    ErrorMinus,
+    /// A Monte Carlo replica.
+    Replica,
+}
+
+impl MemberType {
+    /// Parses the `PdfType` entry of the `LHAPDF` member files.
+    ///
+    /// As the `error` members are not distinguished by `LHAPDF`, they are assumed to come in
+    /// pairs of upward (odd members) and downward (even members) variations.
+    ///
+    /// # Arguments
+    ///
+    /// * `pdf_type` - The `PdfType` entry (`central`, `error` or `replica`).
+    /// * `member` - The index of the member in the set.
+    ///
+    /// # Returns
+    ///
+    /// The `MemberType`, or `None` if the entry is not recognized.
+    pub fn from_pdf_type(pdf_type: &str, member: usize) -> Option<Self> {
+        match pdf_type.trim().to_lowercase().as_str() {
+            "central" => Some(Self::Central),
+            "replica" => Some(Self::Replica),
+            "error" if member % 2 == 1 => Some(Self::ErrorPlus),
+            "error" => Some(Self::ErrorMinus),
+            _ => None,
+        }
+    }
+}
+
+/// Annotations of an individual member of a set.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct MemberInfo {
+    /// The description of the member (e.g. `best fit`), empty if not provided.
+    #[serde(rename = "Description", default)]
+    pub description: String,
+    /// The role of the member within its set.
+    #[serde(rename = "Type")]
+    pub member_type: MemberType,
+}
+
+/// A metadata-only update of a set, listing the entries that differ from a base metadata.
+///
+/// The entries are given by their keys in the `.info` files, such that a patch can be
+/// shipped and edited as a small YAML document.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+pub struct MetaDataPatch {
+    /// The entries that are added or modified, with their new values.
+    #[serde(rename = "Set", default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub set: BTreeMap<String, Value>,
+    /// The keys of the entries that are removed.
+    #[serde(rename = "Remove", default, skip_serializing_if = "Vec::is_empty")]
+    pub remove: Vec<String>,
+}
+
+impl MetaDataPatch {
+    /// Computes the patch turning a base metadata into an updated one.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The metadata the patch applies to.
+    /// * `updated` - The metadata obtained after applying the patch.
+    ///
+    /// # Returns
+    ///
+    /// The `MetaDataPatch` with the entries of `updated` that differ from `base`.
+    pub fn between(base: &MetaData, updated: &MetaData) -> Self {
+        let (base, updated) = (base.to_mapping(), updated.to_mapping());
+
+        let set = updated
+            .iter()
+            .filter(|(key, value)| base.get(*key) != Some(*value))
+            .filter_map(|(key, value)| Some((key.as_str()?.to_string(), value.clone())))
+            .collect();
+        let remove = base
+            .keys()
+            .filter(|key| !updated.contains_key(*key))
+            .filter_map(|key| key.as_str().map(str::to_string))
+            .collect();
+
+        Self { set, remove }
+    }
+
+    /// Checks whether the patch leaves the metadata unchanged.
+    pub fn is_empty(&self) -> bool {
+        self.set.is_empty() && self.remove.is_empty()
+    }
+
+    /// Applies the patch to a given metadata.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The metadata to update.
+    ///
+    /// # Returns
+    ///
+    /// The updated `MetaData`, or an error if the patched entries are not valid.
+    pub fn apply(&self, base: &MetaData) -> Result<MetaData, serde_yaml::Error> {
+        let mut mapping = base.to_mapping();
+        for key in &self.remove {
+            mapping.remove(key.as_str());
+        }
+        for (key, value) in &self.set {
+            mapping.insert(Value::String(key.clone()), value.clone());
+        }
+
+        serde_yaml::from_value(Value::Mapping(mapping))
+    }
+}
+
+/// The JSON representation of a [`MetaData`], see [`MetaData::to_json`].
+#[derive(Deserialize, Serialize)]
+struct MetaDataJson<T> {
+    /// The version of the metadata, which determines the layout of `metadata`.
+    schema_version: u32,
+    /// The fields of the metadata.
+    metadata: T,
+}
+
+/// A metadata entry that differs between two sets, see [`MetaData::diff`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FieldDiff {
+    /// The key of the entry in the `.info` files.
+    pub field: String,
+    /// The YAML rendering of the entry of the first metadata, or `~` if it is not set.
+    pub left: String,
+    /// The YAML rendering of the entry of the second metadata, or `~` if it is not set.
+    pub right: String,
+}
+
+/// The relative tolerance below which two numbers are considered equal by [`MetaData::diff`].
+const DIFF_TOLERANCE: f64 = 1e-12;
+
+/// Compares two metadata entries, with a relative tolerance on the numbers.
+fn entries_match(left: &Value, right: &Value) -> bool {
+    match (left, right) {
+        (Value::Number(left), Value::Number(right)) => match (left.as_f64(), right.as_f64()) {
+            (Some(left), Some(right)) => {
+                left == right
+                    || (left - right).abs() <= DIFF_TOLERANCE * left.abs().max(right.abs())
+            }
+            _ => left == right,
+        },
+        (Value::Sequence(left), Value::Sequence(right)) => {
+            left.len() == right.len()
+                && left
+                    .iter()
+                    .zip(right)
+                    .all(|(left, right)| entries_match(left, right))
+        }
+        (Value::Mapping(left), Value::Mapping(right)) => {
+            left.len() == right.len()
+                && left.iter().all(|(key, left)| {
+                    right
+                        .get(key)
+                        .is_some_and(|right| entries_match(left, right))
+                })
+        }
+        (Value::Tagged(left), Value::Tagged(right)) => {
+            left.tag == right.tag && entries_match(&left.value, &right.value)
+        }
+        _ => left == right,
+    }
+}
+
+/// Renders a metadata entry for a [`FieldDiff`].
+fn render_entry(entry: Option<&Value>) -> String {
+    entry.map_or_else(
+        || "~".to_string(),
+        |value| {
+            serde_yaml::to_string(value)
+                .map(|yaml| yaml.trim_end().to_string())
+                .unwrap_or_default()
+        },
+    )
+}
+
+impl From<MetaDataV1> for MetaDataV2 {
+    fn from(base: MetaDataV1) -> Self {
+        Self {
+            base,
+            ..Default::default()
+        }
+    }
+}
+
+impl MetaDataV1 {
+    /// Upgrades the metadata to [`MetaDataV2`] with explicit `alpha_s` reference values.
+    ///
+    /// Contrary to the `From` conversion, which leaves the new entries unset, this carries
+    /// the reference values of sets that are upgraded to the latest version.
+    ///
+    /// # Arguments
+    ///
+    /// * `alphas_mz` - The reference value of `alpha_s`.
+    /// * `alphas_mass_reference` - The mass scale at which `alphas_mz` is given.
+    ///
+    /// # Returns
+    ///
+    /// The `MetaDataV2` with the entries of `self` and the given reference values.
+    pub fn upgrade_to_v2(self, alphas_mz: f64, alphas_mass_reference: f64) -> MetaDataV2 {
+        MetaDataV2 {
+            alphas_mz: Some(alphas_mz),
+            alphas_mass_reference: Some(alphas_mass_reference),
+            ..self.into()
+        }
+    }
+}
+
+impl MetaDataV2 {
+    /// Starts building a `MetaDataV2` from the default values of its entries.
+    pub fn builder() -> MetaDataV2Builder {
+        MetaDataV2Builder::default()
+    }
+}
+
+/// Builder for [`MetaDataV2`], which validates the entries before producing the metadata.
+///
+/// The entries that are not set take the same default values as when they are missing from
+/// an `.info` file.
+#[derive(Clone, Debug, Default)]
+pub struct MetaDataV2Builder {
+    data: MetaDataV2,
+}
+
+impl MetaDataV2Builder {
+    /// Sets the description of the set.
+    pub fn set_desc(mut self, set_desc: impl Into<String>) -> Self {
+        self.data.base.set_desc = set_desc.into();
+        self
+    }
+
+    /// Sets the index of the set.
+    pub fn set_index(mut self, set_index: u32) -> Self {
+        self.data.base.set_index = set_index;
+        self
+    }
+
+    /// Sets the number of members of the set.
+    pub fn num_members(mut self, num_members: u32) -> Self {
+        self.data.base.num_members = num_members;
+        self
+    }
+
+    /// Sets the PDG IDs of the flavors included in the set.
+    pub fn flavors(mut self, flavors: Vec<i32>) -> Self {
+        self.data.base.flavors = flavors;
+        self
+    }
+
+    /// Sets the range of validity in `x`.
+    pub fn x_range(mut self, x_min: f64, x_max: f64) -> Self {
+        self.data.base.x_min = x_min;
+        self.data.base.x_max = x_max;
+        self
+    }
+
+    /// Sets the range of validity in `Q` (non-squared).
+    pub fn q_range(mut self, q_min: f64, q_max: f64) -> Self {
+        self.data.base.q_min = q_min;
+        self.data.base.q_max = q_max;
+        self
+    }
+
+    /// Sets the format of the PDF data.
+    pub fn format(mut self, format: impl Into<String>) -> Self {
+        self.data.base.format = format.into();
+        self
+    }
+
+    /// Sets the type of the hadrons.
+    pub fn set_type(mut self, set_type: SetType) -> Self {
+        self.data.base.set_type = set_type;
+        self
+    }
+
+    /// Sets the interpolator used to evaluate the set.
+    pub fn interpolator_type(mut self, interpolator_type: InterpolatorType) -> Self {
+        self.data.base.interpolator_type = interpolator_type;
+        self
+    }
+
+    /// Sets the error type of the set (e.g. `replicas` or `hessian`).
+    pub fn error_type(mut self, error_type: impl Into<String>) -> Self {
+        self.data.base.error_type = error_type.into();
+        self
+    }
+
+    /// Sets the reference value of `alpha_s` at the mass of the Z boson.
+    pub fn alphas_mz(mut self, alphas_mz: f64) -> Self {
+        self.data.alphas_mz = Some(alphas_mz);
+        self
+    }
+
+    /// Sets the tags used to categorize the set.
+    pub fn tags(mut self, tags: Vec<String>) -> Self {
+        self.data.tags = tags;
+        self
+    }
+
+    /// Validates the entries and returns the metadata.
+    ///
+    /// # Returns
+    ///
+    /// The `MetaDataV2`, or a `MetaDataError` if one of the ranges is inverted or if no
+    /// flavor is listed.
+    pub fn build(self) -> Result<MetaDataV2, MetaDataError> {
+        let base = &self.data.base;
+        for (entry, min, max) in [("X", base.x_min, base.x_max), ("Q", base.q_min, base.q_max)] {
+            if min > max {
+                return Err(MetaDataError::InvertedRange { entry, min, max });
+            }
+        }
+        if base.flavors.is_empty() {
+            return Err(MetaDataError::NoFlavors);
+        }
+
+        Ok(self.data)
+    }
+}
+
 /// Version-aware metadata wrapper that handles serialization compatibility.
 #[derive(Clone, Debug, Serialize)]
 #[serde(untagged)]
 pub enum MetaData {
     V1(MetaDataV1),
+    V2(MetaDataV2),
 }
 
 impl MetaData {
@@ -144,17 +650,387 @@ impl MetaData {
         Self::V1(data)
     }
 
+    /// Creates a new instance of V2 `MetaData`.
+    pub fn new_v2(data: MetaDataV2) -> Self {
+        Self::V2(data)
+    }
+
     /// Gets the current version as the latest available version.
     pub fn current_v1(data: MetaDataV1) -> Self {
         Self::V1(data)
     }
 
     /// Gets the underlying data as the latest version.
-    pub fn as_latest(&self) -> MetaDataV1 {
+    pub fn as_latest(&self) -> MetaDataV2 {
+        match self {
+            MetaData::V1(data) => data.clone().into(),
+            MetaData::V2(data) => data.clone(),
+        }
+    }
+
+    /// Converts the metadata into the first version, without losing information.
+    ///
+    /// The conversion of V2 metadata is lossy as soon as any entry that is not part of V1
+    /// (e.g. `AlphaS_MZ` or the additional keys) is set, in which case an error is returned
+    /// instead of silently dropping them.
+    ///
+    /// # Returns
+    ///
+    /// The `MetaDataV1`, or `MetaDataError::LossyDowngrade` with the entries that would be
+    /// dropped.
+    pub fn try_as_v1(&self) -> Result<MetaDataV1, MetaDataError> {
+        match self {
+            MetaData::V1(data) => Ok(data.clone()),
+            MetaData::V2(data) => {
+                let downgraded = MetaData::V1(data.base.clone());
+                let patch = MetaDataPatch::between(&downgraded, self);
+                if patch.is_empty() {
+                    Ok(data.base.clone())
+                } else {
+                    Err(MetaDataError::LossyDowngrade {
+                        keys: patch.set.into_keys().collect(),
+                    })
+                }
+            }
+        }
+    }
+
+    /// Borrows the metadata as its second version.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the `MetaDataV2`, or `MetaDataError::VersionMismatch` for V1 metadata,
+    /// which can be converted with [`MetaData::as_latest`] instead.
+    pub fn try_as_v2(&self) -> Result<&MetaDataV2, MetaDataError> {
+        match self {
+            MetaData::V1(_) => Err(MetaDataError::VersionMismatch {
+                expected: 2,
+                found: 1,
+            }),
+            MetaData::V2(data) => Ok(data),
+        }
+    }
+
+    /// Converts the metadata into the first version, see [`MetaData::try_as_v1`].
+    ///
+    /// # Returns
+    ///
+    /// The `MetaDataV1`, or `None` if the conversion would drop some entries.
+    #[deprecated(since = "0.2.1", note = "use `MetaData::try_as_v1` instead")]
+    pub fn as_v1(&self) -> Option<MetaDataV1> {
+        self.try_as_v1().ok()
+    }
+
+    /// Borrows the metadata as its second version, see [`MetaData::try_as_v2`].
+    ///
+    /// # Returns
+    ///
+    /// A reference to the `MetaDataV2`, or `None` for V1 metadata.
+    #[deprecated(since = "0.2.1", note = "use `MetaData::try_as_v2` instead")]
+    pub fn as_v2(&self) -> Option<&MetaDataV2> {
+        self.try_as_v2().ok()
+    }
+
+    /// Serializes the metadata into JSON, preserving its version.
+    ///
+    /// Unlike the `Display` implementation, the output is meant for tools: it is an object
+    /// with the version of the metadata as `schema_version` and its fields, under their keys
+    /// in the `.info` files, as `metadata`.
+    ///
+    /// # Returns
+    ///
+    /// The JSON string, or a `serde_json::Error` if the metadata holds a value that JSON
+    /// cannot represent.
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        match self {
+            MetaData::V1(data) => serde_json::to_string(&MetaDataJson {
+                schema_version: 1,
+                metadata: data,
+            }),
+            MetaData::V2(data) => serde_json::to_string(&MetaDataJson {
+                schema_version: 2,
+                metadata: data,
+            }),
+        }
+    }
+
+    /// Deserializes the metadata written by [`MetaData::to_json`].
+    ///
+    /// # Arguments
+    ///
+    /// * `json` - The JSON string.
+    ///
+    /// # Returns
+    ///
+    /// The `MetaData` of the version given by `schema_version`, or a `serde_json::Error` if
+    /// the JSON is invalid or the version is not supported.
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        let json: MetaDataJson<serde_json::Value> = serde_json::from_str(json)?;
+        match json.schema_version {
+            1 => Ok(MetaData::V1(serde_json::from_value(json.metadata)?)),
+            2 => Ok(MetaData::V2(serde_json::from_value(json.metadata)?)),
+            version => Err(serde::de::Error::custom(format!(
+                "Unsupported metadata schema version: {version}"
+            ))),
+        }
+    }
+
+    /// Serializes the metadata, in its latest version, into a YAML mapping.
+    fn to_mapping(&self) -> Mapping {
+        match serde_yaml::to_value(self.as_latest()) {
+            Ok(Value::Mapping(mapping)) => mapping,
+            _ => unreachable!("The metadata is always serializable into a mapping"),
+        }
+    }
+
+    /// Lists the entries that differ from another metadata.
+    ///
+    /// Both metadata are compared in their latest version, such that V1 metadata differs
+    /// from its V2 upgrade only in the V2-specific entries that are set. Numbers (also within
+    /// lists) are considered equal if they agree within a relative tolerance of `1e-12`, which
+    /// absorbs the noise of the float formatting.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The metadata to compare with.
+    ///
+    /// # Returns
+    ///
+    /// A `FieldDiff` per differing entry, with `self` on the left, in the order of the
+    /// entries of `self` followed by the ones only set in `other`.
+    pub fn diff(&self, other: &MetaData) -> Vec<FieldDiff> {
+        let (left, right) = (self.to_mapping(), other.to_mapping());
+
+        left.keys()
+            .chain(right.keys().filter(|key| !left.contains_key(*key)))
+            .filter_map(Value::as_str)
+            .filter_map(|key| {
+                let (left, right) = (left.get(key), right.get(key));
+                let matching = match (left, right) {
+                    (Some(left), Some(right)) => entries_match(left, right),
+                    _ => false,
+                };
+                (!matching).then(|| FieldDiff {
+                    field: key.to_string(),
+                    left: render_entry(left),
+                    right: render_entry(right),
+                })
+            })
+            .collect()
+    }
+
+    /// Computes a hash of the content of the metadata.
+    ///
+    /// The hash covers all the fields (including the additional keys) and is independent of
+    /// the version of the metadata. It is computed with the 64-bit FNV-1a algorithm over a
+    /// canonical serialization, and is therefore stable across runs and platforms.
+    ///
+    /// # Returns
+    ///
+    /// The 64-bit hash of the metadata.
+    pub fn content_hash(&self) -> u64 {
+        const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+        const FNV_PRIME: u64 = 0x100000001b3;
+
+        let canonical =
+            serde_yaml::to_string(&self.as_latest()).expect("The metadata is always serializable");
+        canonical.bytes().fold(FNV_OFFSET_BASIS, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(FNV_PRIME)
+        })
+    }
+
+    /// Returns the reference value of `alpha_s`, if provided by the set.
+    pub fn alphas_mz(&self) -> Option<f64> {
         match self {
-            MetaData::V1(data) => data.clone(),
+            MetaData::V1(_) => None,
+            MetaData::V2(data) => data.alphas_mz,
         }
     }
+
+    /// Returns the mass scale at which the reference value of `alpha_s` is given, if
+    /// provided by the set.
+    pub fn alphas_mass_reference(&self) -> Option<f64> {
+        match self {
+            MetaData::V1(_) => None,
+            MetaData::V2(data) => data.alphas_mass_reference,
+        }
+    }
+
+    /// Parses the free-form `FlavorScheme` entry into a [`FlavorScheme`].
+    ///
+    /// The spellings are case-insensitive: `variable`, `VFN` and `VFNS` (or an empty entry, as
+    /// in `LHAPDF`) denote the variable scheme, while `fixed`, `FFN`, `FFNS` and `nFNS` denote
+    /// the fixed scheme, where the `n` of the latter is either kept as is or replaced by the
+    /// number of flavors (e.g. `4FNS`). The number of flavors of the fixed scheme is read off
+    /// the entry (e.g. `fixed 3`) and defaults to `NumFlavors`.
+    ///
+    /// # Returns
+    ///
+    /// The normalized flavor scheme of the set.
+    pub fn normalize_flavor_scheme(&self) -> FlavorScheme {
+        let scheme = self.flavor_scheme.trim().to_lowercase();
+        let digits: String = scheme.chars().filter(char::is_ascii_digit).collect();
+        let name: String = scheme.chars().filter(|c| c.is_ascii_alphabetic()).collect();
+
+        match name.as_str() {
+            "" if digits.is_empty() => FlavorScheme::VariableFlavorNumber,
+            "variable" | "vfn" | "vfns" => FlavorScheme::VariableFlavorNumber,
+            "fixed" | "ffn" | "ffns" | "fns" | "nfns" => {
+                FlavorScheme::FixedFlavorNumber(digits.parse().unwrap_or(self.number_flavors))
+            }
+            _ => FlavorScheme::Unknown(self.flavor_scheme.clone()),
+        }
+    }
+
+    /// Returns the tags of the set, which are empty if the set does not provide them.
+    pub fn tags(&self) -> &[String] {
+        match self {
+            MetaData::V1(_) => &[],
+            MetaData::V2(data) => &data.tags,
+        }
+    }
+
+    /// Checks whether the metadata provides an entry, as `PDFSet::has_key` in `LHAPDF`.
+    ///
+    /// Both the typed entries and the additional keys are considered. The optional entries
+    /// are only present if they are set.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key of the entry in the `.info` file (e.g. `Reference`).
+    ///
+    /// # Returns
+    ///
+    /// `true` if the entry is present.
+    pub fn has_key(&self, key: &str) -> bool {
+        self.get_entry(key).is_some()
+    }
+
+    /// Gets the value of an entry, as `PDFSet::get_entry` in `LHAPDF`.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key of the entry in the `.info` file (e.g. `Reference`).
+    ///
+    /// # Returns
+    ///
+    /// The YAML value of the entry, or `None` if the entry is not present.
+    pub fn get_entry(&self, key: &str) -> Option<Value> {
+        if let MetaData::V2(data) = self {
+            if let Some(value) = data.extra.get(key) {
+                return Some(value.clone());
+            }
+        }
+
+        self.to_mapping().remove(key)
+    }
+
+    /// Returns the annotations of the member described by this metadata, if provided.
+    pub fn member_info(&self) -> Option<&MemberInfo> {
+        match self {
+            MetaData::V1(_) => None,
+            MetaData::V2(data) => data.member_info.as_ref(),
+        }
+    }
+
+    /// Checks whether the set is labelled with a given tag.
+    ///
+    /// # Arguments
+    ///
+    /// * `tag` - The tag to look for.
+    ///
+    /// # Returns
+    ///
+    /// `true` if `tag` is one of the tags of the set.
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags().iter().any(|t| t == tag)
+    }
+
+    /// Returns the evaluation settings stored in the header, or the default settings if the
+    /// set does not provide them.
+    pub fn interpolation_config(&self) -> InterpolationConfigV2 {
+        match self {
+            MetaData::V1(_) => InterpolationConfigV2::default(),
+            MetaData::V2(data) => data.interpolation_config.clone().unwrap_or_default(),
+        }
+    }
+
+    /// Checks whether a point lies in the range of validity declared by the set.
+    ///
+    /// The bounds are the `XMin`, `XMax`, `QMin` and `QMax` entries, all inclusive. This
+    /// allows checking a point before evaluating it, instead of relying on the extrapolation
+    /// policy.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The momentum fraction.
+    /// * `q` - The energy scale (non-squared).
+    ///
+    /// # Returns
+    ///
+    /// `true` if `x` and `q` are both within the declared bounds.
+    pub fn in_valid_range(&self, x: f64, q: f64) -> bool {
+        (self.x_min..=self.x_max).contains(&x) && (self.q_min..=self.q_max).contains(&q)
+    }
+
+    /// Checks that the quarks listed in `Flavors` are consistent with `NumFlavors`.
+    ///
+    /// All the quarks and antiquarks up to `NumFlavors` must be listed, and every listed
+    /// quark must come with its antiquark. The entries that are not quarks (e.g. the gluon
+    /// `21` or the photon `22`) are not constrained, and sets without any quark (e.g.
+    /// gluon-only sets) are accepted whatever their `NumFlavors`, as is any set without
+    /// `NumFlavors`.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if the flavors are consistent, `MetaDataError::NoFlavors` if none is listed,
+    /// `MetaDataError::MissingFlavor` for the first missing quark (antiquarks first), or
+    /// `MetaDataError::UnpairedFlavor` for the first quark listed without its antiquark.
+    pub fn validate_flavors(&self) -> Result<(), MetaDataError> {
+        if self.flavors.is_empty() {
+            return Err(MetaDataError::NoFlavors);
+        }
+
+        let is_quark = |pid: &i32| (1..=6).contains(&pid.abs());
+        if !self.flavors.iter().any(is_quark) {
+            return Ok(());
+        }
+
+        let number_flavors = self.number_flavors;
+        let quarks = 1..=i32::try_from(number_flavors).unwrap_or(i32::MAX);
+        if let Some(pid) = quarks
+            .flat_map(|quark| [-quark, quark])
+            .find(|pid| !self.flavors.contains(pid))
+        {
+            return Err(MetaDataError::MissingFlavor {
+                pid,
+                number_flavors,
+            });
+        }
+
+        match self
+            .flavors
+            .iter()
+            .find(|&pid| is_quark(pid) && !self.flavors.contains(&-pid))
+        {
+            Some(&pid) => Err(MetaDataError::UnpairedFlavor { pid }),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Deref for MetaDataV2 {
+    type Target = MetaDataV1;
+
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl DerefMut for MetaDataV2 {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.base
+    }
 }
 
 impl Deref for MetaData {
@@ -163,6 +1039,7 @@ impl Deref for MetaData {
     fn deref(&self) -> &Self::Target {
         match self {
             MetaData::V1(data) => data,
+            MetaData::V2(data) => &data.base,
         }
     }
 }
@@ -171,6 +1048,7 @@ impl DerefMut for MetaData {
     fn deref_mut(&mut self) -> &mut Self::Target {
         match self {
             MetaData::V1(data) => data,
+            MetaData::V2(data) => &mut data.base,
         }
     }
 }
@@ -180,9 +1058,194 @@ impl<'de> Deserialize<'de> for MetaData {
     where
         D: Deserializer<'de>,
     {
-        let v1 = MetaDataV1::deserialize(deserializer)?;
+        // The `.info` files are self-describing and always map onto the latest version. The
+        // compact binary layout is only used by the legacy V1 metadata, the newer versions
+        // being handled explicitly in the `writer` module.
+        if deserializer.is_human_readable() {
+            let v2 = MetaDataV2::deserialize(deserializer)?;
 
-        Ok(MetaData::V1(v1))
+            Ok(MetaData::V2(v2))
+        } else {
+            let v1 = MetaDataV1::deserialize(deserializer)?;
+
+            Ok(MetaData::V1(v1))
+        }
+    }
+}
+
+/// A top-level entry of an `.info` file, together with its original text.
+#[derive(Clone, Debug)]
+struct InfoEntry {
+    /// The key of the entry, or `None` for comments and blank lines.
+    key: Option<String>,
+    /// The original lines of the entry.
+    lines: Vec<String>,
+}
+
+/// Metadata read from an `.info` file, retaining the layout of the original file.
+///
+/// The original key ordering, comments, and formatting are kept alongside the typed
+/// [`MetaData`], such that writing back a minimally edited metadata only modifies the lines
+/// of the fields that actually changed. Keys that are not part of the typed metadata are
+/// preserved as they are.
+#[derive(Clone, Debug)]
+pub struct MetaDataDocument {
+    entries: Vec<InfoEntry>,
+    original: Mapping,
+    metadata: MetaData,
+}
+
+impl MetaDataDocument {
+    /// Reads an `.info` file.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to the `.info` file.
+    ///
+    /// # Returns
+    ///
+    /// The `MetaDataDocument` on success, or an error if reading or parsing fails.
+    pub fn read<P: AsRef<Path>>(path: P) -> Result<Self, Box<dyn std::error::Error>> {
+        Self::parse(&fs::read_to_string(path)?)
+    }
+
+    /// Parses the content of an `.info` file.
+    ///
+    /// # Arguments
+    ///
+    /// * `content` - The YAML content of the `.info` file.
+    ///
+    /// # Returns
+    ///
+    /// The `MetaDataDocument` on success, or an error if parsing fails.
+    pub fn parse(content: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        let metadata: MetaData = serde_yaml::from_str(content)?;
+        let original = Self::to_mapping(&metadata)?;
+
+        let mut entries: Vec<InfoEntry> = Vec::new();
+        for line in content.lines() {
+            let is_continuation = line.starts_with([' ', '\t', '-']);
+            match entries.last_mut() {
+                Some(entry) if is_continuation && entry.key.is_some() => {
+                    entry.lines.push(line.to_string());
+                }
+                _ => {
+                    let is_key = !is_continuation && !line.starts_with('#') && line.contains(':');
+                    entries.push(InfoEntry {
+                        key: is_key.then(|| line[..line.find(':').unwrap()].trim().to_string()),
+                        lines: vec![line.to_string()],
+                    });
+                }
+            }
+        }
+
+        Ok(Self {
+            entries,
+            original,
+            metadata,
+        })
+    }
+
+    /// Returns a reference to the typed metadata.
+    pub fn metadata(&self) -> &MetaData {
+        &self.metadata
+    }
+
+    /// Returns a mutable reference to the typed metadata.
+    pub fn metadata_mut(&mut self) -> &mut MetaData {
+        &mut self.metadata
+    }
+
+    /// Serializes the metadata back into the layout of the original `.info` file.
+    ///
+    /// Unchanged entries are written verbatim, modified entries are rewritten in place
+    /// (keeping their trailing comment), and new entries are appended at the end.
+    ///
+    /// # Returns
+    ///
+    /// The YAML content on success, or an error if the serialization fails.
+    pub fn to_yaml(&self) -> Result<String, Box<dyn std::error::Error>> {
+        let current = Self::to_mapping(&self.metadata)?;
+        let mut lines: Vec<String> = Vec::new();
+
+        for entry in &self.entries {
+            let Some(key) = &entry.key else {
+                lines.extend(entry.lines.iter().cloned());
+                continue;
+            };
+            let yaml_key = Value::String(key.clone());
+            match (self.original.get(&yaml_key), current.get(&yaml_key)) {
+                (original, Some(value)) if original != Some(value) => {
+                    let comment = match entry.lines.as_slice() {
+                        [line] => Self::trailing_comment(line),
+                        _ => "",
+                    };
+                    lines.push(format!("{key}: {}{comment}", Self::flow_yaml(value)?));
+                }
+                (Some(_), None) => {}
+                _ => lines.extend(entry.lines.iter().cloned()),
+            }
+        }
+
+        for (key, value) in &current {
+            let is_written = self
+                .entries
+                .iter()
+                .any(|entry| entry.key.as_deref() == key.as_str());
+            if !is_written && self.original.get(key) != Some(value) {
+                let key = key.as_str().unwrap_or_default();
+                lines.push(format!("{key}: {}", Self::flow_yaml(value)?));
+            }
+        }
+
+        Ok(lines.iter().map(|line| format!("{line}\n")).collect())
+    }
+
+    /// Writes the metadata into an `.info` file, see [`MetaDataDocument::to_yaml`].
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to the output `.info` file.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` on success, or an error if writing fails.
+    pub fn write<P: AsRef<Path>>(&self, path: P) -> Result<(), Box<dyn std::error::Error>> {
+        fs::write(path, self.to_yaml()?)?;
+        Ok(())
+    }
+
+    /// Converts the typed metadata into a YAML mapping.
+    fn to_mapping(metadata: &MetaData) -> Result<Mapping, serde_yaml::Error> {
+        match serde_yaml::to_value(metadata)? {
+            Value::Mapping(mapping) => Ok(mapping),
+            _ => Ok(Mapping::new()),
+        }
+    }
+
+    /// Serializes a value on a single line, using the flow style for sequences.
+    fn flow_yaml(value: &Value) -> Result<String, serde_yaml::Error> {
+        match value {
+            Value::Sequence(items) => Ok(format!(
+                "[{}]",
+                items
+                    .iter()
+                    .map(Self::flow_yaml)
+                    .collect::<Result<Vec<_>, _>>()?
+                    .join(", ")
+            )),
+            _ => Ok(serde_yaml::to_string(value)?.trim_end().to_string()),
+        }
+    }
+
+    /// Extracts the trailing comment (including the leading whitespaces) of a line.
+    fn trailing_comment(line: &str) -> &str {
+        let parsed = serde_yaml::from_str::<Value>(line).ok();
+        line.match_indices(" #")
+            .map(|(idx, _)| idx)
+            .find(|&idx| serde_yaml::from_str::<Value>(&line[..idx]).ok() == parsed)
+            .map(|idx| &line[line[..idx].trim_end().len()..])
+            .unwrap_or_default()
     }
 }
 
@@ -219,3 +1282,391 @@ impl fmt::Display for MetaData {
         writeln!(f, "Number of PDF flavors: {}", self.number_flavors)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INFO: &str = "\
+# Test set used to check the layout preservation
+SetDesc: Test set # a trailing comment
+SetIndex: 1000
+Authors: The NeoPDF collaboration
+NumMembers: 1
+Flavors: [-1, 1, 21]
+
+XMin: 1e-9
+XMax: 1
+QMin: 1.0
+QMax: 1e5
+Format: lhagrid1
+AlphaS_Qs:
+  - 1.0
+  - 91.1876
+AlphaS_Vals:
+  - 0.35
+  - 0.118
+AlphaS_MZ: 0.118
+";
+
+    #[test]
+    fn test_metadata_document_roundtrip() {
+        let mut document = MetaDataDocument::parse(INFO).unwrap();
+        assert_eq!(document.to_yaml().unwrap(), INFO);
+
+        document.metadata_mut().set_desc = "Corrected description".to_string();
+        let written = document.to_yaml().unwrap();
+        let diffs: Vec<_> = INFO
+            .lines()
+            .zip(written.lines())
+            .filter(|(original, new)| original != new)
+            .collect();
+        assert_eq!(INFO.lines().count(), written.lines().count());
+        assert_eq!(
+            diffs,
+            vec![(
+                "SetDesc: Test set # a trailing comment",
+                "SetDesc: Corrected description # a trailing comment"
+            )]
+        );
+
+        let reread = MetaDataDocument::parse(&written).unwrap();
+        assert_eq!(reread.metadata().set_desc, "Corrected description");
+        assert_eq!(reread.metadata().alphas_mz(), Some(0.118));
+    }
+
+    #[test]
+    fn test_content_hash() {
+        let metadata: MetaData = serde_yaml::from_str(INFO).unwrap();
+        let same: MetaData = serde_yaml::from_str(INFO).unwrap();
+        assert_eq!(metadata.content_hash(), same.content_hash());
+
+        let mut changed = metadata.clone();
+        changed.x_min = 1e-8;
+        assert_ne!(metadata.content_hash(), changed.content_hash());
+
+        let extra: MetaData =
+            serde_yaml::from_str(&INFO.replace("Authors: The NeoPDF", "Authors: Some")).unwrap();
+        assert_ne!(metadata.content_hash(), extra.content_hash());
+
+        // The hash does not depend on the version of the metadata.
+        let v1 = MetaData::new_v1(MetaDataV1::default());
+        let v2 = MetaData::new_v2(MetaDataV2::default());
+        assert_eq!(v1.content_hash(), v2.content_hash());
+    }
+
+    #[test]
+    fn test_diff() {
+        let metadata: MetaData = serde_yaml::from_str(INFO).unwrap();
+        assert!(metadata.diff(&metadata.clone()).is_empty());
+        let v1 = MetaData::new_v1(MetaDataV1::default());
+        assert!(v1.diff(&MetaData::new_v2(v1.as_latest())).is_empty());
+
+        let mut other = MetaData::new_v2(metadata.as_latest());
+        other.q_max = 2e5;
+        other.q_min = 1.0 + 1e-15;
+        other.alphas_vals[1] = 0.118 * (1.0 + 1e-14);
+        assert_eq!(
+            metadata.diff(&other),
+            vec![FieldDiff {
+                field: "QMax".to_string(),
+                left: "100000.0".to_string(),
+                right: "200000.0".to_string(),
+            }]
+        );
+
+        let mut other = metadata.clone();
+        if let MetaData::V2(data) = &mut other {
+            data.alphas_mz = None;
+        }
+        let diffs = other.diff(&metadata);
+        assert_eq!(diffs.len(), 1);
+        assert_eq!(
+            (diffs[0].field.as_str(), diffs[0].left.as_str()),
+            ("AlphaS_MZ", "~")
+        );
+        assert_eq!(diffs[0].right, "0.118");
+    }
+
+    #[test]
+    fn test_json_roundtrip() {
+        let v1 = MetaData::new_v1(MetaDataV1 {
+            set_desc: "Test set".into(),
+            flavors: vec![-1, 1, 21],
+            q_max: 1e5,
+            ..Default::default()
+        });
+        let json = v1.to_json().unwrap();
+        assert!(json.starts_with("{\"schema_version\":1,\"metadata\":{"));
+        let parsed = MetaData::from_json(&json).unwrap();
+        assert!(matches!(parsed, MetaData::V1(_)));
+        assert!(parsed.diff(&v1).is_empty());
+        assert_eq!(parsed.to_json().unwrap(), json);
+
+        let v2: MetaData = serde_yaml::from_str(INFO).unwrap();
+        let json = v2.to_json().unwrap();
+        assert!(json.starts_with("{\"schema_version\":2,"));
+        let parsed = MetaData::from_json(&json).unwrap();
+        assert!(matches!(parsed, MetaData::V2(_)));
+        assert_eq!(parsed.alphas_mz(), Some(0.118));
+        assert_eq!(parsed.get_entry("Authors"), v2.get_entry("Authors"));
+        assert!(parsed.diff(&v2).is_empty());
+
+        let future = json.replace("\"schema_version\":2", "\"schema_version\":3");
+        assert_eq!(
+            MetaData::from_json(&future).unwrap_err().to_string(),
+            "Unsupported metadata schema version: 3"
+        );
+    }
+
+    #[test]
+    fn test_version_conversions() {
+        let v1 = MetaDataV1 {
+            set_desc: "Test set".to_string(),
+            flavors: vec![21, 1, 2],
+            ..Default::default()
+        };
+        let v2 = MetaData::new_v2(v1.clone().into());
+        let roundtrip = v2.try_as_v1().unwrap();
+        assert_eq!(
+            serde_yaml::to_string(&roundtrip).unwrap(),
+            serde_yaml::to_string(&v1).unwrap()
+        );
+        assert_eq!(v2.try_as_v2().unwrap().set_desc, "Test set");
+
+        let upgraded = v1.clone().upgrade_to_v2(0.118, 91.1876);
+        assert_eq!(upgraded.alphas_mz, Some(0.118));
+        assert_eq!(upgraded.alphas_mass_reference, Some(91.1876));
+        assert_eq!(upgraded.flavors, v1.flavors);
+        let roundtrip = MetaData::new_v2(upgraded.clone());
+        assert_eq!(roundtrip.as_latest().alphas_mz, Some(0.118));
+
+        let v1 = MetaData::new_v1(v1);
+        assert!(v1.try_as_v1().is_ok());
+        assert_eq!(
+            v1.try_as_v2().unwrap_err(),
+            MetaDataError::VersionMismatch {
+                expected: 2,
+                found: 1
+            }
+        );
+
+        let metadata: MetaData = serde_yaml::from_str(INFO).unwrap();
+        assert_eq!(
+            metadata.try_as_v1().unwrap_err(),
+            MetaDataError::LossyDowngrade {
+                keys: vec!["AlphaS_MZ".to_string(), "Authors".to_string()]
+            }
+        );
+
+        #[allow(deprecated)]
+        {
+            assert!(metadata.as_v1().is_none());
+            assert!(metadata.as_v2().is_some());
+            assert!(v1.as_v1().is_some());
+            assert!(v1.as_v2().is_none());
+        }
+    }
+
+    #[test]
+    fn test_get_entry() {
+        let metadata: MetaData = serde_yaml::from_str(INFO).unwrap();
+        assert!(metadata.has_key("Authors"));
+        assert_eq!(
+            metadata.get_entry("Authors"),
+            Some(Value::from("The NeoPDF collaboration"))
+        );
+        assert_eq!(metadata.get_entry("XMax"), Some(Value::from(1.0)));
+        assert!(metadata.has_key("AlphaS_MZ"));
+        assert!(!metadata.has_key("AlphaS_MassReference"));
+        assert!(!metadata.has_key("Reference"));
+
+        let reserialized: MetaData =
+            serde_yaml::from_str(&serde_yaml::to_string(&metadata.as_latest()).unwrap()).unwrap();
+        assert_eq!(
+            reserialized.get_entry("Authors"),
+            metadata.get_entry("Authors")
+        );
+    }
+
+    #[test]
+    fn test_tags() {
+        let metadata: MetaData = serde_yaml::from_str(INFO).unwrap();
+        assert!(metadata.tags().is_empty());
+        assert!(!metadata.has_tag("nnlo"));
+
+        let tagged: MetaData =
+            serde_yaml::from_str(&format!("{INFO}Tags: [nnlo, nnpdf]\n")).unwrap();
+        assert_eq!(tagged.tags(), ["nnlo", "nnpdf"]);
+        assert!(tagged.has_tag("nnpdf"));
+        assert!(!tagged.has_tag("nlo"));
+        assert!(!tagged.as_latest().extra.contains_key("Tags"));
+    }
+
+    #[test]
+    fn test_metadata_v2_builder() {
+        let metadata = MetaDataV2::builder()
+            .set_desc("Toy set")
+            .flavors(vec![21, 1, 2])
+            .x_range(1e-9, 1.0)
+            .q_range(1.0, 1e4)
+            .alphas_mz(0.118)
+            .build()
+            .unwrap();
+        assert_eq!(metadata.set_desc, "Toy set");
+        assert_eq!(metadata.flavors, [21, 1, 2]);
+        assert_eq!(metadata.alphas_mz, Some(0.118));
+        assert_eq!(metadata.interpolator_type, InterpolatorType::LogBicubic);
+
+        let inverted = MetaDataV2::builder()
+            .flavors(vec![21])
+            .x_range(1e-9, 1.0)
+            .q_range(100.0, 1.0)
+            .build();
+        assert_eq!(
+            inverted.unwrap_err(),
+            MetaDataError::InvertedRange {
+                entry: "Q",
+                min: 100.0,
+                max: 1.0
+            }
+        );
+        assert_eq!(
+            MetaDataV2::builder().build().unwrap_err(),
+            MetaDataError::NoFlavors
+        );
+    }
+
+    #[test]
+    fn test_in_valid_range() {
+        let v1: MetaData = serde_yaml::from_str(INFO).unwrap();
+        let v2 = MetaData::new_v2(
+            MetaDataV2::builder()
+                .flavors(vec![21])
+                .x_range(1e-9, 1.0)
+                .q_range(1.65, 1e5)
+                .build()
+                .unwrap(),
+        );
+
+        for metadata in [v1, v2] {
+            let (x_min, q_min) = (metadata.x_min, metadata.q_min);
+            assert!(metadata.in_valid_range(1e-3, 10.0));
+            assert!(metadata.in_valid_range(x_min, q_min));
+            assert!(metadata.in_valid_range(metadata.x_max, metadata.q_max));
+            assert!(!metadata.in_valid_range(x_min / 2.0, 10.0));
+            assert!(!metadata.in_valid_range(1.5, 10.0));
+            assert!(!metadata.in_valid_range(1e-3, q_min / 2.0));
+            assert!(!metadata.in_valid_range(1e-3, 2.0 * metadata.q_max));
+            assert!(!metadata.in_valid_range(f64::NAN, 10.0));
+        }
+    }
+
+    #[test]
+    fn test_validate_flavors() {
+        let with_flavors = |flavors: Vec<i32>, number_flavors: u32| {
+            MetaData::new_v1(MetaDataV1 {
+                flavors,
+                number_flavors,
+                ..Default::default()
+            })
+        };
+
+        let five = (-5..=5)
+            .map(|pid| if pid == 0 { 21 } else { pid })
+            .collect::<Vec<_>>();
+        assert_eq!(with_flavors(five.clone(), 5).validate_flavors(), Ok(()));
+        assert_eq!(with_flavors(five.clone(), 4).validate_flavors(), Ok(()));
+
+        let without_sbar: Vec<i32> = five.iter().copied().filter(|&pid| pid != -3).collect();
+        assert_eq!(
+            with_flavors(without_sbar.clone(), 5).validate_flavors(),
+            Err(MetaDataError::MissingFlavor {
+                pid: -3,
+                number_flavors: 5
+            })
+        );
+        assert_eq!(
+            with_flavors(without_sbar, 2).validate_flavors(),
+            Err(MetaDataError::UnpairedFlavor { pid: 3 })
+        );
+        assert_eq!(
+            with_flavors(five, 6).validate_flavors(),
+            Err(MetaDataError::MissingFlavor {
+                pid: -6,
+                number_flavors: 6
+            })
+        );
+
+        // Gluon-only and photon-inclusive sets, and sets without `NumFlavors`.
+        assert_eq!(with_flavors(vec![21], 5).validate_flavors(), Ok(()));
+        assert_eq!(with_flavors(vec![21, 22], 0).validate_flavors(), Ok(()));
+        assert_eq!(with_flavors(vec![-2, 2, 21], 0).validate_flavors(), Ok(()));
+        assert_eq!(
+            with_flavors(vec![-1, 1, 21, 22], 1).validate_flavors(),
+            Ok(())
+        );
+        assert_eq!(
+            with_flavors(vec![], 0).validate_flavors(),
+            Err(MetaDataError::NoFlavors)
+        );
+    }
+
+    #[test]
+    fn test_validate_interpolator() {
+        let (xs, q2s) = (vec![1e-3, 1e-2, 1e-1, 1.0], vec![1.0, 10.0, 100.0]);
+        let two_d = SubGrid::new(
+            vec![0.0],
+            vec![0.0],
+            vec![0.0],
+            xs.clone(),
+            q2s.clone(),
+            1,
+            vec![1.0; 12],
+        );
+        let three_d = SubGrid::new(
+            vec![1.0, 4.0, 12.0, 56.0],
+            vec![0.0],
+            vec![0.0],
+            xs,
+            q2s,
+            1,
+            vec![1.0; 48],
+        );
+        let metadata = MetaData::new_v1(MetaDataV1::default());
+
+        assert_eq!(validate_interpolator(&metadata, &two_d), Ok(()));
+        assert_eq!(
+            validate_interpolator(&metadata, &three_d),
+            Err(MetaDataError::IncompatibleInterpolator {
+                interpolator: InterpolatorType::LogBicubic,
+                config: InterpolationConfig::ThreeDNucleons,
+            })
+        );
+    }
+
+    #[test]
+    fn test_normalize_flavor_scheme() {
+        let scheme = |name: &str| {
+            MetaData::new_v1(MetaDataV1 {
+                flavor_scheme: name.to_string(),
+                number_flavors: 5,
+                ..Default::default()
+            })
+            .normalize_flavor_scheme()
+        };
+
+        for name in ["variable", "VFN", " vfns ", ""] {
+            assert_eq!(scheme(name), FlavorScheme::VariableFlavorNumber);
+        }
+        assert_eq!(scheme("FIXED"), FlavorScheme::FixedFlavorNumber(5));
+        assert_eq!(scheme("FFNS"), FlavorScheme::FixedFlavorNumber(5));
+        assert_eq!(scheme("fixed 3"), FlavorScheme::FixedFlavorNumber(3));
+        assert_eq!(scheme("4FNS"), FlavorScheme::FixedFlavorNumber(4));
+        assert_eq!(scheme("nFNS"), FlavorScheme::FixedFlavorNumber(5));
+        assert_eq!(
+            scheme("massive"),
+            FlavorScheme::Unknown("massive".to_string())
+        );
+    }
+}
@@ -1,14 +1,20 @@
 //! This module provides parsing utilities for reading and interpreting PDF set data files.
 //!
 //! It defines types and methods for loading, parsing, and representing both LHAPDF and NeoPDF
-//! set formats, including subgrid data extraction and metadata reading.
+//! set formats, including subgrid data extraction and metadata reading. With the `hdf5`
+//! feature, PDF members stored in HDF5 files can also be read through [`Hdf5Set`].
 use serde::{Deserialize, Serialize};
+use serde_yaml::{Mapping, Value};
 use std::fs;
+use std::io::{BufRead, BufReader, Read};
 use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use thiserror::Error;
 
 use super::gridpdf::GridArray;
 use super::manage::{ManageData, PdfSetFormat};
-use super::metadata::MetaData;
+use super::metadata::{MemberInfo, MemberType, MetaData};
+use super::subgrid::{ShapeError, SubGrid, SubGridBuilder};
 use super::writer::{GridArrayReader, LazyGridArrayIterator};
 
 /// Represents the data for a single subgrid within a PDF data file.
@@ -29,6 +35,8 @@ pub struct PdfData {
     pub pids: Vec<i32>,
     pub alphas_q_values: Option<Vec<f64>>,
     pub alphas_vals: Option<Vec<f64>>,
+    pub pdf_type: Option<String>,
+    pub pdf_desc: Option<String>,
 }
 
 /// Manages the loading and parsing of LHAPDF data sets.
@@ -72,11 +80,15 @@ impl LhapdfSet {
     /// A tuple containing the `MetaData` and `PdfData` for the specified member.
     pub fn member(&self, member: usize) -> (MetaData, GridArray) {
         let pdfset_path = self.manager.set_path();
-        let data_path = pdfset_path.join(format!(
+        let mut data_path = pdfset_path.join(format!(
             "{}_{:04}.dat",
             pdfset_path.file_name().unwrap().to_str().unwrap(),
             member
         ));
+        let gzipped = data_path.with_extension("dat.gz");
+        if !data_path.exists() && gzipped.exists() {
+            data_path = gzipped;
+        }
 
         let pdf_data = Self::read_data(&data_path);
         let knot_array = GridArray::new(pdf_data.subgrid_data, pdf_data.pids);
@@ -90,6 +102,17 @@ impl LhapdfSet {
                 }
             }
         }
+        if let Some(member_type) = pdf_data
+            .pdf_type
+            .and_then(|pdf_type| MemberType::from_pdf_type(&pdf_type, member))
+        {
+            let mut latest = info.as_latest();
+            latest.member_info = Some(MemberInfo {
+                description: pdf_data.pdf_desc.unwrap_or_default(),
+                member_type,
+            });
+            info = MetaData::new_v2(latest);
+        }
         (info, knot_array)
     }
 
@@ -113,10 +136,9 @@ impl LhapdfSet {
     ///
     /// # Returns
     ///
-    /// A `Result` containing the `Info` struct if successful, or a `serde_yaml::Error` otherwise.
-    fn read_metadata(path: &Path) -> Result<MetaData, serde_yaml::Error> {
-        let content = fs::read_to_string(path).unwrap();
-        serde_yaml::from_str(&content)
+    /// A `Result` containing the `Info` struct if successful, or a `ParseError` otherwise.
+    fn read_metadata(path: &Path) -> Result<MetaData, ParseError> {
+        parse_info(fs::File::open(path)?)
     }
 
     /// Reads an LHAPDF `.dat` file for a PDF set and parses its content.
@@ -127,17 +149,24 @@ impl LhapdfSet {
     ///
     /// # Arguments
     ///
-    /// * `path` - The path to the `.dat` file.
+    /// * `path` - The path to the `.dat` file, which may be gzip-compressed, see
+    ///   [`open_member`].
     ///
     /// # Returns
     ///
     /// A `PdfData` struct containing the parsed subgrid data and flavor IDs.
     pub fn read_data(path: &Path) -> PdfData {
-        let content = fs::read_to_string(path).unwrap();
+        let mut content = String::new();
+        member_reader(path)
+            .unwrap()
+            .read_to_string(&mut content)
+            .unwrap();
         let mut subgrid_data = Vec::new();
         let mut flavors = Vec::new();
         let mut alphas_q_values: Option<Vec<f64>> = None;
         let mut alphas_vals: Option<Vec<f64>> = None;
+        let mut pdf_type: Option<String> = None;
+        let mut pdf_desc: Option<String> = None;
 
         let blocks: Vec<&str> = content.split("---").map(|s| s.trim()).collect();
 
@@ -149,6 +178,10 @@ impl LhapdfSet {
                 alphas_q_values: Vec<f64>,
                 #[serde(rename = "AlphaS_Vals", default)]
                 alphas_vals: Vec<f64>,
+                #[serde(rename = "PdfType", default)]
+                pdf_type: Option<String>,
+                #[serde(rename = "PdfDesc", default)]
+                pdf_desc: Option<String>,
             }
 
             let metadata_block = blocks[0];
@@ -159,6 +192,8 @@ impl LhapdfSet {
                 if !dat_meta.alphas_vals.is_empty() {
                     alphas_vals = Some(dat_meta.alphas_vals);
                 }
+                pdf_type = dat_meta.pdf_type;
+                pdf_desc = dat_meta.pdf_desc;
             }
         }
 
@@ -224,6 +259,346 @@ impl LhapdfSet {
             pids: flavors,
             alphas_q_values,
             alphas_vals,
+            pdf_type,
+            pdf_desc,
+        }
+    }
+}
+
+/// Errors that can occur when parsing LHAPDF `.info` and `.dat` files.
+#[derive(Debug, Error)]
+pub enum ParseError {
+    /// Error raised while reading the file.
+    #[error("Failed to read the file: {0}")]
+    Io(#[from] std::io::Error),
+    /// Error indicating that the `.info` file is not valid YAML or is not valid metadata.
+    #[error("Invalid metadata: {0}")]
+    Yaml(#[from] serde_yaml::Error),
+    /// Error indicating that a token of a knot, flavor or value line is not a number.
+    #[error("Invalid number `{token}` on line {line}")]
+    InvalidNumber {
+        /// The line of the token, starting from 1.
+        line: usize,
+        /// The token that could not be parsed.
+        token: String,
+    },
+    /// Error indicating that a block does not list the same flavors as the first one.
+    #[error("Block {block} has {found} flavors but the first block has {expected}")]
+    FlavorCountMismatch {
+        /// The index of the block, starting from 0.
+        block: usize,
+        /// The number of flavors of the first block.
+        expected: usize,
+        /// The number of flavors of the block.
+        found: usize,
+    },
+    /// Error indicating that a block ends before its flavor line.
+    #[error("Block {block} ends before its knots and flavors are defined")]
+    IncompleteBlock {
+        /// The index of the block, starting from 0.
+        block: usize,
+    },
+    /// Error indicating that the values of a block do not match its knots.
+    #[error("Block {block} is inconsistent: {source}")]
+    Shape {
+        /// The index of the block, starting from 0.
+        block: usize,
+        /// The inconsistency between the values and the knots.
+        #[source]
+        source: ShapeError,
+    },
+}
+
+/// The first two bytes of a gzip stream.
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// Opens an LHAPDF `.dat` member file, decompressing it if it starts with the gzip magic bytes.
+fn member_reader(path: &Path) -> Result<Box<dyn BufRead>, ParseError> {
+    let mut reader = BufReader::new(fs::File::open(path)?);
+    if !reader.fill_buf()?.starts_with(&GZIP_MAGIC) {
+        return Ok(Box::new(reader));
+    }
+
+    #[cfg(feature = "gzip")]
+    {
+        Ok(Box::new(BufReader::new(flate2::bufread::GzDecoder::new(
+            reader,
+        ))))
+    }
+    #[cfg(not(feature = "gzip"))]
+    {
+        Err(ParseError::Io(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            format!(
+                "{} is gzip-compressed, which requires the `gzip` feature",
+                path.display()
+            ),
+        )))
+    }
+}
+
+/// Reads an LHAPDF `.dat` member file into subgrids.
+///
+/// Gzip-compressed files (e.g. `.dat.gz`) are recognized by their magic bytes, independently
+/// of their extension, and decompressed on the fly if the `gzip` feature is enabled. Other
+/// files are read as plain text with [`parse_member_dat`].
+///
+/// # Arguments
+///
+/// * `path` - The path to the member file.
+///
+/// # Returns
+///
+/// The subgrids of the member, or a `ParseError` if the file cannot be read or parsed. A
+/// gzip-compressed file is reported as an `InvalidData` I/O error without the `gzip` feature.
+pub fn open_member(path: impl AsRef<Path>) -> Result<Vec<SubGrid>, ParseError> {
+    parse_member_dat(member_reader(path.as_ref())?)
+}
+
+/// Parses the content of an LHAPDF `.dat` member file into subgrids.
+///
+/// The header, which ends with the first `---` line, is skipped. Every following block,
+/// terminated by `---` or by the end of the file, contains a line of `x` knots, a line of
+/// `Q` knots, a line of flavor IDs and the grid values, with the flavor running fastest.
+/// The values are copied into the subgrid as they are read, such that the content of the
+/// file is never held in memory.
+///
+/// # Arguments
+///
+/// * `reader` - The content of the member file.
+///
+/// # Returns
+///
+/// The subgrids of the member, in the order of the file, or a `ParseError` if a block is
+/// malformed or does not have the same number of flavors as the first one.
+pub fn parse_member_dat(reader: impl BufRead) -> Result<Vec<SubGrid>, ParseError> {
+    let mut subgrids = Vec::new();
+    let mut nflav = None;
+    let mut knots: Vec<Vec<f64>> = Vec::new();
+    let mut builder: Option<SubGridBuilder> = None;
+    let mut in_header = true;
+
+    for (index, line) in reader.lines().enumerate() {
+        let line = line?;
+        let line = line.trim();
+        if in_header {
+            in_header = line != "---";
+            continue;
+        }
+        if line.is_empty() {
+            continue;
+        }
+        if line == "---" {
+            finish_block(&mut subgrids, &knots, builder.take())?;
+            continue;
+        }
+
+        let line_number = index + 1;
+        if let Some(builder) = builder.as_mut() {
+            let values: Vec<f64> = parse_tokens(line, line_number)?;
+            builder
+                .push_block(&values)
+                .map_err(|source| ParseError::Shape {
+                    block: subgrids.len(),
+                    source,
+                })?;
+        } else if knots.len() < 2 {
+            knots.push(parse_tokens(line, line_number)?);
+        } else {
+            let found = parse_tokens::<i32>(line, line_number)?.len();
+            let expected = *nflav.get_or_insert(found);
+            if found != expected {
+                return Err(ParseError::FlavorCountMismatch {
+                    block: subgrids.len(),
+                    expected,
+                    found,
+                });
+            }
+
+            let q2s = knots.pop().unwrap().iter().map(|q| q * q).collect();
+            let xs = knots.pop().unwrap();
+            builder = Some(SubGridBuilder::new(
+                vec![0.0],
+                vec![0.0],
+                vec![0.0],
+                xs,
+                q2s,
+                found,
+            ));
+        }
+    }
+    finish_block(&mut subgrids, &knots, builder)?;
+
+    Ok(subgrids)
+}
+
+/// Appends the subgrid of the block being parsed, if any, to `subgrids`.
+fn finish_block(
+    subgrids: &mut Vec<SubGrid>,
+    knots: &[Vec<f64>],
+    builder: Option<SubGridBuilder>,
+) -> Result<(), ParseError> {
+    let block = subgrids.len();
+    match builder {
+        Some(builder) => {
+            let subgrid = builder
+                .finish()
+                .map_err(|source| ParseError::Shape { block, source })?;
+            subgrids.push(subgrid);
+            Ok(())
+        }
+        None if knots.is_empty() => Ok(()),
+        None => Err(ParseError::IncompleteBlock { block }),
+    }
+}
+
+/// Parses the whitespace-separated tokens of a line of a `.dat` file.
+fn parse_tokens<T: FromStr>(line: &str, line_number: usize) -> Result<Vec<T>, ParseError> {
+    line.split_whitespace()
+        .map(|token| {
+            token.parse().map_err(|_| ParseError::InvalidNumber {
+                line: line_number,
+                token: token.to_string(),
+            })
+        })
+        .collect()
+}
+
+/// The integer entries of the `.info` files.
+const INFO_INTEGER_KEYS: &[&str] = &[
+    "SetIndex",
+    "NumMembers",
+    "Particle",
+    "OrderQCD",
+    "AlphaS_OrderQCD",
+    "NumFlavors",
+];
+
+/// The floating-point entries of the `.info` files.
+const INFO_FLOAT_KEYS: &[&str] = &[
+    "XMin",
+    "XMax",
+    "QMin",
+    "QMax",
+    "MW",
+    "MZ",
+    "MUp",
+    "MDown",
+    "MStrange",
+    "MCharm",
+    "MBottom",
+    "MTop",
+    "AlphaS_MZ",
+    "AlphaS_MassReference",
+];
+
+/// The entries of the `.info` files holding lists of floating-point numbers.
+const INFO_FLOAT_LIST_KEYS: &[&str] = &["AlphaS_Qs", "AlphaS_Vals"];
+
+/// The numeric entries required by [`MetaData`], with the values filled in if they are
+/// missing. The bounds span the whole physical domain, such that no query is rejected.
+const INFO_REQUIRED_NUMERIC_DEFAULTS: &[(&str, f64)] = &[
+    ("SetIndex", 0.0),
+    ("NumMembers", 0.0),
+    ("XMin", 0.0),
+    ("XMax", 1.0),
+    ("QMin", 0.0),
+    ("QMax", f64::MAX),
+];
+
+/// Parses the content of an LHAPDF `.info` file into metadata.
+///
+/// Before being deserialized, the numeric entries are normalized to tolerate the quirks of
+/// the files found in the wild: numbers written as strings or in Fortran notation (e.g.
+/// `1.0D-09`), integral floats for integer entries (e.g. `NumMembers: 101.0`), and
+/// `Flavors` written as a single string instead of a list. Missing required numeric
+/// entries are filled in instead of failing: `XMin`, `XMax`, `QMin` and `QMax` default to
+/// `0`, `1`, `0` and `f64::MAX` respectively, while `SetIndex` and `NumMembers` default to
+/// zero.
+///
+/// # Arguments
+///
+/// * `reader` - The content of the `.info` file.
+///
+/// # Returns
+///
+/// The `MetaData`, or a `ParseError` if the content is not valid YAML or if the normalized
+/// entries do not form valid metadata.
+pub fn parse_info(mut reader: impl Read) -> Result<MetaData, ParseError> {
+    let mut content = String::new();
+    reader.read_to_string(&mut content)?;
+
+    let mut value: Value = serde_yaml::from_str(&content)?;
+    if let Value::Mapping(mapping) = &mut value {
+        normalize_info(mapping);
+    }
+
+    Ok(serde_yaml::from_value(value)?)
+}
+
+/// Normalizes the numeric entries of an `.info` file, see [`parse_info`].
+fn normalize_info(mapping: &mut Mapping) {
+    for &(key, default) in INFO_REQUIRED_NUMERIC_DEFAULTS {
+        if !mapping.contains_key(key) {
+            mapping.insert(key.into(), default.into());
+        }
+    }
+
+    for (key, value) in mapping.iter_mut() {
+        let Some(key) = key.as_str() else {
+            continue;
+        };
+
+        if INFO_INTEGER_KEYS.contains(&key) {
+            normalize_integer(value);
+        } else if INFO_FLOAT_KEYS.contains(&key) {
+            normalize_float(value);
+        } else if INFO_FLOAT_LIST_KEYS.contains(&key) {
+            if let Value::Sequence(values) = value {
+                values.iter_mut().for_each(normalize_float);
+            }
+        } else if key == "Flavors" {
+            if let Value::String(flavors) = value {
+                *value = Value::Sequence(
+                    flavors
+                        .trim_matches(|c| c == '[' || c == ']')
+                        .split(|c: char| c == ',' || c.is_whitespace())
+                        .filter(|token| !token.is_empty())
+                        .map(|token| Value::String(token.to_string()))
+                        .collect(),
+                );
+            }
+            if let Value::Sequence(flavors) = value {
+                flavors.iter_mut().for_each(normalize_integer);
+            }
+        }
+    }
+}
+
+/// Returns the number held by an `.info` value, which may be written as a string.
+fn info_number(value: &Value) -> Option<f64> {
+    match value {
+        Value::Number(number) => number.as_f64(),
+        Value::String(string) => string.trim().replace(['D', 'd'], "e").parse().ok(),
+        _ => None,
+    }
+}
+
+/// Converts an `.info` value holding an integral number into an integer.
+fn normalize_integer(value: &mut Value) {
+    if value.is_i64() || value.is_u64() {
+        return;
+    }
+    if let Some(number) = info_number(value).filter(|number| number.fract() == 0.0) {
+        *value = Value::Number((number as i64).into());
+    }
+}
+
+/// Converts an `.info` value holding a number into a floating-point number.
+fn normalize_float(value: &mut Value) {
+    if value.is_string() {
+        if let Some(number) = info_number(value) {
+            *value = Value::Number(number.into());
         }
     }
 }
@@ -262,6 +637,179 @@ impl NeopdfSet {
     }
 }
 
+/// Errors that can occur while reading a PDF member from an HDF5 file.
+#[cfg(feature = "hdf5")]
+#[derive(Debug, Error)]
+pub enum Hdf5Error {
+    /// Error raised by the HDF5 library, e.g. a missing group or dataset.
+    #[error("HDF5 error: {0}")]
+    Hdf5(#[from] hdf5::Error),
+    /// Error indicating that the attributes do not form valid metadata.
+    #[error("Invalid metadata attributes: {0}")]
+    Metadata(#[from] serde_yaml::Error),
+    /// Error indicating that the `values` dataset does not match the axes.
+    #[error("The `values` dataset has {got} elements but the axes require {expected}")]
+    MismatchedValues {
+        /// The number of values required by the axes.
+        expected: usize,
+        /// The number of values in the dataset.
+        got: usize,
+    },
+}
+
+/// Reads PDF members stored in HDF5 files.
+///
+/// A member is stored in a single HDF5 group, containing the following datasets:
+///
+/// - `pids`: the particle flavor IDs, as a 1D integer dataset.
+/// - `xs` and `q2s`: the `x` and `Q2` knots, as 1D float datasets.
+/// - `nucleons`, `alphas` and `kts`: the knots of the optional dimensions, as 1D float
+///   datasets. Absent axes default to `[0.0]`, as for LHAPDF sets.
+/// - `values`: the `xf` values, whose row-major content follows the axis order
+///   `[nucleons, alphas, kts, xs, q2s, pids]`. Any shape with the right number of elements
+///   is accepted, e.g. `[xs, q2s, pids]` if the optional axes are absent.
+///
+/// Members with several subgrids store the axes and the values of each subgrid in the
+/// subgroups `subgrid_0`, `subgrid_1`, etc., while `pids` stays in the member group.
+///
+/// The attributes of the member group are mapped onto the metadata keys of the `.info`
+/// files (e.g. `SetDesc`, `AlphaS_MZ` or `InterpolatorType`). The keys that can be derived
+/// from the grid (`XMin`, `XMax`, `QMin`, `QMax`, `Flavors`, ...) are optional.
+#[cfg(feature = "hdf5")]
+pub struct Hdf5Set;
+
+#[cfg(feature = "hdf5")]
+impl Hdf5Set {
+    /// Reads the metadata and data of the PDF member stored in a given group.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to the HDF5 file.
+    /// * `group` - The name of the group containing the member (e.g. `"/"`).
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the `MetaData` and `GridArray` of the member, or an
+    /// `Hdf5Error`.
+    pub fn read(path: &Path, group: &str) -> Result<(MetaData, GridArray), Hdf5Error> {
+        let file = hdf5::File::open(path)?;
+        let member = file.group(group)?;
+        let pids: Vec<i32> = member.dataset("pids")?.read_raw()?;
+
+        let subgrid_groups: Vec<hdf5::Group> = if member.link_exists("subgrid_0") {
+            (0..)
+                .map(|idx| format!("subgrid_{idx}"))
+                .take_while(|name| member.link_exists(name))
+                .map(|name| member.group(&name))
+                .collect::<Result<_, _>>()?
+        } else {
+            vec![member.clone()]
+        };
+        let subgrid_data = subgrid_groups
+            .iter()
+            .map(|subgrid| Self::read_subgrid(subgrid, pids.len()))
+            .collect::<Result<Vec<_>, _>>()?;
+        let knot_array = GridArray::new(subgrid_data, pids);
+
+        let mut mapping = serde_yaml::Mapping::new();
+        for name in member.attr_names()? {
+            if let Some(value) = Self::read_attribute(&member.attr(&name)?)? {
+                mapping.insert(name.into(), value);
+            }
+        }
+
+        let ranges = knot_array.global_ranges();
+        let defaults = [
+            ("SetDesc", serde_yaml::Value::from(group)),
+            ("SetIndex", 0.into()),
+            ("NumMembers", 1.into()),
+            ("XMin", ranges.x.min.into()),
+            ("XMax", ranges.x.max.into()),
+            ("QMin", ranges.q2.min.sqrt().into()),
+            ("QMax", ranges.q2.max.sqrt().into()),
+            ("Flavors", knot_array.pids.to_vec().into()),
+            ("Format", "neopdf".into()),
+        ];
+        for (key, value) in defaults {
+            if !mapping.contains_key(key) {
+                mapping.insert(key.into(), value);
+            }
+        }
+        let info: MetaData = serde_yaml::from_value(serde_yaml::Value::Mapping(mapping))?;
+
+        Ok((info, knot_array))
+    }
+
+    /// Reads the axes and values of a single subgrid.
+    fn read_subgrid(group: &hdf5::Group, nflav: usize) -> Result<SubgridData, Hdf5Error> {
+        let read_axis = |name: &str| -> Result<Vec<f64>, Hdf5Error> {
+            if group.link_exists(name) {
+                Ok(group.dataset(name)?.read_raw()?)
+            } else {
+                Ok(vec![0.0])
+            }
+        };
+
+        let subgrid = SubgridData {
+            nucleons: read_axis("nucleons")?,
+            alphas: read_axis("alphas")?,
+            kts: read_axis("kts")?,
+            xs: group.dataset("xs")?.read_raw()?,
+            q2s: group.dataset("q2s")?.read_raw()?,
+            grid_data: group.dataset("values")?.read_raw()?,
+        };
+
+        let expected = [
+            &subgrid.nucleons,
+            &subgrid.alphas,
+            &subgrid.kts,
+            &subgrid.xs,
+            &subgrid.q2s,
+        ]
+        .iter()
+        .map(|axis| axis.len())
+        .product::<usize>()
+            * nflav;
+        if subgrid.grid_data.len() != expected {
+            return Err(Hdf5Error::MismatchedValues {
+                expected,
+                got: subgrid.grid_data.len(),
+            });
+        }
+
+        Ok(subgrid)
+    }
+
+    /// Converts a scalar or 1D attribute into a metadata value.
+    ///
+    /// Attributes of unsupported types are skipped.
+    fn read_attribute(attr: &hdf5::Attribute) -> Result<Option<serde_yaml::Value>, Hdf5Error> {
+        use hdf5::types::{TypeDescriptor, VarLenAscii, VarLenUnicode};
+
+        let scalar = attr.is_scalar();
+        let value: serde_yaml::Value = match attr.dtype()?.to_descriptor()? {
+            TypeDescriptor::Float(_) if scalar => attr.read_scalar::<f64>()?.into(),
+            TypeDescriptor::Float(_) => attr.read_raw::<f64>()?.into(),
+            TypeDescriptor::Integer(_) | TypeDescriptor::Unsigned(_) if scalar => {
+                attr.read_scalar::<i64>()?.into()
+            }
+            TypeDescriptor::Integer(_) | TypeDescriptor::Unsigned(_) => {
+                attr.read_raw::<i64>()?.into()
+            }
+            TypeDescriptor::Boolean if scalar => attr.read_scalar::<bool>()?.into(),
+            TypeDescriptor::VarLenUnicode if scalar => {
+                attr.read_scalar::<VarLenUnicode>()?.as_str().into()
+            }
+            TypeDescriptor::VarLenAscii if scalar => {
+                attr.read_scalar::<VarLenAscii>()?.as_str().into()
+            }
+            _ => return Ok(None),
+        };
+
+        Ok(Some(value))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -296,6 +844,58 @@ mod tests {
         assert_eq!(info.format, "LHAPDF");
     }
 
+    #[test]
+    fn test_parse_info() {
+        let info_content = r#"# Generated by a Fortran fitting code
+SetDesc: "NNPDF40_nnlo_as_01180 with quirks"
+SetIndex: 331100
+Authors: NNPDF Collaboration
+NumMembers: 101.0
+Particle: 2212
+Flavors: [-5,-4, -3,-2,-1,1,2,3,4,5,21]
+OrderQCD: 2
+Format: lhagrid1
+XMin: 1E-09
+XMax: 1
+QMin: "1.0D+00"
+MZ: 91.1876
+AlphaS_MZ: 0.118
+AlphaS_Qs: [1.0E+00, "9.1187600E+01"]
+AlphaS_Vals: [0.36, 0.118]
+"#;
+        let info = parse_info(info_content.as_bytes()).unwrap();
+
+        assert_eq!(info.set_desc, "NNPDF40_nnlo_as_01180 with quirks");
+        assert_eq!(info.set_index, 331100);
+        assert_eq!(info.num_members, 101);
+        assert_eq!(info.hadron_pid, 2212);
+        assert_eq!(info.flavors, vec![-5, -4, -3, -2, -1, 1, 2, 3, 4, 5, 21]);
+        assert_eq!(info.order_qcd, 2);
+        assert_eq!(info.x_min, 1.0e-9);
+        assert_eq!(info.x_max, 1.0);
+        assert_eq!(info.q_min, 1.0);
+        // Missing numeric entries are default-filled, without restricting the valid range.
+        assert_eq!(info.q_max, f64::MAX);
+        assert!(info.in_valid_range(1e-3, 1e4));
+        assert_eq!(info.m_z, 91.1876);
+        assert_eq!(info.alphas_mz(), Some(0.118));
+        assert_eq!(info.alphas_q_values, vec![1.0, 91.1876]);
+        assert_eq!(info.alphas_vals, vec![0.36, 0.118]);
+
+        let string_flavors = "SetDesc: set\nFormat: lhagrid1\nFlavors: \"21, 1, -1\"\n";
+        let info = parse_info(string_flavors.as_bytes()).unwrap();
+        assert_eq!(info.flavors, vec![21, 1, -1]);
+        assert_eq!((info.set_index, info.num_members), (0, 0));
+        assert_eq!((info.x_min, info.x_max), (0.0, 1.0));
+        assert_eq!((info.q_min, info.q_max), (0.0, f64::MAX));
+        assert!(info.in_valid_range(1.0, 1e8));
+
+        assert!(matches!(
+            parse_info("SetDesc: [unclosed".as_bytes()),
+            Err(ParseError::Yaml(_))
+        ));
+    }
+
     #[test]
     fn test_read_data() {
         let data_content = r#"
@@ -341,4 +941,118 @@ mod tests {
             vec![10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0, 17.0, 18.0]
         );
     }
+
+    #[test]
+    fn test_open_member() {
+        let content = "PdfType: central\n---\n\
+            1.0e-3 1.0e-1 1.0\n\
+            1.0 2.0\n\
+            21 2\n\
+            1.0 2.0 3.0 4.0\n\
+            5.0 6.0 7.0 8.0\n\
+            9.0 10.0 11.0 12.0\n\
+            ---\n";
+        let dir = tempfile::tempdir().unwrap();
+        let plain = dir.path().join("Set_0000.dat");
+        fs::write(&plain, content).unwrap();
+        let subgrids = open_member(&plain).unwrap();
+        assert_eq!(subgrids, parse_member_dat(content.as_bytes()).unwrap());
+
+        let gzipped = dir.path().join("Set_0000.dat.gz");
+        let mut encoder = flate2::write::GzEncoder::new(
+            fs::File::create(&gzipped).unwrap(),
+            flate2::Compression::default(),
+        );
+        encoder.write_all(content.as_bytes()).unwrap();
+        encoder.finish().unwrap();
+
+        #[cfg(feature = "gzip")]
+        {
+            assert_eq!(open_member(&gzipped).unwrap(), subgrids);
+            let (plain_data, gzipped_data) =
+                (LhapdfSet::read_data(&plain), LhapdfSet::read_data(&gzipped));
+            assert_eq!(plain_data.pids, gzipped_data.pids);
+            assert_eq!(
+                plain_data.subgrid_data[0].grid_data,
+                gzipped_data.subgrid_data[0].grid_data
+            );
+        }
+        #[cfg(not(feature = "gzip"))]
+        assert!(matches!(
+            open_member(&gzipped),
+            Err(ParseError::Io(error)) if error.kind() == std::io::ErrorKind::InvalidData
+        ));
+    }
+
+    #[test]
+    fn test_parse_member_dat() {
+        let data_content = "PdfType: central\nFormat: lhagrid1\n---\n\
+            1.0e-3 1.0e-2 1.0e-1 1.0 \n\
+            1.0 2.0\t\n\
+            21 2\n\
+            1.0 2.0 3.0 4.0\n\
+            5.0 6.0 7.0 8.0\n\
+            9.0 10.0 11.0 12.0\n\
+            13.0 14.0 15.0 16.0   \n\
+            ---\n\
+            1.0e-3 1.0e-2 1.0e-1 1.0\n\
+            2.0 10.0 100.0\n\
+            21 2\n\
+            1.0 2.0 3.0 4.0 5.0 6.0\n\
+            7.0 8.0 9.0 10.0 11.0 12.0\n\
+            13.0 14.0 15.0 16.0 17.0 18.0\n\
+            19.0 20.0 21.0 22.0 23.0 24.0\n\
+            ---\n";
+        let subgrids = parse_member_dat(data_content.as_bytes()).unwrap();
+
+        let xs = vec![1.0e-3, 1.0e-2, 1.0e-1, 1.0];
+        let expected = [
+            SubGrid::new(
+                vec![0.0],
+                vec![0.0],
+                vec![0.0],
+                xs.clone(),
+                vec![1.0, 4.0],
+                2,
+                (1..=16).map(f64::from).collect(),
+            ),
+            SubGrid::new(
+                vec![0.0],
+                vec![0.0],
+                vec![0.0],
+                xs,
+                vec![4.0, 100.0, 10000.0],
+                2,
+                (1..=24).map(f64::from).collect(),
+            ),
+        ];
+        assert_eq!(subgrids, expected);
+
+        // The final `---` is optional.
+        let single = "PdfType: central\n---\n1.0e-1 1.0\n1.0 2.0\n21\n1.0 2.0\n3.0 4.0\n";
+        assert_eq!(parse_member_dat(single.as_bytes()).unwrap().len(), 1);
+
+        let mismatch = "---\n1.0e-1 1.0\n1.0 2.0\n21 2\n1 2 3 4 5 6 7 8\n---\n\
+            1.0e-1 1.0\n1.0 2.0\n21\n1 2 3 4\n---\n";
+        assert!(matches!(
+            parse_member_dat(mismatch.as_bytes()),
+            Err(ParseError::FlavorCountMismatch {
+                block: 1,
+                expected: 2,
+                found: 1
+            })
+        ));
+
+        let missing = "---\n1.0e-1 1.0\n1.0 2.0\n21\n1.0 2.0 3.0\n---\n";
+        assert!(matches!(
+            parse_member_dat(missing.as_bytes()),
+            Err(ParseError::Shape { block: 0, .. })
+        ));
+
+        let invalid = "---\n1.0e-1 abc\n";
+        assert!(matches!(
+            parse_member_dat(invalid.as_bytes()),
+            Err(ParseError::InvalidNumber { line: 2, ref token }) if token == "abc"
+        ));
+    }
 }
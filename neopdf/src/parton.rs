@@ -0,0 +1,134 @@
+//! This module defines the partons that are commonly stored in PDF sets.
+//!
+//! The [`Parton`] enum provides a typed alternative to the PDG IDs accepted by the
+//! interpolation methods, e.g. `pdf.xfxq2(Parton::Gluon, &[x, q2])`. The function
+//! [`canonical_pid`] resolves the `LHAPDF` convention in which `0` denotes the gluon.
+
+/// Maps a PDG ID to its canonical form, i.e. the alias `0` of the gluon to `21`.
+///
+/// Flavor IDs should be compared through this function, such that sets declaring the gluon
+/// as `0` can be queried with `21` and vice versa.
+pub fn canonical_pid(pid: i32) -> i32 {
+    if pid == 0 {
+        21
+    } else {
+        pid
+    }
+}
+
+/// Represents a parton, identified by its PDG ID.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Parton {
+    /// The top antiquark (`-6`).
+    AntiTop,
+    /// The bottom antiquark (`-5`).
+    AntiBottom,
+    /// The charm antiquark (`-4`).
+    AntiCharm,
+    /// The strange antiquark (`-3`).
+    AntiStrange,
+    /// The up antiquark (`-2`).
+    AntiUp,
+    /// The down antiquark (`-1`).
+    AntiDown,
+    /// The down quark (`1`).
+    Down,
+    /// The up quark (`2`).
+    Up,
+    /// The strange quark (`3`).
+    Strange,
+    /// The charm quark (`4`).
+    Charm,
+    /// The bottom quark (`5`).
+    Bottom,
+    /// The top quark (`6`).
+    Top,
+    /// The gluon (`21`).
+    Gluon,
+    /// The photon (`22`).
+    Photon,
+}
+
+impl Parton {
+    /// Returns the PDG ID of the parton.
+    pub fn to_pdg(self) -> i32 {
+        match self {
+            Self::AntiTop => -6,
+            Self::AntiBottom => -5,
+            Self::AntiCharm => -4,
+            Self::AntiStrange => -3,
+            Self::AntiUp => -2,
+            Self::AntiDown => -1,
+            Self::Down => 1,
+            Self::Up => 2,
+            Self::Strange => 3,
+            Self::Charm => 4,
+            Self::Bottom => 5,
+            Self::Top => 6,
+            Self::Gluon => 21,
+            Self::Photon => 22,
+        }
+    }
+
+    /// Creates a `Parton` from its PDG ID.
+    ///
+    /// As in `LHAPDF`, the gluon can also be identified by `0`.
+    ///
+    /// # Arguments
+    ///
+    /// * `pid` - The PDG ID.
+    ///
+    /// # Returns
+    ///
+    /// The corresponding `Parton`, or `None` if `pid` does not denote a parton.
+    pub fn from_pdg(pid: i32) -> Option<Self> {
+        let parton = match pid {
+            -6 => Self::AntiTop,
+            -5 => Self::AntiBottom,
+            -4 => Self::AntiCharm,
+            -3 => Self::AntiStrange,
+            -2 => Self::AntiUp,
+            -1 => Self::AntiDown,
+            1 => Self::Down,
+            2 => Self::Up,
+            3 => Self::Strange,
+            4 => Self::Charm,
+            5 => Self::Bottom,
+            6 => Self::Top,
+            0 | 21 => Self::Gluon,
+            22 => Self::Photon,
+            _ => return None,
+        };
+
+        Some(parton)
+    }
+}
+
+impl From<Parton> for i32 {
+    fn from(parton: Parton) -> Self {
+        parton.to_pdg()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pdg_roundtrip() {
+        for pid in (-6..=6).filter(|&pid| pid != 0).chain([21, 22]) {
+            assert_eq!(Parton::from_pdg(pid).unwrap().to_pdg(), pid);
+        }
+        assert_eq!(Parton::from_pdg(0), Some(Parton::Gluon));
+        assert_eq!(Parton::from_pdg(7), None);
+        assert_eq!(i32::from(Parton::AntiUp), -2);
+    }
+
+    #[test]
+    fn test_canonical_pid() {
+        assert_eq!(canonical_pid(0), 21);
+        assert_eq!(canonical_pid(21), 21);
+        assert_eq!(canonical_pid(-1), -1);
+        assert_eq!(canonical_pid(22), 22);
+    }
+}
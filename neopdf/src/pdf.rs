@@ -19,13 +19,63 @@
 //! - Loader functions: [`PDF::load`], [`PDF::load_pdfs`], and internal helpers for batch loading.
 //!
 //! See the documentation for [`PDF`] for more details on available methods and usage patterns.
+use itertools::Itertools;
 use ndarray::{Array1, Array2};
+#[cfg(feature = "rayon")]
 use rayon::prelude::*;
+use serde::Serialize;
+use std::collections::HashMap;
 
-use super::gridpdf::{ForcePositive, GridArray, GridPDF};
-use super::metadata::MetaData;
-use super::parser::{LhapdfSet, NeopdfSet};
-use super::subgrid::{RangeParameters, SubGrid};
+use super::alphas::AlphaSOde;
+use super::gridpdf::{
+    Error, ExtrapolationPolicy, ForcePositive, GridArray, GridPDF, InterpScratch, InterpTrace,
+    ScaleUnit,
+};
+use super::interpolator::{Axis, InterpolationConfig, Point};
+use super::metadata::{InterpolatorType, MetaData, MetaDataPatch};
+#[cfg(feature = "hdf5")]
+use super::parser::{Hdf5Error, Hdf5Set};
+use super::parser::{LhapdfSet, NeopdfSet, SubgridData};
+use super::subgrid::{ParamRange, RangeParameters, SubGrid};
+use super::utils::simpson_integrate;
+
+/// Summary of the kinematic reach and content of a PDF, see [`PDF::coverage_report`].
+#[derive(Clone, Debug)]
+pub struct CoverageReport {
+    /// The range in the momentum fraction `x`.
+    pub x_range: ParamRange,
+    /// The range in the energy scale `Q2`.
+    pub q2_range: ParamRange,
+    /// The range in the nucleon numbers `A`.
+    pub nucleons_range: ParamRange,
+    /// The range in the strong coupling `alpha_s`.
+    pub alphas_range: ParamRange,
+    /// The range in the transverse momentum `kT`.
+    pub kt_range: ParamRange,
+    /// The number of subgrids.
+    pub num_subgrids: usize,
+    /// The number of flavors.
+    pub num_flavors: usize,
+    /// The interpolator used for the PDF.
+    pub interpolator: InterpolatorType,
+}
+
+/// Number of subintervals in `ln(x)` used by [`PDF::parton_luminosity`].
+const LUMINOSITY_INTERVALS: usize = 200;
+
+/// Tolerance below which negative PDF values are not reported by [`PDF::validate_positivity`].
+const POSITIVITY_TOLERANCE: f64 = 1e-10;
+
+/// Represents a point at which an interpolated PDF value is negative.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PositivityViolation {
+    /// The flavor ID (PDG ID) of the negative PDF.
+    pub pid: i32,
+    /// The coordinates of the point, ordered as the inputs of [`PDF::xfxq2`].
+    pub point: Vec<f64>,
+    /// The (negative) interpolated PDF value.
+    pub value: f64,
+}
 
 /// Trait for abstracting over different PDF set backends (e.g., LHAPDF, NeoPDF).
 ///
@@ -68,9 +118,36 @@ impl PdfSet for NeopdfSet {
 /// A [`PDF`] instance for the specified member.
 fn pdfset_loader<T: PdfSet>(set: T, member: usize) -> PDF {
     let (info, knot_array) = set.member(member);
-    PDF {
-        grid_pdf: GridPDF::new(info, knot_array),
+    PDF::from_parts(info, knot_array)
+}
+
+/// Builds a [`PDF`] with an interpolator overriding the one defined in the metadata.
+///
+/// # Arguments
+///
+/// * `info` - The metadata of the PDF set.
+/// * `knot_array` - The grid data of the PDF member.
+/// * `interpolator_type` - The interpolator to use.
+///
+/// # Returns
+///
+/// A [`PDF`] instance, or an error if the interpolator does not support the dimensions of
+/// one of the subgrids.
+fn pdf_with_interpolator(
+    mut info: MetaData,
+    knot_array: GridArray,
+    interpolator_type: InterpolatorType,
+) -> Result<PDF, Error> {
+    let is_supported = knot_array
+        .subgrids
+        .iter()
+        .all(|subgrid| subgrid.interpolation_config().supports(&interpolator_type));
+    if !is_supported {
+        return Err(Error::IncompatibleInterpolator(interpolator_type));
     }
+
+    info.interpolator_type = interpolator_type;
+    Ok(PDF::from_parts(info, knot_array))
 }
 
 /// Loads all PDF members from a generic PDF set backend in sequential.
@@ -86,14 +163,13 @@ fn pdfsets_seq_loader<T: PdfSet + Send + Sync>(set: T) -> Vec<PDF> {
     (0..set.num_members())
         .map(|idx| {
             let (info, knot_array) = set.member(idx);
-            PDF {
-                grid_pdf: GridPDF::new(info, knot_array),
-            }
+            PDF::from_parts(info, knot_array)
         })
         .collect()
 }
 
-/// Loads all PDF members from a generic PDF set backend in parallel.
+/// Loads all PDF members from a generic PDF set backend, in parallel with the `rayon`
+/// feature.
 ///
 /// # Arguments
 ///
@@ -103,15 +179,17 @@ fn pdfsets_seq_loader<T: PdfSet + Send + Sync>(set: T) -> Vec<PDF> {
 ///
 /// A vector of [`PDF`] instances, one for each member in the set.
 fn pdfsets_par_loader<T: PdfSet + Send + Sync>(set: T) -> Vec<PDF> {
-    (0..set.num_members())
-        .into_par_iter()
-        .map(|idx| {
-            let (info, knot_array) = set.member(idx);
-            PDF {
-                grid_pdf: GridPDF::new(info, knot_array),
-            }
-        })
-        .collect()
+    let load = |idx| {
+        let (info, knot_array) = set.member(idx);
+        PDF::from_parts(info, knot_array)
+    };
+
+    #[cfg(feature = "rayon")]
+    let members = (0..set.num_members()).into_par_iter().map(load).collect();
+    #[cfg(not(feature = "rayon"))]
+    let members = (0..set.num_members()).map(load).collect();
+
+    members
 }
 
 /// Represents a Parton Distribution Function (PDF) set.
@@ -124,6 +202,13 @@ pub struct PDF {
 }
 
 impl PDF {
+    /// Creates a `PDF` from its metadata and grid data.
+    pub(crate) fn from_parts(info: MetaData, knot_array: GridArray) -> Self {
+        Self {
+            grid_pdf: GridPDF::new(info, knot_array),
+        }
+    }
+
     /// Loads a given member of the PDF set.
     ///
     /// This function reads the `.info` file and the corresponding `.dat` member file
@@ -145,6 +230,53 @@ impl PDF {
         }
     }
 
+    /// Loads a given member of the PDF set with a user-defined interpolator.
+    ///
+    /// The interpolator overrides the `InterpolatorType` defined in the metadata of the set.
+    ///
+    /// # Arguments
+    ///
+    /// * `pdf_name` - The name of the PDF set (e.g., "NNPDF40_nnlo_as_01180").
+    /// * `member` - The ID of the PDF member to load (0-indexed).
+    /// * `interpolator_type` - The interpolator to use.
+    ///
+    /// # Returns
+    ///
+    /// A `PDF` instance, or an error if the interpolator is not compatible with the
+    /// dimensions of the grids.
+    pub fn load_with_interpolator(
+        pdf_name: &str,
+        member: usize,
+        interpolator_type: InterpolatorType,
+    ) -> Result<Self, Error> {
+        let (info, knot_array) = if pdf_name.ends_with(".neopdf.lz4") {
+            NeopdfSet::new(pdf_name).member(member)
+        } else {
+            LhapdfSet::new(pdf_name).member(member)
+        };
+
+        pdf_with_interpolator(info, knot_array, interpolator_type)
+    }
+
+    /// Loads a PDF member stored in an HDF5 file.
+    ///
+    /// See [`Hdf5Set`] for the naming convention of the datasets and attributes.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to the HDF5 file.
+    /// * `group` - The name of the group containing the member (e.g. `"/"`).
+    ///
+    /// # Returns
+    ///
+    /// A `PDF` instance, or an `Hdf5Error` if the file does not follow the convention.
+    #[cfg(feature = "hdf5")]
+    pub fn from_hdf5<P: AsRef<std::path::Path>>(path: P, group: &str) -> Result<Self, Hdf5Error> {
+        let (info, knot_array) = Hdf5Set::read(path.as_ref(), group)?;
+
+        Ok(Self::from_parts(info, knot_array))
+    }
+
     /// Loads all members of a PDF set in parallel.
     ///
     /// This function reads the `.info` file and all `.dat` member files
@@ -214,9 +346,7 @@ impl PDF {
             grid_array_with_metadata_result.map(|grid_array_with_metadata| {
                 let info = (*grid_array_with_metadata.metadata).clone();
                 let knot_array = grid_array_with_metadata.grid;
-                PDF {
-                    grid_pdf: GridPDF::new(info, knot_array),
-                }
+                PDF::from_parts(info, knot_array)
             })
         })
     }
@@ -254,164 +384,2105 @@ impl PDF {
             .unwrap_or(&ForcePositive::NoClipping)
     }
 
-    /// Interpolates the PDF value (xf) for a given nucleon, alphas, flavor, x, and Q2.
+    /// Sets the policy for queries outside of the grid coverage.
     ///
-    /// Abstraction to the `GridPDF::xfxq2` method.
+    /// The default policy is `ExtrapolationPolicy::Clamp`, and it can still be overridden
+    /// for individual queries with `PDF::xfxq2_with_policy`. With `ExtrapolationPolicy::Error`,
+    /// the out-of-range queries make `PDF::xfxq2` panic, use `PDF::try_xfxq2` instead.
     ///
     /// # Arguments
     ///
-    /// * `id` - The flavor ID (PDG ID).
-    /// * `points` - A slice containing the collection of points to interpolate on.
+    /// * `policy` - The `ExtrapolationPolicy` used by all subsequent queries.
+    pub fn set_extrapolation_policy(&mut self, policy: ExtrapolationPolicy) {
+        self.grid_pdf.set_extrapolation_policy(policy);
+    }
+
+    /// Returns the policy for queries outside of the grid coverage.
     ///
     /// # Returns
     ///
-    /// The interpolated PDF value `xf(nuclone, alphas, flavor, x, Q^2)`.
-    pub fn xfxq2(&self, pid: i32, points: &[f64]) -> f64 {
-        self.grid_pdf.xfxq2(pid, points).unwrap()
+    /// The `ExtrapolationPolicy` used by `PDF::xfxq2`.
+    pub fn extrapolation_policy(&self) -> ExtrapolationPolicy {
+        self.grid_pdf.extrapolation_policy()
     }
 
-    /// Interpolates the PDF value (xf) for multiple nucleons, alphas, flavors, xs, and Q2s.
+    /// Sets whether `ExtrapolationPolicy::LogLinear` extrapolates the queries below `x`.
     ///
-    /// Abstraction to the `GridPDF::xfxq2s` method.
+    /// The extrapolation is disabled by default for `TimeLike` sets (fragmentation functions),
+    /// whose queries below `x` then fail, and enabled for `SpaceLike` sets.
     ///
     /// # Arguments
     ///
-    /// * `ids` - A vector of flavor IDs.
-    /// * `slice_points` - A slice containing the collection of knots to interpolate on.
-    ///   A knot is a collection of points containing `(nucleon, alphas, x, Q2)`.
+    /// * `enabled` - Whether the queries below `x` are extrapolated.
+    pub fn set_low_x_extrapolation(&mut self, enabled: bool) {
+        self.grid_pdf.set_low_x_extrapolation(enabled);
+    }
+
+    /// Returns whether `ExtrapolationPolicy::LogLinear` extrapolates the queries below `x`.
     ///
     /// # Returns
     ///
-    /// A 2D array of interpolated PDF values with shape `[flavors, N_knots]`.
-    pub fn xfxq2s(&self, pids: Vec<i32>, slice_points: &[&[f64]]) -> Array2<f64> {
-        self.grid_pdf.xfxq2s(pids, slice_points)
+    /// `false` by default for `TimeLike` sets, `true` otherwise.
+    pub fn low_x_extrapolation(&self) -> bool {
+        self.grid_pdf.low_x_extrapolation()
     }
 
-    /// Interpolates the PDF value (xf) for multiple points using Chebyshev batch interpolation.
+    /// Sets the interpolators of specific flavors, overriding the one of the metadata.
     ///
-    /// Abstraction to the `GridPDF::xfxq2_cheby_batch` method.
+    /// Abstraction to the `GridPDF::set_interpolator_overrides` method.
     ///
     /// # Arguments
     ///
-    /// * `pid` - The flavor ID.
-    /// * `points` - A slice containing the collection of knots to interpolate on.
-    ///   A knot is a collection of points containing `(nucleon, alphas, x, Q2)`.
+    /// * `overrides` - The interpolators of the overridden flavors, by PDG ID. The other
+    ///   flavors use the `interpolator_type` of the metadata.
     ///
     /// # Returns
     ///
-    /// A `Vec<f64>` of interpolated PDF values.
-    pub fn xfxq2_cheby_batch(&self, pid: i32, points: &[&[f64]]) -> Vec<f64> {
-        self.grid_pdf.xfxq2_cheby_batch(pid, points).unwrap()
+    /// `Ok(())`, or `Error::IncompatibleInterpolator` if an interpolator does not support the
+    /// dimensions of one of the subgrids.
+    pub fn set_interpolator_overrides(
+        &mut self,
+        overrides: HashMap<i32, InterpolatorType>,
+    ) -> Result<(), Error> {
+        self.grid_pdf.set_interpolator_overrides(overrides)
     }
 
-    /// Interpolates the strong coupling constant `alpha_s` for a given Q2.
+    /// Returns the interpolators overriding the one of the metadata, by PDG ID.
+    pub fn interpolator_overrides(&self) -> &HashMap<i32, InterpolatorType> {
+        self.grid_pdf.interpolator_overrides()
+    }
+
+    /// Sets the unit of the scale argument of `PDF::xfxq2` and the related methods.
     ///
-    /// Abstraction to the `GridPDF::alphas_q2` method.
+    /// By default, the scale is given as `Q2`. With `ScaleUnit::Q`, the last coordinate of
+    /// the points is interpreted as `Q` and squared before the interpolation. The methods
+    /// taking an explicit `q2` argument, such as `PDF::xfxq2_batch`, are not affected.
     ///
     /// # Arguments
     ///
-    /// * `q2` - The squared energy scale.
+    /// * `unit` - The `ScaleUnit` of the queries.
     ///
     /// # Returns
     ///
-    /// The interpolated `alpha_s` value.
-    pub fn alphas_q2(&self, q2: f64) -> f64 {
-        self.grid_pdf.alphas_q2(q2)
+    /// The `PDF` with the new scale unit.
+    pub fn with_query_scale(mut self, unit: ScaleUnit) -> Self {
+        self.grid_pdf.set_query_scale(unit);
+        self
     }
 
-    /// Returns a reference to the PDF metadata.
+    /// Returns the unit of the scale argument of `PDF::xfxq2`.
     ///
-    /// Abstraction to the `GridPDF::info` method.
+    /// # Returns
+    ///
+    /// The `ScaleUnit` of the queries.
+    pub fn query_scale(&self) -> ScaleUnit {
+        self.grid_pdf.query_scale()
+    }
+
+    /// Creates a new `PDF` in which the grid values of a given flavor are rescaled.
+    ///
+    /// This is useful to apply ad-hoc per-flavor corrections, e.g. scaling the strange
+    /// quark by a constant factor. All the other flavors are left untouched.
+    ///
+    /// # Arguments
+    ///
+    /// * `pid` - The flavor ID (PDG ID) to rescale.
+    /// * `factor` - The multiplicative factor applied to the grid values.
     ///
     /// # Returns
     ///
-    /// A `MetaData` struct containing information about the PDF set.
-    pub fn metadata(&self) -> &MetaData {
-        self.grid_pdf.metadata()
+    /// A new `PDF` instance, or an error if the flavor is not part of the grid.
+    pub fn scale_flavor(&self, pid: i32, factor: f64) -> Result<PDF, Error> {
+        let mut knot_array = self.grid_pdf.knot_array.clone();
+        knot_array.scale_flavor(pid, factor)?;
+
+        let mut grid_pdf = GridPDF::new(self.metadata().clone(), knot_array);
+        grid_pdf.inherit_options(&self.grid_pdf);
+
+        Ok(PDF { grid_pdf })
     }
 
-    /// Returns the number of subgrids in the PDF set.
+    /// Computes the metadata-only patch turning the metadata of `base` into the one of `self`.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The previous version of the PDF, with the same grid.
     ///
     /// # Returns
     ///
-    /// The number of subgrids.
-    pub fn num_subgrids(&self) -> usize {
-        self.grid_pdf.knot_array.subgrids.len()
+    /// The `MetaDataPatch` between the two versions, or an error if the grids differ, in
+    /// which case the update cannot be described by the metadata alone.
+    pub fn metadata_patch_against(&self, base: &PDF) -> Result<MetaDataPatch, Error> {
+        if self.grid_pdf.knot_array != base.grid_pdf.knot_array {
+            return Err(Error::IncompatibleGrids(
+                "the grids differ and cannot be described by a metadata patch".to_string(),
+            ));
+        }
+
+        Ok(MetaDataPatch::between(base.metadata(), self.metadata()))
     }
 
-    /// Returns a reference to the subgrid at the given index.
+    /// Creates a new `PDF` by applying a metadata-only patch to this PDF.
     ///
     /// # Arguments
     ///
-    /// * `index` - The index of the subgrid.
+    /// * `patch` - The patch, e.g. computed with `PDF::metadata_patch_against`.
     ///
     /// # Returns
     ///
-    /// A reference to the `SubGrid`.
-    pub fn subgrid(&self, index: usize) -> &SubGrid {
-        &self.grid_pdf.knot_array.subgrids[index]
+    /// A new `PDF` instance with the same grid and the patched metadata, or an error if the
+    /// patched entries are not valid.
+    pub fn apply_metadata_patch(&self, patch: &MetaDataPatch) -> Result<PDF, serde_yaml::Error> {
+        let info = patch.apply(self.metadata())?;
+
+        let mut grid_pdf = GridPDF::new(info, self.grid_pdf.knot_array.clone());
+        grid_pdf.inherit_options(&self.grid_pdf);
+
+        Ok(PDF { grid_pdf })
     }
 
-    /// Returns references to all the subgrid at the given index.
+    /// Creates a new `PDF` in which all the grid values are rounded to a number of significant
+    /// digits.
+    ///
+    /// This produces small and platform-independent fixtures, whose interpolation is only an
+    /// approximation of the original PDF.
+    ///
+    /// # Arguments
+    ///
+    /// * `significant_digits` - The number of significant digits to keep.
     ///
     /// # Returns
     ///
-    /// A reference to all the `SubGrid`.
-    pub fn subgrids(&self) -> &Vec<SubGrid> {
-        &self.grid_pdf.knot_array.subgrids
+    /// A new `PDF` instance with the rounded grid values.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `significant_digits` is zero.
+    pub fn trim_precision(&self, significant_digits: usize) -> PDF {
+        assert!(significant_digits > 0, "At least one digit must be kept");
+
+        let mut knot_array = self.grid_pdf.knot_array.clone();
+        for subgrid in &mut knot_array.subgrids {
+            // Round through the decimal representation, such that the values are the closest
+            // floating-point numbers to the trimmed decimals.
+            subgrid.grid.mapv_inplace(|value| {
+                format!("{:.*e}", significant_digits - 1, value)
+                    .parse()
+                    .expect("A formatted float is always parsable")
+            });
+        }
+
+        let mut grid_pdf = GridPDF::new(self.metadata().clone(), knot_array);
+        grid_pdf.inherit_options(&self.grid_pdf);
+
+        PDF { grid_pdf }
     }
 
-    /// Returns the flavor PIDS of the PDG Grid.
+    /// Creates a new `PDF` by morphing this PDF into another one.
+    ///
+    /// The grid values are linearly blended node by node as `(1 - t) * self + t * other`,
+    /// such that `t = 0` reproduces `self` and `t = 1` reproduces `other`. The metadata of
+    /// `self` is kept.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The PDF to morph into, with the same axes and flavors.
+    /// * `t` - The morphing parameter in `[0, 1]`.
     ///
     /// # Returns
     ///
-    /// PID representation of the PDF.
-    pub fn pids(&self) -> &Array1<i32> {
-        &self.grid_pdf.knot_array.pids
+    /// A new `PDF` instance, or an error if the grids are not compatible.
+    pub fn morph(&self, other: &PDF, t: f64) -> Result<PDF, Error> {
+        let knot_array = self
+            .grid_pdf
+            .knot_array
+            .morph(&other.grid_pdf.knot_array, t)?;
+
+        let mut grid_pdf = GridPDF::new(self.metadata().clone(), knot_array);
+        grid_pdf.inherit_options(&self.grid_pdf);
+
+        Ok(PDF { grid_pdf })
     }
 
-    /// Retrieves the ranges for the parameters.
+    /// Creates a new `PDF` by resampling this PDF onto the `x` and `Q2` knots of another one.
     ///
-    /// Abstraction to the `GridPDF::param_ranges` method.
+    /// The values at the knots of each subgrid of `reference` are interpolated from this PDF
+    /// for all its flavors, such that the result can be compared node by node with
+    /// `reference`. The metadata and the interpolator of `self` are kept, except for the
+    /// kinematic ranges which are the ones of `reference`.
+    ///
+    /// # Arguments
+    ///
+    /// * `reference` - The PDF providing the knots, whose range must be covered by `self`.
+    /// * `interp` - The interpolator used to evaluate `self` at the new knots.
     ///
     /// # Returns
     ///
-    /// The minimum and maximum values for the parameters (x, q2, ...).
-    pub fn param_ranges(&self) -> RangeParameters {
-        self.grid_pdf.param_ranges()
+    /// A new `PDF` instance with the axes of `reference`, or an error if the grids are not 2D,
+    /// if the range of `reference` exceeds the one of `self` or if `interp` is not compatible
+    /// with the grids of `self`.
+    pub fn resample_to_set(&self, reference: &PDF, interp: InterpolatorType) -> Result<PDF, Error> {
+        if !self.is_two_dimensional() || !reference.is_two_dimensional() {
+            return Err(Error::InterpolationError(
+                "resampling is only available for 2D grids".to_string(),
+            ));
+        }
+
+        let (support, target) = (self.param_ranges(), reference.param_ranges());
+        let covers = |outer: &ParamRange, inner: &ParamRange| {
+            outer.min <= inner.min && inner.max <= outer.max
+        };
+        if !covers(&support.x, &target.x) || !covers(&support.q2, &target.q2) {
+            return Err(Error::IncompatibleGrids(
+                "the range of the reference exceeds the support of the PDF".to_string(),
+            ));
+        }
+
+        let evaluator = pdf_with_interpolator(
+            self.metadata().clone(),
+            self.grid_pdf.knot_array.clone(),
+            interp,
+        )?
+        .grid_pdf;
+        let pids = self.pids().to_vec();
+        let subgrid_data = reference
+            .subgrids()
+            .iter()
+            .map(|subgrid| {
+                let grid_data = subgrid
+                    .xs
+                    .iter()
+                    .cartesian_product(subgrid.q2s.iter())
+                    .flat_map(|(&x, &q2)| {
+                        let evaluator = &evaluator;
+                        pids.iter().map(move |&pid| {
+                            evaluator.xfxq2_with_policy(pid, &[x, q2], ExtrapolationPolicy::Error)
+                        })
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                Ok(SubgridData {
+                    nucleons: subgrid.nucleons.to_vec(),
+                    alphas: subgrid.alphas.to_vec(),
+                    kts: subgrid.kts.to_vec(),
+                    xs: subgrid.xs.to_vec(),
+                    q2s: subgrid.q2s.to_vec(),
+                    grid_data,
+                })
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        let mut info = self.metadata().clone();
+        info.x_min = target.x.min;
+        info.x_max = target.x.max;
+        info.q_min = target.q2.min.sqrt();
+        info.q_max = target.q2.max.sqrt();
+
+        let mut grid_pdf = GridPDF::new(info, GridArray::new(subgrid_data, pids));
+        grid_pdf.inherit_options(&self.grid_pdf);
+
+        Ok(PDF { grid_pdf })
     }
 
-    /// Retrieves the PDF value (xf) at a specific knot point in the grid.
+    /// Whether all the subgrids only depend on `x` and `Q2`.
+    fn is_two_dimensional(&self) -> bool {
+        self.subgrids()
+            .iter()
+            .all(|sg| matches!(sg.interpolation_config(), InterpolationConfig::TwoD))
+    }
+
+    /// Creates a new `PDF` in which the `alpha_s` table is regenerated from the RGE.
     ///
-    /// Abstraction to the `GridArray::xf_from_index` method. This method does not
-    /// perform any interpolation.
+    /// The strong coupling is computed at each scale by solving the RGE anchored to the
+    /// reference value `AlphaS_MZ` (or to the value at `MZ` read from the current table). The
+    /// resulting table replaces `AlphaS_Qs`/`AlphaS_Vals` and is used for interpolation.
     ///
     /// # Arguments
     ///
-    /// * `i_nucleons` - The index of the nucleon.
-    /// * `i_alphas` - The index of the alpha_s value.
-    /// * `i_kt` - The index of the `kT` value.
-    /// * `ix` - The index of the x-value.
-    /// * `iq2` - The index of the Q2-value.
-    /// * `id` - The flavor ID.
-    /// * `subgrid_id` - The ID of the subgrid.
+    /// * `q2_grid` - The `Q2` values at which to tabulate `alpha_s`.
     ///
     /// # Returns
     ///
-    /// The PDF value at the specified knot.
-    #[allow(clippy::too_many_arguments)]
-    pub fn xf_from_index(
-        &self,
-        i_nucleons: usize,
-        i_alphas: usize,
-        i_kt: usize,
-        ix: usize,
-        iq2: usize,
-        id: i32,
-        subgrid_id: usize,
-    ) -> f64 {
+    /// A new `PDF` instance with the regenerated `alpha_s` table.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no reference value of `alpha_s` is available to solve the RGE.
+    pub fn regenerate_alphas_table(&self, q2_grid: &[f64]) -> PDF {
+        let rge =
+            AlphaSOde::from_metadata(self.metadata()).expect("Failed to set up the alpha_s RGE");
+
+        let mut q2s = q2_grid.to_vec();
+        q2s.sort_by(f64::total_cmp);
+        q2s.dedup();
+
+        let mut info = self.metadata().clone();
+        info.alphas_q_values = q2s.iter().map(|q2| q2.sqrt()).collect();
+        info.alphas_vals = q2s.iter().map(|&q2| rge.alphas_q2(q2)).collect();
+        info.alphas_type = "ipol".to_string();
+
+        let mut grid_pdf = GridPDF::new(info, self.grid_pdf.knot_array.clone());
+        grid_pdf.inherit_options(&self.grid_pdf);
+
+        PDF { grid_pdf }
+    }
+
+    /// Scans the interpolated PDF for negative values.
+    ///
+    /// For each subgrid, `x` and `Q2` are sampled at `samples` log-spaced points each, taken
+    /// at the centers of the logarithmic bins such that the scan probes the interpolation in
+    /// between the grid nodes. The remaining dimensions (`A`, `alpha_s`, `kT`), if present,
+    /// are evaluated at their knot values. This check is only meaningful for unpolarised sets.
+    ///
+    /// # Arguments
+    ///
+    /// * `pids` - The flavor IDs (PDG IDs) to check.
+    /// * `samples` - The number of sampled points along the `x` and `Q2` directions.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<PositivityViolation>` with all the points at which a flavor is negative beyond
+    /// a small tolerance.
+    pub fn validate_positivity(&self, pids: &[i32], samples: usize) -> Vec<PositivityViolation> {
+        let log_samples = |range: &ParamRange| -> Vec<f64> {
+            let (lmin, lmax) = (range.min.ln(), range.max.ln());
+            (0..samples)
+                .map(|i| (lmin + (lmax - lmin) * (i as f64 + 0.5) / samples as f64).exp())
+                .collect()
+        };
+
+        self.subgrids()
+            .iter()
+            .flat_map(|subgrid| {
+                let axes: Vec<Vec<f64>> = [&subgrid.nucleons, &subgrid.alphas, &subgrid.kts]
+                    .into_iter()
+                    .filter(|knots| knots.len() > 1)
+                    .map(|knots| knots.to_vec())
+                    .chain([
+                        log_samples(&subgrid.x_range),
+                        log_samples(&subgrid.q2_range),
+                    ])
+                    .collect();
+
+                axes.into_iter().multi_cartesian_product()
+            })
+            .flat_map(|point| {
+                pids.iter()
+                    .map(|&pid| PositivityViolation {
+                        pid,
+                        value: self.xfxq2_in_q2(pid, &point),
+                        point: point.clone(),
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .filter(|violation| violation.value < -POSITIVITY_TOLERANCE)
+            .collect()
+    }
+
+    /// Interpolates the PDF value (xf) for a given nucleon, alphas, flavor, x, and Q2.
+    ///
+    /// Abstraction to the `GridPDF::xfxq2` method.
+    ///
+    /// # Arguments
+    ///
+    /// * `pid` - The flavor ID, either as a PDG ID or as a [`crate::parton::Parton`].
+    /// * `points` - A slice containing the collection of points to interpolate on.
+    ///
+    /// # Returns
+    ///
+    /// The interpolated PDF value `xf(nuclone, alphas, flavor, x, Q^2)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the interpolation fails, e.g. for a query outside of the grid coverage with
+    /// `ExtrapolationPolicy::Error`. See `PDF::try_xfxq2` for the fallible version.
+    pub fn xfxq2(&self, pid: impl Into<i32>, points: &[f64]) -> f64 {
+        self.try_xfxq2(pid, points).unwrap()
+    }
+
+    /// Interpolates the PDF value (xf) for a given nucleon, alphas, flavor, x, and Q2.
+    ///
+    /// Abstraction to the `GridPDF::xfxq2` method, returning its errors instead of panicking.
+    ///
+    /// # Arguments
+    ///
+    /// * `pid` - The flavor ID, either as a PDG ID or as a [`crate::parton::Parton`].
+    /// * `points` - A slice containing the collection of points to interpolate on.
+    ///
+    /// # Returns
+    ///
+    /// The interpolated PDF value, or an `Error` if the query is outside of the grid coverage
+    /// with `ExtrapolationPolicy::Error`. As in `LHAPDF`, a flavor that is not part of the
+    /// grid evaluates to zero.
+    pub fn try_xfxq2(&self, pid: impl Into<i32>, points: &[f64]) -> Result<f64, Error> {
+        self.grid_pdf.xfxq2(pid.into(), points)
+    }
+
+    /// Interpolates the PDF value (xf) at a point whose scale is always `Q2`, independently of
+    /// the [`PDF::query_scale`], for the methods taking an explicit `q2` argument.
+    fn xfxq2_in_q2(&self, pid: impl Into<i32>, points: &[f64]) -> f64 {
         self.grid_pdf
-            .knot_array
-            .xf_from_index(i_nucleons, i_alphas, i_kt, ix, iq2, id, subgrid_id)
+            .xfxq2_in_q2(pid.into(), points, self.extrapolation_policy())
+            .unwrap()
+    }
+
+    /// Interpolates the PDF value (xf) for a given flavor, x, and Q.
+    ///
+    /// This mirrors `xfxQ` of LHAPDF: the scale `Q` is squared and the query is delegated to
+    /// `PDF::xfxq2`, independently of the [`PDF::query_scale`] of the PDF.
+    ///
+    /// # Arguments
+    ///
+    /// * `pid` - The flavor ID, either as a PDG ID or as a [`crate::parton::Parton`].
+    /// * `x` - The momentum fraction.
+    /// * `q` - The energy scale.
+    ///
+    /// # Returns
+    ///
+    /// The interpolated PDF value `xf(flavor, x, Q^2)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `q` is negative or NaN.
+    pub fn xfxq(&self, pid: impl Into<i32>, x: f64, q: f64) -> f64 {
+        assert!(q >= 0.0, "The scale Q must be non-negative, got {q}");
+
+        match self.query_scale() {
+            ScaleUnit::Q2 => self.xfxq2(pid, &[x, q * q]),
+            ScaleUnit::Q => self.xfxq2(pid, &[x, q]),
+        }
+    }
+
+    /// Interpolates the PDF value (xf) for a given flavor, x, Q2, and alpha_s.
+    ///
+    /// The value is interpolated across the `alpha_s` knots of the grid with the configured
+    /// interpolator, which requires the grid to have an `alpha_s` axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `pid` - The flavor ID, either as a PDG ID or as a [`crate::parton::Parton`].
+    /// * `x` - The momentum fraction.
+    /// * `q2` - The squared energy scale.
+    /// * `alphas` - The value of the strong coupling.
+    ///
+    /// # Returns
+    ///
+    /// The interpolated PDF value `xf(alphas, flavor, x, Q^2)`, or an `Error` if the
+    /// interpolation fails. `Error::AxisMismatch` is returned if the subgrid selected for the
+    /// point has a single `alpha_s` knot or further axes (`A` or `kT`).
+    pub fn xfx_at_alphas(
+        &self,
+        pid: impl Into<i32>,
+        x: f64,
+        q2: f64,
+        alphas: f64,
+    ) -> Result<f64, Error> {
+        let point = Point::new(x, q2).alphas(alphas);
+        self.xfx_in_config(pid.into(), &point, InterpolationConfig::ThreeDAlphas)
+    }
+
+    /// Interpolates the TMD PDF value (xf) for a given flavor, x, Q2, and transverse momentum.
+    ///
+    /// The value is interpolated across the `kT` knots of the grid with the configured
+    /// interpolator, which requires the grid to have a `kT` axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `pid` - The flavor ID, either as a PDG ID or as a [`crate::parton::Parton`].
+    /// * `x` - The momentum fraction.
+    /// * `q2` - The squared energy scale.
+    /// * `kt` - The transverse momentum.
+    ///
+    /// # Returns
+    ///
+    /// The interpolated PDF value `xf(kT, flavor, x, Q^2)`, or an `Error` if the interpolation
+    /// fails. `Error::AxisMismatch` is returned if the subgrid selected for the point has a
+    /// single `kT` knot or further axes (`A` or `alpha_s`).
+    pub fn xfx_tmd(&self, pid: impl Into<i32>, x: f64, q2: f64, kt: f64) -> Result<f64, Error> {
+        let point = Point::new(x, q2).kt(kt);
+        self.xfx_in_config(pid.into(), &point, InterpolationConfig::ThreeDKt)
+    }
+
+    /// Interpolates the PDF value (xf) at a point with an axis besides `x` and `Q2`, whose
+    /// scale is always `Q2`.
+    ///
+    /// The subgrid selected for the point, which is the one of [`GridArray::find_subgrid`],
+    /// must have the axes of `config`.
+    fn xfx_in_config(
+        &self,
+        pid: i32,
+        point: &Point,
+        config: InterpolationConfig,
+    ) -> Result<f64, Error> {
+        let points = point.to_ordered(config);
+        if let Some(subgrid) = self.grid_pdf.knot_array.find_subgrid(&points) {
+            let found = self.subgrid(subgrid).interpolation_config();
+            if found != config {
+                return Err(Error::AxisMismatch {
+                    subgrid,
+                    expected: config,
+                    found,
+                });
+            }
+        }
+
+        self.grid_pdf
+            .xfxq2_in_q2(pid, &points, self.extrapolation_policy())
+    }
+
+    /// Interpolates the PDF value (xf) for a given flavor, recording where it comes from.
+    ///
+    /// Abstraction to the `GridPDF::interpolate_traced` method.
+    ///
+    /// # Arguments
+    ///
+    /// * `pid` - The flavor ID, either as a PDG ID or as a [`crate::parton::Parton`].
+    /// * `points` - A slice containing the collection of points to interpolate on.
+    ///
+    /// # Returns
+    ///
+    /// The interpolated PDF value, as [`PDF::xfxq2`], and its `InterpTrace` with the subgrid,
+    /// the bracketing knots and the interpolator that produced it.
+    pub fn interpolate_traced(&self, pid: impl Into<i32>, points: &[f64]) -> (f64, InterpTrace) {
+        self.grid_pdf
+            .interpolate_traced(pid.into(), points)
+            .unwrap()
+    }
+
+    /// Interpolates the PDF value (xf) for a given flavor, overriding the extrapolation policy.
+    ///
+    /// Abstraction to the `GridPDF::xfxq2_with_policy` method.
+    ///
+    /// # Arguments
+    ///
+    /// * `pid` - The flavor ID (PDG ID).
+    /// * `points` - A slice containing the collection of points to interpolate on.
+    /// * `policy` - The `ExtrapolationPolicy` used for this query only.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the interpolated PDF value or an `Error`.
+    pub fn xfxq2_with_policy(
+        &self,
+        pid: i32,
+        points: &[f64],
+        policy: ExtrapolationPolicy,
+    ) -> Result<f64, Error> {
+        self.grid_pdf.xfxq2_with_policy(pid, points, policy)
+    }
+
+    /// Interpolates the PDF values (xf) of all the flavors for a given point.
+    ///
+    /// Abstraction to the `GridPDF::xfxq2_all` method.
+    ///
+    /// # Arguments
+    ///
+    /// * `points` - A slice containing the collection of points to interpolate on.
+    ///
+    /// # Returns
+    ///
+    /// The interpolated PDF values, ordered as [`PDF::flavor_order`].
+    pub fn xfxq2_all(&self, points: &[f64]) -> Vec<f64> {
+        self.grid_pdf.xfxq2_all(points).unwrap()
+    }
+
+    /// Returns the order of the flavors in the output of `PDF::xfxq2_all`.
+    ///
+    /// # Returns
+    ///
+    /// The flavor IDs, in the order of the `Flavors` entry of the metadata.
+    pub fn flavor_order(&self) -> &[i32] {
+        self.grid_pdf.flavor_order()
+    }
+
+    /// Interpolates the PDF value (xf) for a given flavor, x, and Q2 without allocating.
+    ///
+    /// Abstraction to the `GridPDF::eval_into` method.
+    ///
+    /// # Arguments
+    ///
+    /// * `pid` - The flavor ID (PDG ID).
+    /// * `x` - The momentum fraction.
+    /// * `q2` - The energy scale squared.
+    /// * `scratch` - A scratch buffer reused across calls.
+    ///
+    /// # Returns
+    ///
+    /// The interpolated PDF value `xf(flavor, x, Q^2)`.
+    pub fn eval_into(&self, pid: i32, x: f64, q2: f64, scratch: &mut InterpScratch) -> f64 {
+        self.grid_pdf.eval_into(pid, x, q2, scratch).unwrap()
+    }
+
+    /// Interpolates the PDF values (xf) of all the flavors for a given x and Q2 without
+    /// allocating.
+    ///
+    /// Abstraction to the `GridPDF::eval_all_into` method.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The momentum fraction.
+    /// * `q2` - The energy scale squared.
+    /// * `scratch` - A scratch buffer reused across calls.
+    ///
+    /// # Returns
+    ///
+    /// The interpolated PDF values, ordered as [`PDF::pids`].
+    pub fn eval_all_into<'a>(&self, x: f64, q2: f64, scratch: &'a mut InterpScratch) -> &'a [f64] {
+        self.grid_pdf.eval_all_into(x, q2, scratch).unwrap()
+    }
+
+    /// Interpolates the PDF value (xf) for multiple nucleons, alphas, flavors, xs, and Q2s.
+    ///
+    /// Abstraction to the `GridPDF::xfxq2s` method.
+    ///
+    /// # Arguments
+    ///
+    /// * `ids` - A vector of flavor IDs.
+    /// * `slice_points` - A slice containing the collection of knots to interpolate on.
+    ///   A knot is a collection of points containing `(nucleon, alphas, x, Q2)`.
+    ///
+    /// # Returns
+    ///
+    /// A 2D array of interpolated PDF values with shape `[flavors, N_knots]`.
+    pub fn xfxq2s(&self, pids: Vec<i32>, slice_points: &[&[f64]]) -> Array2<f64> {
+        self.grid_pdf.xfxq2s(pids, slice_points)
+    }
+
+    /// Interpolates the PDF value (xf) for a batch of paired `(x, Q2)` points.
+    ///
+    /// Abstraction to the `GridPDF::xfxq2_batch` method.
+    ///
+    /// # Arguments
+    ///
+    /// * `pid` - The flavor ID.
+    /// * `xs` - The momentum fractions `x`.
+    /// * `q2s` - The energy scales `Q2`, one per `x` value.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<f64>` of interpolated PDF values (empty for empty inputs), or an error if
+    /// `xs` and `q2s` do not have the same length.
+    pub fn xfxq2_batch(&self, pid: i32, xs: &[f64], q2s: &[f64]) -> Result<Vec<f64>, Error> {
+        self.grid_pdf.xfxq2_batch(pid, xs, q2s)
+    }
+
+    /// Returns the number of points of the last call to `PDF::xfxq2_batch` that were clamped
+    /// to the grid coverage.
+    ///
+    /// Abstraction to the `GridPDF::last_batch_clamped_count` method.
+    ///
+    /// # Returns
+    ///
+    /// The number of out-of-range points, which is only recorded with
+    /// `ExtrapolationPolicy::Clamp` and is zero otherwise.
+    pub fn last_batch_clamped_count(&self) -> usize {
+        self.grid_pdf.last_batch_clamped_count()
+    }
+
+    /// Interpolates the PDF value (xf) on the `(x, Q2)` mesh spanned by `xs` and `q2s`.
+    ///
+    /// Abstraction to the `GridPDF::evaluate_on_grid` method.
+    ///
+    /// # Arguments
+    ///
+    /// * `pid` - The flavor ID.
+    /// * `xs` - The momentum fractions `x` defining the rows of the mesh.
+    /// * `q2s` - The energy scales `Q2` defining the columns of the mesh.
+    ///
+    /// # Returns
+    ///
+    /// A 2D array of interpolated PDF values with shape `[xs.len(), q2s.len()]`.
+    pub fn evaluate_on_grid(
+        &self,
+        pid: i32,
+        xs: &[f64],
+        q2s: &[f64],
+    ) -> Result<Array2<f64>, Error> {
+        self.grid_pdf.evaluate_on_grid(pid, xs, q2s)
+    }
+
+    /// Estimates the interpolation error on the `(x, Q2)` mesh spanned by `xs` and `q2s`.
+    ///
+    /// Abstraction to the `GridPDF::interpolation_error_map` method. The result has the same
+    /// layout as `PDF::evaluate_on_grid`, such that both can be plotted side by side.
+    ///
+    /// # Arguments
+    ///
+    /// * `pid` - The flavor ID.
+    /// * `xs` - The momentum fractions `x` defining the rows of the mesh.
+    /// * `q2s` - The energy scales `Q2` defining the columns of the mesh.
+    ///
+    /// # Returns
+    ///
+    /// A 2D array of estimated errors with shape `[xs.len(), q2s.len()]`.
+    pub fn interpolation_error_map(
+        &self,
+        pid: i32,
+        xs: &[f64],
+        q2s: &[f64],
+    ) -> Result<Array2<f64>, Error> {
+        self.grid_pdf.interpolation_error_map(pid, xs, q2s)
+    }
+
+    /// Interpolates the PDF value (xf) on the `(x, Q2)` mesh, in parallel over the `x` values.
+    ///
+    /// Abstraction to the `GridPDF::par_evaluate_on_grid` method.
+    ///
+    /// # Arguments
+    ///
+    /// * `pid` - The flavor ID.
+    /// * `xs` - The momentum fractions `x` defining the rows of the mesh.
+    /// * `q2s` - The energy scales `Q2` defining the columns of the mesh.
+    ///
+    /// # Returns
+    ///
+    /// A 2D array of interpolated PDF values with shape `[xs.len(), q2s.len()]`.
+    #[cfg(feature = "rayon")]
+    pub fn par_evaluate_on_grid(
+        &self,
+        pid: i32,
+        xs: &[f64],
+        q2s: &[f64],
+    ) -> Result<Array2<f64>, Error> {
+        self.grid_pdf.par_evaluate_on_grid(pid, xs, q2s)
+    }
+
+    /// Interpolates the PDF value (xf) for multiple points using Chebyshev batch interpolation.
+    ///
+    /// Abstraction to the `GridPDF::xfxq2_cheby_batch` method.
+    ///
+    /// # Arguments
+    ///
+    /// * `pid` - The flavor ID.
+    /// * `points` - A slice containing the collection of knots to interpolate on.
+    ///   A knot is a collection of points containing `(nucleon, alphas, x, Q2)`.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<f64>` of interpolated PDF values.
+    pub fn xfxq2_cheby_batch(&self, pid: i32, points: &[&[f64]]) -> Vec<f64> {
+        self.grid_pdf.xfxq2_cheby_batch(pid, points).unwrap()
+    }
+
+    /// Computes the parton luminosity `L_ab(tau) = int dx/x f_a(x) f_b(tau/x)` of this PDF
+    /// with another one.
+    ///
+    /// The integral is computed in `ln(x)` over the range in which both `x` and `tau/x` are
+    /// covered by the grids of the respective PDFs, such that no extrapolation is involved.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The PDF providing the second parton, which can be `self`.
+    /// * `pid_a` - The flavor ID of the parton from this PDF.
+    /// * `pid_b` - The flavor ID of the parton from `other`.
+    /// * `tau` - The ratio between the partonic and hadronic center-of-mass energies squared.
+    /// * `q2` - The energy scale squared.
+    ///
+    /// # Returns
+    ///
+    /// The parton luminosity, which vanishes if the grids have no overlapping coverage.
+    pub fn parton_luminosity(&self, other: &PDF, pid_a: i32, pid_b: i32, tau: f64, q2: f64) -> f64 {
+        let x_a = self.param_ranges().x;
+        let x_b = other.param_ranges().x;
+        let x_min = x_a.min.max(tau / x_b.max);
+        let x_max = x_a.max.min(tau / x_b.min);
+        if x_min >= x_max {
+            return 0.0;
+        }
+
+        // With `y = ln(x)`, `f_a(x) f_b(tau/x) dx/x = xf_a(x) xf_b(tau/x) dy / tau`. The
+        // momentum fractions are clamped to undo the rounding of `exp(ln(x))` at the edges.
+        let integrand = |y: f64| {
+            let x = y.exp().clamp(x_min, x_max);
+            let x_other = (tau / x).clamp(x_b.min, x_b.max);
+            self.xfxq2_in_q2(pid_a, &[x, q2]) * other.xfxq2_in_q2(pid_b, &[x_other, q2])
+        };
+
+        simpson_integrate(integrand, x_min.ln(), x_max.ln(), LUMINOSITY_INTERVALS) / tau
+    }
+
+    /// Interpolates the strong coupling constant `alpha_s` for a given Q2.
+    ///
+    /// Abstraction to the `GridPDF::alphas_q2` method.
+    ///
+    /// # Arguments
+    ///
+    /// * `q2` - The squared energy scale.
+    ///
+    /// # Returns
+    ///
+    /// The interpolated `alpha_s` value.
+    pub fn alphas_q2(&self, q2: f64) -> f64 {
+        self.grid_pdf.alphas_q2(q2)
+    }
+
+    /// Interpolates the strong coupling constant `alpha_s` for a given Q.
+    ///
+    /// This mirrors `alphasQ` of LHAPDF, the scale `Q` being squared before calling
+    /// `PDF::alphas_q2`.
+    ///
+    /// # Arguments
+    ///
+    /// * `q` - The energy scale.
+    ///
+    /// # Returns
+    ///
+    /// The interpolated `alpha_s` value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `q` is negative or NaN.
+    pub fn alphas_q(&self, q: f64) -> f64 {
+        assert!(q >= 0.0, "The scale Q must be non-negative, got {q}");
+
+        self.alphas_q2(q * q)
+    }
+
+    /// Returns a reference to the PDF metadata.
+    ///
+    /// Abstraction to the `GridPDF::info` method.
+    ///
+    /// # Returns
+    ///
+    /// A `MetaData` struct containing information about the PDF set.
+    pub fn metadata(&self) -> &MetaData {
+        self.grid_pdf.metadata()
+    }
+
+    /// Returns a checksum of the metadata, independent of the grid values.
+    ///
+    /// Abstraction to the `MetaData::content_hash` method.
+    ///
+    /// # Returns
+    ///
+    /// The 64-bit hash of the metadata.
+    pub fn checksum_metadata(&self) -> u64 {
+        self.metadata().content_hash()
+    }
+
+    /// Returns the number of subgrids in the PDF set.
+    ///
+    /// # Returns
+    ///
+    /// The number of subgrids.
+    pub fn num_subgrids(&self) -> usize {
+        self.grid_pdf.knot_array.subgrids.len()
+    }
+
+    /// Returns a reference to the subgrid at the given index.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the subgrid.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the `SubGrid`.
+    pub fn subgrid(&self, index: usize) -> &SubGrid {
+        &self.grid_pdf.knot_array.subgrids[index]
+    }
+
+    /// Returns references to all the subgrid at the given index.
+    ///
+    /// # Returns
+    ///
+    /// A reference to all the `SubGrid`.
+    pub fn subgrids(&self) -> &Vec<SubGrid> {
+        &self.grid_pdf.knot_array.subgrids
+    }
+
+    /// Returns the flavor PIDS of the PDG Grid.
+    ///
+    /// # Returns
+    ///
+    /// PID representation of the PDF.
+    pub fn pids(&self) -> &Array1<i32> {
+        &self.grid_pdf.knot_array.pids
+    }
+
+    /// Retrieves the ranges for the parameters.
+    ///
+    /// Abstraction to the `GridPDF::param_ranges` method.
+    ///
+    /// # Returns
+    ///
+    /// The minimum and maximum values for the parameters (x, q2, ...).
+    pub fn param_ranges(&self) -> RangeParameters {
+        self.grid_pdf.param_ranges()
+    }
+
+    /// Summarizes the kinematic reach and content of the PDF.
+    ///
+    /// # Returns
+    ///
+    /// A `CoverageReport` gathering the parameter ranges, the number of subgrids and flavors,
+    /// and the interpolator.
+    pub fn coverage_report(&self) -> CoverageReport {
+        let ranges = self.param_ranges();
+
+        CoverageReport {
+            x_range: ranges.x,
+            q2_range: ranges.q2,
+            nucleons_range: ranges.nucleons,
+            alphas_range: ranges.alphas,
+            kt_range: ranges.kt,
+            num_subgrids: self.num_subgrids(),
+            num_flavors: self.pids().len(),
+            interpolator: self.metadata().interpolator_type.clone(),
+        }
+    }
+
+    /// Dumps the axes of all the subgrids as JSON, without the grid values.
+    ///
+    /// # Returns
+    ///
+    /// A JSON array with one object per subgrid, containing the `nucleons`, `alphas`, `kts`,
+    /// `xs`, and `q2s` knots.
+    pub fn dump_axes_json(&self) -> String {
+        /// The knots of a subgrid, in the layout of the JSON objects.
+        #[derive(Serialize)]
+        struct SubgridAxes<'a> {
+            nucleons: &'a [f64],
+            alphas: &'a [f64],
+            kts: &'a [f64],
+            xs: &'a [f64],
+            q2s: &'a [f64],
+        }
+
+        let subgrids: Vec<SubgridAxes> = self
+            .subgrids()
+            .iter()
+            .map(|subgrid| {
+                let knots = |axis| {
+                    subgrid
+                        .knots(axis)
+                        .as_slice()
+                        .expect("The knots are contiguous")
+                };
+                SubgridAxes {
+                    nucleons: knots(Axis::Nucleons),
+                    alphas: knots(Axis::AlphaS),
+                    kts: knots(Axis::Kt),
+                    xs: knots(Axis::X),
+                    q2s: knots(Axis::Q2),
+                }
+            })
+            .collect();
+
+        serde_json::to_string(&subgrids).expect("The knots are serializable")
+    }
+
+    /// Retrieves the PDF value (xf) stored at a node of a 2D grid, without interpolation.
+    ///
+    /// Abstraction to the `GridArray::node_value` method.
+    ///
+    /// # Arguments
+    ///
+    /// * `pid` - The flavor ID.
+    /// * `x_index` - The index along the `x` axis.
+    /// * `q2_index` - The index along the `Q2` axis, running over the subgrids in order.
+    ///
+    /// # Returns
+    ///
+    /// The PDF value at the node, or an error if the indices are out of bounds.
+    pub fn node_value(&self, pid: i32, x_index: usize, q2_index: usize) -> Result<f64, Error> {
+        self.grid_pdf.knot_array.node_value(pid, x_index, q2_index)
+    }
+
+    /// Iterates over the `Q2` knots of a 2D grid, yielding the values of all the flavors and
+    /// `x` knots at each of them.
+    ///
+    /// Abstraction to the `GridArray::q2_slices` method.
+    ///
+    /// # Returns
+    ///
+    /// An iterator over the pairs `(q2, values)`, where `values` has the shape `(nflav, nx)`,
+    /// or an error if the grid is not 2D.
+    pub fn q2_slices(&self) -> Result<impl Iterator<Item = (f64, Array2<f64>)> + '_, Error> {
+        self.grid_pdf.knot_array.q2_slices()
+    }
+
+    /// Lists the conventional partons that are not part of the PDF.
+    ///
+    /// A missing flavor silently evaluates to zero, see [`PDF::try_xfxq2`], so this allows
+    /// tooling to warn about sets that do not provide the full set of partons assumed by an
+    /// analysis.
+    ///
+    /// # Returns
+    ///
+    /// The PDG IDs of the gluon and of the (anti)quarks up to the bottom that are missing.
+    pub fn missing_standard_flavors(&self) -> Vec<i32> {
+        self.grid_pdf.knot_array.missing_standard_flavors()
+    }
+
+    /// Finds the index of the first subgrid whose `Q2` range contains a given value.
+    ///
+    /// Abstraction to the `GridPDF::find_subgrid_by_q2` method.
+    ///
+    /// # Arguments
+    ///
+    /// * `q2` - The energy scale squared.
+    ///
+    /// # Returns
+    ///
+    /// The index of the subgrid, or `None` if `q2` is outside of the grid.
+    pub fn find_subgrid_by_q2(&self, q2: f64) -> Option<usize> {
+        self.grid_pdf.find_subgrid_by_q2(q2)
+    }
+
+    /// Finds the index of the `x` knot closest to a given value.
+    ///
+    /// Abstraction to the `GridArray::nearest_x_index` method.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The momentum fraction.
+    ///
+    /// # Returns
+    ///
+    /// The index of the closest `x` knot, as used by `PDF::node_value`.
+    pub fn nearest_x_index(&self, x: f64) -> usize {
+        self.grid_pdf.knot_array.nearest_x_index(x)
+    }
+
+    /// Finds the index of the `Q2` knot closest to a given value.
+    ///
+    /// Abstraction to the `GridArray::nearest_q2_index` method.
+    ///
+    /// # Arguments
+    ///
+    /// * `q2` - The energy scale squared.
+    ///
+    /// # Returns
+    ///
+    /// The index of the closest `Q2` knot across the subgrids, as used by `PDF::node_value`.
+    pub fn nearest_q2_index(&self, q2: f64) -> usize {
+        self.grid_pdf.knot_array.nearest_q2_index(q2)
+    }
+
+    /// Retrieves the PDF value (xf) at a specific knot point in the grid.
+    ///
+    /// Abstraction to the `GridArray::xf_from_index` method. This method does not
+    /// perform any interpolation.
+    ///
+    /// # Arguments
+    ///
+    /// * `i_nucleons` - The index of the nucleon.
+    /// * `i_alphas` - The index of the alpha_s value.
+    /// * `i_kt` - The index of the `kT` value.
+    /// * `ix` - The index of the x-value.
+    /// * `iq2` - The index of the Q2-value.
+    /// * `id` - The flavor ID.
+    /// * `subgrid_id` - The ID of the subgrid.
+    ///
+    /// # Returns
+    ///
+    /// The PDF value at the specified knot.
+    #[allow(clippy::too_many_arguments)]
+    pub fn xf_from_index(
+        &self,
+        i_nucleons: usize,
+        i_alphas: usize,
+        i_kt: usize,
+        ix: usize,
+        iq2: usize,
+        id: i32,
+        subgrid_id: usize,
+    ) -> f64 {
+        self.grid_pdf
+            .knot_array
+            .xf_from_index(i_nucleons, i_alphas, i_kt, ix, iq2, id, subgrid_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metadata::{MetaDataV1, MetaDataV2, SetType};
+    use crate::parser::SubgridData;
+    use crate::test_support::synthetic_subgrid;
+
+    const PRECISION: f64 = 1e-14;
+
+    /// Builds a PDF with a single subgrid, whose values are `value(pid, alphas, kt, x, q2)`.
+    ///
+    /// The `alpha_s` and `kT` axes are only interpolated if they hold more than one knot.
+    fn pdf_from_fn(
+        alphas: &[f64],
+        kts: &[f64],
+        xs: &[f64],
+        q2s: &[f64],
+        pids: &[i32],
+        interpolator_type: InterpolatorType,
+        value: impl Fn(i32, f64, f64, f64, f64) -> f64,
+    ) -> PDF {
+        let grid_data = alphas
+            .iter()
+            .cartesian_product(kts)
+            .cartesian_product(xs)
+            .cartesian_product(q2s)
+            .cartesian_product(pids)
+            .map(|((((&a, &kt), &x), &q2), &pid)| value(pid, a, kt, x, q2))
+            .collect();
+        let subgrid_data = vec![SubgridData {
+            nucleons: vec![0.0],
+            alphas: alphas.to_vec(),
+            kts: kts.to_vec(),
+            xs: xs.to_vec(),
+            q2s: q2s.to_vec(),
+            grid_data,
+        }];
+        let info = MetaData::new_v1(MetaDataV1 {
+            num_members: 1,
+            flavors: pids.to_vec(),
+            interpolator_type,
+            ..Default::default()
+        });
+
+        PDF::from_parts(info, GridArray::new(subgrid_data, pids.to_vec()))
+    }
+
+    fn test_pdf() -> PDF {
+        let xs = [1e-3, 1e-2, 1e-1, 1.0];
+        let q2s = [1.0, 10.0, 100.0, 1000.0];
+        pdf_from_fn(
+            &[0.0],
+            &[0.0],
+            &xs,
+            &q2s,
+            &[21, 1, 2],
+            InterpolatorType::LogBicubic,
+            |pid, _, _, x, q2| match pid {
+                21 => x * (1.0 + q2.ln()),
+                1 => x * x,
+                _ => x * (1.0 - x),
+            },
+        )
+    }
+
+    #[test]
+    #[cfg(any(feature = "deterministic", not(target_feature = "fma")))]
+    fn test_golden_values() {
+        // Without fused multiply-adds, the interpolation only uses explicit multiplications and
+        // additions, which are never contracted, such that the results are bit-reproducible.
+        let pdf = test_pdf();
+        let golden = [
+            (21, 0x3f79afaa303156ad),
+            (1, 0xbfa6370824d484db),
+            (2, 0x3fa6e94b95ca9237),
+        ];
+        for (pid, bits) in golden {
+            assert_eq!(pdf.xfxq2(pid, &[2.5e-2, 37.0]).to_bits(), bits);
+        }
+    }
+
+    #[test]
+    fn test_xfxq2_parton() {
+        use crate::parton::Parton;
+
+        let pdf = test_pdf();
+        let point = [2e-2, 5.0];
+        assert_eq!(pdf.xfxq2(Parton::Gluon, &point), pdf.xfxq2(21, &point));
+        assert_eq!(pdf.xfxq2(Parton::Up, &point), pdf.xfxq2(2, &point));
+    }
+
+    #[test]
+    fn test_missing_standard_flavors() {
+        assert_eq!(
+            test_pdf().missing_standard_flavors(),
+            vec![-5, -4, -3, -2, -1, 3, 4, 5]
+        );
+
+        let pids = vec![-4, -3, -2, -1, 0, 1, 2, 3, 4];
+        let subgrid = synthetic_subgrid(InterpolationConfig::TwoD, pids.len(), 0);
+        let info = MetaData::new_v1(MetaDataV1 {
+            flavors: pids.clone(),
+            interpolator_type: InterpolatorType::LogBilinear,
+            ..Default::default()
+        });
+        let grid = GridArray {
+            pids: Array1::from_vec(pids),
+            subgrids: vec![subgrid],
+        };
+        let pdf = PDF::from_parts(info, grid);
+        assert_eq!(pdf.missing_standard_flavors(), vec![-5, 5]);
+        assert_eq!(pdf.xfxq2(5, &[1e-2, 10.0]), 0.0);
+    }
+
+    #[test]
+    fn test_parton_luminosity() {
+        // With `xf(x) = -ln(x)`, which the log-bilinear interpolation reproduces exactly,
+        // `L(tau) = ln(1/tau)^3 / (6 tau)`.
+        let pdf = pdf_from_fn(
+            &[0.0],
+            &[0.0],
+            &[1e-5, 1e-4, 1e-3, 1e-2, 1e-1, 1.0],
+            &[1.0, 1000.0],
+            &[21],
+            InterpolatorType::LogBilinear,
+            |_, _, _, x, _| -x.ln(),
+        );
+
+        let tau: f64 = 1e-3;
+        let expected = (-tau.ln()).powi(3) / (6.0 * tau);
+        let lumi = pdf.parton_luminosity(&pdf, 21, 21, tau, 10.0);
+        assert!((lumi / expected - 1.0).abs() < 1e-10);
+
+        // Only `x <= 1e-2` keeps `tau/x` within the coverage of `test_pdf`.
+        let mixed = pdf.parton_luminosity(&test_pdf(), 21, 21, 1e-5, 10.0);
+        assert!(mixed.is_finite() && mixed > 0.0);
+        assert_eq!(pdf.parton_luminosity(&test_pdf(), 21, 21, 1e-9, 10.0), 0.0);
+    }
+
+    #[test]
+    fn test_scale_flavor() {
+        let pdf = test_pdf();
+        let scaled = pdf.scale_flavor(21, 2.0).unwrap();
+
+        for point in [[1e-3, 1.0], [2e-2, 5.0], [0.5, 500.0]] {
+            let gluon = pdf.xfxq2(21, &point);
+            assert!((scaled.xfxq2(21, &point) - 2.0 * gluon).abs() < PRECISION);
+            assert_eq!(scaled.xfxq2(1, &point), pdf.xfxq2(1, &point));
+            assert_eq!(scaled.xfxq2(2, &point), pdf.xfxq2(2, &point));
+        }
+
+        assert!(matches!(
+            pdf.scale_flavor(3, 1.1),
+            Err(Error::FlavorNotFound { pid: 3 })
+        ));
+    }
+
+    #[test]
+    fn test_extrapolation_policy() {
+        let mut pdf = test_pdf();
+        let below = [1e-5, 1.0];
+        let above = [0.5, 1e6];
+
+        assert_eq!(pdf.extrapolation_policy(), ExtrapolationPolicy::Clamp);
+        assert_eq!(pdf.xfxq2(21, &below), pdf.xfxq2(21, &[1e-3, 1.0]));
+        assert_eq!(pdf.try_xfxq2(3, &[1e-2, 10.0]).unwrap(), 0.0);
+
+        pdf.set_extrapolation_policy(ExtrapolationPolicy::Error);
+        assert!(matches!(
+            pdf.try_xfxq2(21, &below),
+            Err(Error::SubgridNotFound { .. })
+        ));
+
+        pdf.set_extrapolation_policy(ExtrapolationPolicy::Clamp);
+        assert_eq!(pdf.xfxq2(21, &above), pdf.xfxq2(21, &[0.5, 1000.0]));
+
+        // The per-call override takes precedence over the instance policy
+        assert!(pdf
+            .xfxq2_with_policy(21, &above, ExtrapolationPolicy::Error)
+            .is_err());
+        assert!(pdf
+            .scale_flavor(1, 2.0)
+            .unwrap()
+            .xfxq2(21, &below)
+            .is_finite());
+    }
+
+    #[test]
+    fn test_extrapolation_policies_below_x_min() {
+        let mut pdf = test_pdf();
+        let below = [5e-4, 10.0];
+        let (f0, f1) = (pdf.xfxq2(21, &[1e-3, 10.0]), pdf.xfxq2(21, &[1e-2, 10.0]));
+
+        let mut evaluate = |policy| {
+            pdf.set_extrapolation_policy(policy);
+            pdf.grid_pdf.xfxq2(21, &below)
+        };
+        assert!(matches!(
+            evaluate(ExtrapolationPolicy::Error),
+            Err(Error::SubgridNotFound { .. })
+        ));
+        assert_eq!(evaluate(ExtrapolationPolicy::Clamp).unwrap(), f0);
+        assert!(evaluate(ExtrapolationPolicy::Nan).unwrap().is_nan());
+        assert_eq!(evaluate(ExtrapolationPolicy::Constant(-1.0)).unwrap(), -1.0);
+
+        let expected = f0 + (f1 - f0) * 0.5f64.ln() / 10.0f64.ln();
+        let extrapolated = evaluate(ExtrapolationPolicy::LogLinear).unwrap();
+        assert!((extrapolated - expected).abs() < 1e-15);
+        assert!(extrapolated < f0);
+
+        // Inside the grid, the policies do not change the result
+        pdf.set_extrapolation_policy(ExtrapolationPolicy::LogLinear);
+        assert_eq!(pdf.xfxq2(21, &[1e-3, 10.0]), f0);
+    }
+
+    #[test]
+    fn test_timelike_low_x_extrapolation() {
+        let spacelike = test_pdf();
+        let mut info = spacelike.metadata().clone();
+        info.set_type = SetType::TimeLike;
+        let mut timelike = PDF::from_parts(info, spacelike.grid_pdf.knot_array.clone());
+        assert!(spacelike.low_x_extrapolation());
+        assert!(!timelike.low_x_extrapolation());
+
+        let (below, inside) = ([1e-5, 10.0], [1e-2, 10.0]);
+        let policy = ExtrapolationPolicy::LogLinear;
+        let extrapolated = spacelike.xfxq2_with_policy(21, &below, policy).unwrap();
+        assert!(extrapolated.is_finite());
+        assert!(matches!(
+            timelike.xfxq2_with_policy(21, &below, policy),
+            Err(Error::SubgridNotFound { .. })
+        ));
+
+        // Inside the grid and with the other policies, the set type does not matter
+        assert_eq!(
+            timelike.xfxq2_with_policy(21, &inside, policy).unwrap(),
+            spacelike.xfxq2(21, &inside)
+        );
+        let clamp = ExtrapolationPolicy::Clamp;
+        assert_eq!(
+            timelike.xfxq2_with_policy(21, &below, clamp).unwrap(),
+            spacelike.xfxq2_with_policy(21, &below, clamp).unwrap()
+        );
+
+        timelike.set_low_x_extrapolation(true);
+        assert_eq!(
+            timelike.xfxq2_with_policy(21, &below, policy).unwrap(),
+            extrapolated
+        );
+    }
+
+    #[test]
+    fn test_gluon_declared_as_zero() {
+        let reference = test_pdf();
+        let mut info = reference.metadata().clone();
+        info.flavors = vec![0, 1, 2];
+        let mut knot_array = reference.grid_pdf.knot_array.clone();
+        knot_array.pids = Array1::from_vec(vec![0, 1, 2]);
+        let pdf = PDF::from_parts(info, knot_array);
+
+        let point = [0.05, 50.0];
+        let gluon = reference.xfxq2(21, &point);
+        assert!(gluon > 0.0);
+        assert_eq!(pdf.xfxq2(21, &point), gluon);
+        assert_eq!(pdf.xfxq2(0, &point), gluon);
+        assert_eq!(pdf.xfxq2(crate::parton::Parton::Gluon, &point), gluon);
+        assert!(pdf.missing_standard_flavors().iter().all(|&pid| pid != 21));
+    }
+
+    #[test]
+    fn test_last_batch_clamped_count() {
+        let mut pdf = test_pdf();
+        let xs = [1e-5, 1e-2, 0.5, 0.1];
+        let q2s = [10.0, 10.0, 1e6, 0.5];
+
+        pdf.set_extrapolation_policy(ExtrapolationPolicy::Clamp);
+        pdf.xfxq2_batch(21, &xs, &q2s).unwrap();
+        assert_eq!(pdf.last_batch_clamped_count(), 3);
+        pdf.xfxq2_batch(21, &xs[1..2], &q2s[1..2]).unwrap();
+        assert_eq!(pdf.last_batch_clamped_count(), 0);
+
+        pdf.set_extrapolation_policy(ExtrapolationPolicy::Nan);
+        pdf.xfxq2_batch(21, &xs, &q2s).unwrap();
+        assert_eq!(pdf.last_batch_clamped_count(), 0);
+    }
+
+    #[test]
+    fn test_extrapolation_policy_nan() {
+        let mut pdf = test_pdf();
+        pdf.set_extrapolation_policy(ExtrapolationPolicy::Nan);
+
+        let xs = [1e-2, 1e-5, 0.5, 0.5, 1.0];
+        let q2s = [10.0, 10.0, 1e6, 500.0, 1000.0];
+        let results = pdf.xfxq2_batch(21, &xs, &q2s).unwrap();
+
+        let nan_positions: Vec<usize> = (0..results.len())
+            .filter(|&idx| results[idx].is_nan())
+            .collect();
+        assert_eq!(nan_positions, vec![1, 2]);
+        assert_eq!(results[0], pdf.xfxq2(21, &[1e-2, 10.0]));
+    }
+
+    #[cfg(feature = "hdf5")]
+    #[test]
+    fn test_from_hdf5() {
+        use hdf5::types::VarLenUnicode;
+
+        let pdf = test_pdf();
+        let subgrid = pdf.subgrid(0);
+        let values = subgrid
+            .grid
+            .view()
+            .permuted_axes([0, 1, 3, 4, 5, 2])
+            .as_standard_layout()
+            .into_owned();
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("twin.h5");
+        {
+            let file = hdf5::File::create(&path).unwrap();
+            let group = file.create_group("member_0").unwrap();
+            for (name, data) in [("xs", &subgrid.xs), ("q2s", &subgrid.q2s)] {
+                group
+                    .new_dataset_builder()
+                    .with_data(data)
+                    .create(name)
+                    .unwrap();
+            }
+            group
+                .new_dataset_builder()
+                .with_data(pdf.pids())
+                .create("pids")
+                .unwrap();
+            group
+                .new_dataset_builder()
+                .with_data(&values)
+                .create("values")
+                .unwrap();
+            group
+                .new_attr::<VarLenUnicode>()
+                .create("InterpolatorType")
+                .unwrap()
+                .write_scalar(&"LogBicubic".parse::<VarLenUnicode>().unwrap())
+                .unwrap();
+            group
+                .new_attr::<f64>()
+                .create("AlphaS_MZ")
+                .unwrap()
+                .write_scalar(&0.118)
+                .unwrap();
+        }
+
+        let loaded = PDF::from_hdf5(&path, "member_0").unwrap();
+        assert_eq!(loaded.metadata().alphas_mz(), Some(0.118));
+        assert_eq!(loaded.metadata().flavors, vec![21, 1, 2]);
+
+        for point in [[1e-3, 1.0], [2e-2, 5.0], [0.5, 500.0]] {
+            for pid in [21, 1, 2] {
+                assert_eq!(loaded.xfxq2(pid, &point), pdf.xfxq2(pid, &point));
+            }
+        }
+
+        assert!(PDF::from_hdf5(&path, "member_1").is_err());
+    }
+
+    #[test]
+    fn test_interpolation_error_map() {
+        let pdf = test_pdf();
+        let knots_x = pdf.subgrid(0).xs.to_vec();
+        let knots_q2 = pdf.subgrid(0).q2s.to_vec();
+
+        let at_knots = pdf
+            .interpolation_error_map(21, &knots_x, &knots_q2)
+            .unwrap();
+        assert_eq!(at_knots.dim(), (4, 4));
+        assert!(at_knots.iter().all(|&error| error < PRECISION));
+
+        let between = pdf
+            .interpolation_error_map(21, &[3e-3, 3e-2, 0.3], &[3.0, 30.0, 300.0])
+            .unwrap();
+        assert!(between.iter().all(|&error| error > 1e3 * PRECISION));
+    }
+
+    #[test]
+    fn test_nearest_knot_indices() {
+        let pdf = test_pdf();
+
+        // xs = [1e-3, 1e-2, 1e-1, 1], q2s = [1, 10, 100, 1000]
+        assert_eq!(pdf.nearest_x_index(2e-2), 1);
+        assert_eq!(pdf.nearest_x_index(8e-2), 2);
+        assert_eq!(pdf.nearest_q2_index(40.0), 1);
+        assert_eq!(pdf.nearest_q2_index(60.0), 2);
+
+        for (idx, &x) in pdf.subgrid(0).xs.iter().enumerate() {
+            assert_eq!(pdf.nearest_x_index(x), idx);
+        }
+        for (idx, &q2) in pdf.subgrid(0).q2s.iter().enumerate() {
+            assert_eq!(pdf.nearest_q2_index(q2), idx);
+        }
+    }
+
+    #[test]
+    fn test_xfxq2_all_order() {
+        let pdf = test_pdf();
+        let mut info = pdf.metadata().clone();
+        info.flavors = vec![2, 21, 1];
+        let reordered = PDF::from_parts(info, pdf.grid_pdf.knot_array.clone());
+        assert_eq!(reordered.flavor_order(), &[2, 21, 1]);
+
+        for point in [[1e-3, 1.0], [2e-2, 5.0], [0.5, 500.0]] {
+            let values = reordered.xfxq2_all(&point);
+            assert_eq!(values.len(), 3);
+            for (idx, &pid) in reordered.flavor_order().iter().enumerate() {
+                assert_eq!(values[idx], pdf.xfxq2(pid, &point));
+            }
+        }
+    }
+
+    #[test]
+    fn test_query_scale() {
+        let pdf = test_pdf();
+        let pdf_q = test_pdf().with_query_scale(ScaleUnit::Q);
+        assert_eq!(pdf.query_scale(), ScaleUnit::Q2);
+        assert_eq!(pdf_q.query_scale(), ScaleUnit::Q);
+
+        for (x, q) in [(1e-3, 1.0), (2e-2, 5.0), (0.5, 30.0)] {
+            for pid in [21, 1, 2] {
+                assert_eq!(pdf_q.xfxq2(pid, &[x, q]), pdf.xfxq2(pid, &[x, q * q]));
+            }
+        }
+        assert!(pdf_q
+            .xfxq2_with_policy(21, &[0.1, 100.0], ExtrapolationPolicy::Error)
+            .is_err());
+
+        // The methods taking an explicit `q2` do not follow the query scale
+        let (xs, q2s) = ([1e-3, 2e-2, 0.5], [1.0, 25.0, 900.0]);
+        assert_eq!(
+            pdf_q.xfxq2_batch(21, &xs, &q2s).unwrap(),
+            pdf.xfxq2_batch(21, &xs, &q2s).unwrap()
+        );
+        assert_eq!(pdf_q.last_batch_clamped_count(), 0);
+        assert_eq!(
+            pdf_q.evaluate_on_grid(2, &xs, &q2s).unwrap(),
+            pdf.evaluate_on_grid(2, &xs, &q2s).unwrap()
+        );
+        assert_eq!(
+            pdf_q.parton_luminosity(&pdf_q, 21, 2, 1e-2, 50.0),
+            pdf.parton_luminosity(&pdf, 21, 2, 1e-2, 50.0)
+        );
+    }
+
+    #[test]
+    fn test_xfxq_and_alphas_q() {
+        let pdf = test_pdf();
+        let pdf_q = test_pdf().with_query_scale(ScaleUnit::Q);
+
+        for (x, q) in [(1e-3, 1.0), (2e-2, 5.0), (0.5, 30.0)] {
+            for pid in [21, 1, 2] {
+                assert_eq!(pdf.xfxq(pid, x, q), pdf.xfxq2(pid, &[x, q * q]));
+                assert_eq!(pdf_q.xfxq(pid, x, q), pdf.xfxq(pid, x, q));
+            }
+            assert_eq!(pdf.alphas_q(q), pdf.alphas_q2(q * q));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "The scale Q must be non-negative")]
+    fn test_xfxq_negative_q() {
+        test_pdf().xfxq(21, 0.1, -2.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "The scale Q must be non-negative")]
+    fn test_alphas_q_negative_q() {
+        test_pdf().alphas_q(-2.0);
+    }
+
+    #[test]
+    fn test_interpolator_overrides() {
+        let mut pdf = test_pdf();
+        let bilinear = pdf_with_interpolator(
+            pdf.metadata().clone(),
+            pdf.grid_pdf.knot_array.clone(),
+            InterpolatorType::LogBilinear,
+        )
+        .unwrap();
+        let points = [[2e-3, 3.0], [0.05, 50.0], [0.7, 500.0]];
+        let bicubic: Vec<f64> = points.iter().map(|p| pdf.xfxq2(2, p)).collect();
+
+        pdf.set_interpolator_overrides(HashMap::from([(21, InterpolatorType::LogBilinear)]))
+            .unwrap();
+        for (point, &expected) in points.iter().zip(&bicubic) {
+            let (value, trace) = pdf.interpolate_traced(21, point);
+            assert_eq!(trace.interpolator, InterpolatorType::LogBilinear);
+            assert_eq!(value, bilinear.xfxq2(21, point));
+
+            let (value, trace) = pdf.interpolate_traced(2, point);
+            assert_eq!(trace.interpolator, InterpolatorType::LogBicubic);
+            assert_eq!(value, expected);
+        }
+        assert_ne!(pdf.xfxq2(21, &points[0]), test_pdf().xfxq2(21, &points[0]));
+
+        // The overrides are carried over to the derived PDFs.
+        let scaled = pdf.scale_flavor(1, 2.0).unwrap();
+        assert_eq!(
+            scaled.interpolator_overrides(),
+            pdf.interpolator_overrides()
+        );
+        assert_eq!(scaled.xfxq2(21, &points[1]), bilinear.xfxq2(21, &points[1]));
+
+        assert!(matches!(
+            pdf.set_interpolator_overrides(HashMap::from([(2, InterpolatorType::LogTricubic)])),
+            Err(Error::IncompatibleInterpolator(
+                InterpolatorType::LogTricubic
+            ))
+        ));
+        assert_eq!(pdf.interpolator_overrides().len(), 1);
+
+        // The linear interpolators are evaluated on linear coordinates.
+        let linear = pdf_with_interpolator(
+            pdf.metadata().clone(),
+            pdf.grid_pdf.knot_array.clone(),
+            InterpolatorType::Bilinear,
+        )
+        .unwrap();
+        pdf.set_interpolator_overrides(HashMap::from([(21, InterpolatorType::Bilinear)]))
+            .unwrap();
+        assert_eq!(pdf.xfxq2(21, &points[1]), linear.xfxq2(21, &points[1]));
+        assert_eq!(pdf.xfxq2(2, &points[1]), bicubic[1]);
+
+        pdf.set_interpolator_overrides(HashMap::new()).unwrap();
+        let (value, trace) = pdf.interpolate_traced(21, &points[0]);
+        assert_eq!(trace.interpolator, InterpolatorType::LogBicubic);
+        assert_eq!(value, test_pdf().xfxq2(21, &points[0]));
+    }
+
+    #[test]
+    fn test_xfx_at_alphas() {
+        let alphas = vec![0.110, 0.115, 0.120, 0.125];
+        let xs = vec![1e-3, 1e-2, 1e-1, 0.5, 1.0];
+        let q2s = vec![1.0, 10.0, 100.0, 1000.0];
+        let value = |pid: i32, a: f64, x: f64, q2: f64| match pid {
+            21 => x * (1.0 - x) * (1.0 + q2.ln()) * (1.0 + 10.0 * a),
+            _ => x * x * (2.0 - 5.0 * a),
+        };
+        let pdf = pdf_from_fn(
+            &alphas,
+            &[0.0],
+            &xs,
+            &q2s,
+            &[21, 2],
+            InterpolatorType::LogTricubic,
+            |pid, a, _, x, q2| value(pid, a, x, q2),
+        );
+
+        // The interpolation is cubic in `ln(alphas)`: exact at the knots, and close to the
+        // linear dependence in between.
+        for (&a, tolerance) in [0.110, 0.1118, 0.115, 0.118, 0.1234, 0.125]
+            .iter()
+            .zip([1e-14, 1e-3, 1e-14, 1e-3, 1e-3, 1e-14])
+        {
+            for &x in &xs[1..4] {
+                for &q2 in &q2s[1..3] {
+                    for pid in [21, 2] {
+                        let expected = value(pid, a, x, q2);
+                        let result = pdf.xfx_at_alphas(pid, x, q2, a).unwrap();
+                        assert!((result - expected).abs() < tolerance * expected.abs());
+                    }
+                }
+            }
+        }
+
+        // The explicit `q2` does not follow the query scale
+        let expected = pdf.xfx_at_alphas(21, 0.1, 100.0, 0.118).unwrap();
+        let pdf_q = pdf.with_query_scale(ScaleUnit::Q);
+        assert_eq!(
+            pdf_q.xfx_at_alphas(21, 0.1, 100.0, 0.118).unwrap(),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_xfx_at_alphas_single_knot() {
+        assert!(matches!(
+            test_pdf().xfx_at_alphas(21, 0.1, 10.0, 0.118),
+            Err(Error::AxisMismatch {
+                subgrid: 0,
+                expected: InterpolationConfig::ThreeDAlphas,
+                found: InterpolationConfig::TwoD,
+            })
+        ));
+    }
+
+    #[test]
+    fn test_xfx_tmd() {
+        // The values are linear in `ln(kT)`, which two Chebyshev knots reproduce exactly.
+        let kts = vec![1.0, 4.0];
+        let xs = vec![1e-3, 1e-2, 1e-1];
+        let q2s = vec![1.0, 10.0, 100.0];
+        let value = |pid: i32, kt: f64, x: f64, q2: f64| match pid {
+            21 => x * (1.0 - x) * (1.0 + q2.ln()) * (2.0 - 0.5 * f64::ln(kt)),
+            _ => x * x * (1.0 + f64::ln(kt)),
+        };
+        let pdf = pdf_from_fn(
+            &[0.0],
+            &kts,
+            &xs,
+            &q2s,
+            &[21, 2],
+            InterpolatorType::LogChebyshev,
+            |pid, _, kt, x, q2| value(pid, kt, x, q2),
+        );
+
+        // With two knots, the interpolation is linear in `ln(kT)`, like the values above.
+        for kt in [1.0, 1.5, 2.0, 3.0, 4.0] {
+            for &x in &xs {
+                for &q2 in &q2s {
+                    for pid in [21, 2] {
+                        let expected = value(pid, kt, x, q2);
+                        let result = pdf.xfx_tmd(pid, x, q2, kt).unwrap();
+                        assert!((result - expected).abs() < 1e-12 * expected.abs());
+                    }
+                }
+            }
+        }
+
+        // The explicit `q2` does not follow the query scale
+        let expected = pdf.xfx_tmd(21, 0.1, 10.0, 2.0).unwrap();
+        let pdf_q = pdf.with_query_scale(ScaleUnit::Q);
+        assert_eq!(pdf_q.xfx_tmd(21, 0.1, 10.0, 2.0).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_xfx_tmd_single_knot() {
+        assert!(matches!(
+            test_pdf().xfx_tmd(21, 0.1, 10.0, 1.0),
+            Err(Error::AxisMismatch {
+                subgrid: 0,
+                expected: InterpolationConfig::ThreeDKt,
+                found: InterpolationConfig::TwoD,
+            })
+        ));
+
+        // The axes are checked on the subgrid selected for the point
+        let (xs, q2s) = ([1e-3, 1e-2, 1e-1], [1.0, 10.0, 100.0]);
+        let tmd = pdf_from_fn(
+            &[0.0],
+            &[1.0, 4.0],
+            &xs,
+            &q2s,
+            &[21],
+            InterpolatorType::LogChebyshev,
+            |_, _, kt, x, _| x * kt,
+        );
+        let collinear = pdf_from_fn(
+            &[0.0],
+            &[0.0],
+            &xs,
+            &[100.0, 1000.0, 10000.0],
+            &[21],
+            InterpolatorType::LogChebyshev,
+            |_, _, _, x, _| x,
+        );
+        let mut grid = tmd.grid_pdf.knot_array.clone();
+        grid.subgrids.push(collinear.subgrid(0).clone());
+        let mixed = PDF::from_parts(tmd.metadata().clone(), grid);
+
+        assert_eq!(
+            mixed.xfx_tmd(21, 0.1, 10.0, 2.0).unwrap(),
+            tmd.xfx_tmd(21, 0.1, 10.0, 2.0).unwrap()
+        );
+        assert!(matches!(
+            mixed.xfx_tmd(21, 0.1, 1000.0, 2.0),
+            Err(Error::AxisMismatch { subgrid: 1, .. })
+        ));
+    }
+
+    #[test]
+    fn test_interpolation_config_roundtrip() {
+        use crate::metadata::InterpolationConfigV2;
+        use crate::writer::{GridArrayCollection, GridArrayReader};
+
+        let pdf = test_pdf();
+        assert_eq!(pdf.extrapolation_policy(), ExtrapolationPolicy::Clamp);
+        assert!(matches!(pdf.is_force_positive(), ForcePositive::NoClipping));
+
+        let mut info = pdf.metadata().as_latest();
+        info.interpolation_config = Some(InterpolationConfigV2 {
+            extrapolation_policy: ExtrapolationPolicy::Constant(-1.0),
+            force_positive: Some(ForcePositive::ClipNegative),
+        });
+        let file = tempfile::NamedTempFile::new().unwrap();
+        GridArrayCollection::compress(
+            &[&pdf.grid_pdf.knot_array],
+            &MetaData::new_v2(info),
+            file.path(),
+        )
+        .unwrap();
+
+        let reader = GridArrayReader::from_file(file.path()).unwrap();
+        let member = reader.load_grid(0).unwrap();
+        let loaded = PDF::from_parts((*member.metadata).clone(), member.grid);
+        assert_eq!(
+            loaded.extrapolation_policy(),
+            ExtrapolationPolicy::Constant(-1.0)
+        );
+        assert!(matches!(
+            loaded.is_force_positive(),
+            ForcePositive::ClipNegative
+        ));
+        assert_eq!(loaded.xfxq2(21, &[1e-5, 1.0]), -1.0);
+    }
+
+    #[test]
+    fn test_dump_axes_json() {
+        let json = test_pdf().dump_axes_json();
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        let subgrids = parsed.as_array().unwrap();
+        assert_eq!(subgrids.len(), 1);
+        let xs: Vec<f64> = serde_json::from_value(subgrids[0]["xs"].clone()).unwrap();
+        assert_eq!(xs, vec![1e-3, 1e-2, 1e-1, 1.0]);
+        let q2s: Vec<f64> = serde_json::from_value(subgrids[0]["q2s"].clone()).unwrap();
+        assert_eq!(q2s, vec![1.0, 10.0, 100.0, 1000.0]);
+        assert!(json.starts_with("[{\"nucleons\":[0.0]"));
+    }
+
+    #[test]
+    fn test_node_value() {
+        let pdf = test_pdf();
+        let subgrid = pdf.subgrid(0);
+
+        for (ix, &x) in subgrid.xs.iter().enumerate() {
+            for (iq2, &q2) in subgrid.q2s.iter().enumerate() {
+                for pid in [21, 1, 2] {
+                    let node = pdf.node_value(pid, ix, iq2).unwrap();
+                    assert!((node - pdf.xfxq2(pid, &[x, q2])).abs() < PRECISION);
+                }
+            }
+        }
+
+        assert!(matches!(
+            pdf.node_value(21, 4, 0),
+            Err(Error::NodeIndexOutOfBounds {
+                x_index: 4,
+                q2_index: 0
+            })
+        ));
+        assert!(pdf.node_value(21, 0, 4).is_err());
+        assert!(pdf.node_value(3, 0, 0).is_err());
+    }
+
+    #[test]
+    fn test_coverage_report() {
+        let report = test_pdf().coverage_report();
+
+        assert_eq!((report.x_range.min, report.x_range.max), (1e-3, 1.0));
+        assert_eq!((report.q2_range.min, report.q2_range.max), (1.0, 1000.0));
+        assert_eq!(
+            (report.nucleons_range.min, report.nucleons_range.max),
+            (0.0, 0.0)
+        );
+        assert_eq!(
+            (report.alphas_range.min, report.alphas_range.max),
+            (0.0, 0.0)
+        );
+        assert_eq!((report.kt_range.min, report.kt_range.max), (0.0, 0.0));
+        assert_eq!(report.num_subgrids, 1);
+        assert_eq!(report.num_flavors, 3);
+        assert!(matches!(report.interpolator, InterpolatorType::LogBicubic));
+    }
+
+    #[test]
+    fn test_resample_to_set() {
+        let pdf = test_pdf();
+        let reference = pdf_from_fn(
+            &[0.0],
+            &[0.0],
+            &[1e-2, 5e-2, 0.2, 0.8],
+            &[2.0, 20.0, 200.0, 900.0],
+            &[21, 1, 2],
+            InterpolatorType::LogBicubic,
+            |_, _, _, _, _| 1.0,
+        );
+
+        let resampled = pdf
+            .resample_to_set(&reference, InterpolatorType::LogBicubic)
+            .unwrap();
+        let (new, target) = (resampled.subgrid(0), reference.subgrid(0));
+        assert_eq!(new.xs, target.xs);
+        assert_eq!(new.q2s, target.q2s);
+        for (x_idx, &x) in target.xs.iter().enumerate() {
+            for (q2_idx, &q2) in target.q2s.iter().enumerate() {
+                assert_eq!(
+                    resampled.node_value(2, x_idx, q2_idx).unwrap(),
+                    pdf.xfxq2(2, &[x, q2])
+                );
+            }
+        }
+        assert!(resampled.morph(&reference, 0.5).is_ok());
+
+        assert!(matches!(
+            reference.resample_to_set(&pdf, InterpolatorType::LogBicubic),
+            Err(Error::IncompatibleGrids(_))
+        ));
+    }
+
+    #[test]
+    fn test_metadata_patch() {
+        let pdf = test_pdf();
+        let mut info = pdf.metadata().clone();
+        info.set_desc = "Updated set".to_string();
+        info.q_max = 1e4;
+        let updated = PDF::from_parts(info, pdf.grid_pdf.knot_array.clone());
+
+        let patch = updated.metadata_patch_against(&pdf).unwrap();
+        assert_eq!(
+            patch.set.keys().collect::<Vec<_>>(),
+            vec!["QMax", "SetDesc"]
+        );
+        assert!(patch.remove.is_empty());
+
+        let yaml = serde_yaml::to_string(&patch).unwrap();
+        let patch: MetaDataPatch = serde_yaml::from_str(&yaml).unwrap();
+        let patched = pdf.apply_metadata_patch(&patch).unwrap();
+        assert_eq!(patched.metadata().set_desc, "Updated set");
+        assert_eq!(patched.metadata().q_max, 1e4);
+        assert!(updated.metadata_patch_against(&patched).unwrap().is_empty());
+
+        let rescaled = pdf.scale_flavor(21, 2.0).unwrap();
+        assert!(matches!(
+            rescaled.metadata_patch_against(&pdf),
+            Err(Error::IncompatibleGrids(_))
+        ));
+    }
+
+    #[test]
+    fn test_trim_precision() {
+        let pdf = test_pdf();
+        let trimmed = pdf.trim_precision(3);
+
+        let original = &pdf.subgrid(0).grid;
+        for (value, trimmed) in original.iter().zip(trimmed.subgrid(0).grid.iter()) {
+            let repr = format!("{trimmed:e}");
+            let mantissa = repr.split('e').next().unwrap().replace(['-', '.'], "");
+            assert!(mantissa.len() <= 3, "{repr} has more than 3 digits");
+            assert!((value - trimmed).abs() <= 5e-3 * value.abs());
+        }
+        assert!((trimmed.xfxq2(21, &[2e-2, 5.0]) / pdf.xfxq2(21, &[2e-2, 5.0]) - 1.0).abs() < 1e-2);
+    }
+
+    #[test]
+    fn test_morph() {
+        let pdf = test_pdf();
+        let other = pdf
+            .scale_flavor(21, 3.0)
+            .unwrap()
+            .scale_flavor(2, 0.5)
+            .unwrap();
+
+        let start = pdf.morph(&other, 0.0).unwrap();
+        let end = pdf.morph(&other, 1.0).unwrap();
+        let mid = pdf.morph(&other, 0.5).unwrap();
+        for point in [[1e-3, 1.0], [2e-2, 5.0], [0.5, 500.0]] {
+            for pid in [21, 1, 2] {
+                let (a, b) = (pdf.xfxq2(pid, &point), other.xfxq2(pid, &point));
+                assert_eq!(start.xfxq2(pid, &point), a);
+                assert_eq!(end.xfxq2(pid, &point), b);
+                assert!((mid.xfxq2(pid, &point) - 0.5 * (a + b)).abs() < PRECISION);
+            }
+        }
+
+        assert!(matches!(
+            pdf.morph(&other, 1.5),
+            Err(Error::MorphParameterOutOfRange { .. })
+        ));
+        let mut knot_array = pdf.grid_pdf.knot_array.clone();
+        knot_array.subgrids[0].xs[1] = 2e-2;
+        let shifted = PDF {
+            grid_pdf: GridPDF::new(pdf.metadata().clone(), knot_array),
+        };
+        assert!(matches!(
+            pdf.morph(&shifted, 0.5),
+            Err(Error::IncompatibleGrids(_))
+        ));
+    }
+
+    #[test]
+    fn test_regenerate_alphas_table() {
+        let pdf = test_pdf();
+        let info = MetaData::new_v2(MetaDataV2 {
+            base: MetaDataV1 {
+                alphas_type: "ode".into(),
+                alphas_order_qcd: 2,
+                m_z: 91.1876,
+                m_charm: 1.51,
+                m_bottom: 4.92,
+                m_top: 172.5,
+                ..(**pdf.metadata()).clone()
+            },
+            alphas_mz: Some(0.118),
+            ..Default::default()
+        });
+        let pdf = PDF {
+            grid_pdf: GridPDF::new(info, pdf.grid_pdf.knot_array.clone()),
+        };
+
+        let q2_grid: Vec<f64> = (0..=300).map(|i| 10f64.powf(i as f64 / 50.0)).collect();
+        let regenerated = pdf.regenerate_alphas_table(&q2_grid);
+        assert_eq!(regenerated.metadata().alphas_type, "ipol");
+        assert_eq!(regenerated.metadata().alphas_vals.len(), q2_grid.len());
+
+        for q2 in [1.5, 20.0, 91.1876 * 91.1876, 3.3e3, 5e5] {
+            let reference = pdf.alphas_q2(q2);
+            assert!((regenerated.alphas_q2(q2) / reference - 1.0).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_pdf_with_interpolator() {
+        let pdf = test_pdf();
+        let (info, knot_array) = (pdf.metadata().clone(), pdf.grid_pdf.knot_array.clone());
+        let overridden = pdf_with_interpolator(
+            info.clone(),
+            knot_array.clone(),
+            "logbilinear".parse().unwrap(),
+        )
+        .unwrap();
+        assert!(matches!(
+            overridden.metadata().interpolator_type,
+            InterpolatorType::LogBilinear
+        ));
+
+        // The quadratic `x * x` is linear in neither `x` nor `ln(x)` in between the nodes.
+        assert_eq!(
+            overridden.xfxq2(1, &[1e-2, 10.0]),
+            pdf.xfxq2(1, &[1e-2, 10.0])
+        );
+        assert_ne!(
+            overridden.xfxq2(1, &[5e-2, 10.0]),
+            pdf.xfxq2(1, &[5e-2, 10.0])
+        );
+
+        assert!(matches!(
+            pdf_with_interpolator(info, knot_array, InterpolatorType::InterpNDLinear),
+            Err(Error::IncompatibleInterpolator(
+                InterpolatorType::InterpNDLinear
+            ))
+        ));
+        assert!("logquartic".parse::<InterpolatorType>().is_err());
+    }
+
+    #[test]
+    fn test_validate_positivity() {
+        // A steep drop in `x` makes the cubic interpolation undershoot in between the nodes.
+        let xs = [1e-4, 1e-3, 1e-2, 1e-1, 1.0];
+        let q2s = [1.0, 10.0, 100.0, 1000.0];
+        let pdf = pdf_from_fn(
+            &[0.0],
+            &[0.0],
+            &xs,
+            &q2s,
+            &[21, 2],
+            InterpolatorType::LogBicubic,
+            |pid, _, _, x, _| match pid {
+                21 if x < 1e-2 => 1.0,
+                21 => 1e-3,
+                _ => 0.5,
+            },
+        );
+
+        // A scan restricted to the nodes does not see any negative value.
+        assert!(xs
+            .iter()
+            .cartesian_product(&q2s)
+            .all(|(&x, &q2)| pdf.xfxq2(21, &[x, q2]) >= 0.0));
+
+        let violations = pdf.validate_positivity(&[21, 2], 20);
+        assert!(!violations.is_empty());
+        for violation in &violations {
+            assert_eq!(violation.pid, 21);
+            assert!(violation.value < 0.0);
+            assert!(violation.point[0] > xs[2] && violation.point[0] < xs[3]);
+            assert!(!xs.contains(&violation.point[0]));
+        }
+
+        // The sampled scales are always `Q2`
+        let pdf_q = pdf.with_query_scale(ScaleUnit::Q);
+        assert_eq!(pdf_q.validate_positivity(&[21, 2], 20), violations);
     }
 }
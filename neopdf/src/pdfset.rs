@@ -0,0 +1,1178 @@
+//! This module provides the interface for working with full PDF sets.
+//!
+//! It defines the [`PDFSet`] struct, which holds all the members of a PDF set and provides the
+//! statistical combinations of member-wise values, such as the computation of the PDF
+//! uncertainties following the conventions of `LHAPDF`.
+//!
+//! # Key Types
+//!
+//! - [`PDFSet`]: Collection of all the members of a PDF set.
+//! - [`ErrorType`]: The representation of the PDF uncertainties (replicas or Hessian).
+//! - [`AsymMode`]: The formula used to compute asymmetric Hessian uncertainties.
+//! - [`Uncertainty`]: The central value and uncertainties of a quantity.
+//! - [`ReweightedSet`]: A replica set in which each replica carries a weight.
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex, OnceLock};
+
+use ndarray::Array2;
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+use thiserror::Error;
+
+use super::gridpdf::Error as GridError;
+use super::interpolator::Point;
+use super::manage::{ManageData, PdfSetFormat};
+use super::metadata::{MemberInfo, MetaData};
+use super::pdf::PDF;
+use super::utils::normal_quantile;
+
+/// The confidence level, in percent, of a one-sigma interval of the normal distribution.
+pub const ONE_SIGMA_CL: f64 = 68.268949;
+
+/// Errors that can occur when accessing or combining the members of a PDF set.
+#[derive(Debug, Error)]
+pub enum SetError {
+    /// Error indicating that the set does not contain any member.
+    #[error("The PDF set does not contain any member")]
+    EmptySet,
+    /// Error indicating that the number of values does not match the number of members.
+    #[error("Expected {expected} values (one per member) but got {got}")]
+    MismatchedMembers {
+        /// The number of members in the set.
+        expected: usize,
+        /// The number of values provided.
+        got: usize,
+    },
+    /// Error indicating that a member index is not smaller than the number of members.
+    #[error("Member {requested} requested but the set has {available} members")]
+    MemberOutOfRange {
+        /// The requested member index.
+        requested: usize,
+        /// The number of members in the set.
+        available: usize,
+    },
+    /// Error indicating that the error type of the set is not supported.
+    #[error("Unsupported error type: {0}")]
+    UnsupportedErrorType(String),
+    /// Error indicating that the number of members is not valid for the error type.
+    #[error("Invalid number of members {members} for error type {error_type:?}")]
+    InvalidMemberCount {
+        /// The error type of the set.
+        error_type: ErrorType,
+        /// The number of members in the set.
+        members: usize,
+    },
+    /// Error indicating that a reweighting weight is negative or not finite.
+    #[error("Invalid weight {weight} for member {member}")]
+    InvalidWeight {
+        /// The index of the member.
+        member: usize,
+        /// The value of the weight.
+        weight: f64,
+    },
+    /// Error indicating that all the reweighting weights of the replicas vanish.
+    #[error("The weights of all the replicas vanish")]
+    VanishingWeights,
+    /// Error indicating that a confidence level is not in `(0, 100)`.
+    #[error("Invalid confidence level {0}%")]
+    InvalidConfidenceLevel(f64),
+}
+
+/// Represents the representation of the PDF uncertainties of a set.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ErrorType {
+    /// Monte Carlo replicas, the first member being their average.
+    Replicas,
+    /// Symmetric Hessian eigenvectors, one member per eigenvector direction.
+    SymmHessian,
+    /// Asymmetric Hessian eigenvectors, with a pair of members per eigenvector direction.
+    Hessian,
+}
+
+impl FromStr for ErrorType {
+    type Err = SetError;
+
+    /// Parses the `ErrorType` field of the metadata, e.g. `"replicas"`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "replicas" => Ok(Self::Replicas),
+            "symmhessian" => Ok(Self::SymmHessian),
+            "hessian" => Ok(Self::Hessian),
+            _ => Err(SetError::UnsupportedErrorType(s.to_string())),
+        }
+    }
+}
+
+/// The formula used to compute asymmetric Hessian uncertainties.
+///
+/// The two formulas coincide when the members of each eigenvector pair shift a quantity in
+/// opposite directions, but differ for non-quadratic quantities where both members of a pair
+/// shift it in the same direction.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AsymMode {
+    /// Each eigenvector pair contributes the largest shift of its two members in each
+    /// direction, i.e. `max(X+ - X0, X- - X0, 0)` for the upper uncertainty. This is the
+    /// formula used by `LHAPDF`.
+    #[default]
+    PairFormula,
+    /// Each eigenvector direction contributes independently, i.e. the shifts of both members
+    /// of a pair are added in quadrature.
+    MasterFormula,
+}
+
+/// The central value and uncertainties of a quantity computed over a PDF set.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Uncertainty {
+    /// The central value.
+    pub central: f64,
+    /// The upper uncertainty.
+    pub errplus: f64,
+    /// The lower uncertainty.
+    pub errminus: f64,
+    /// The symmetrized uncertainty.
+    pub errsymm: f64,
+    /// The factor applied to the uncertainties to rescale them from the confidence level of
+    /// the set to the requested one.
+    pub scale: f64,
+}
+
+/// The sets loaded with [`PDFSet::load_shared`], keyed by the canonical path of the set.
+///
+/// Each entry is initialized once, such that the threads loading the same set concurrently
+/// wait for the first one instead of parsing the set again.
+type SharedSets = Mutex<HashMap<PathBuf, Arc<OnceLock<Arc<PDFSet>>>>>;
+
+static SHARED_SETS: OnceLock<SharedSets> = OnceLock::new();
+
+/// Represents a full PDF set, i.e. the collection of all its members.
+pub struct PDFSet {
+    members: Vec<PDF>,
+}
+
+impl PDFSet {
+    /// Loads all the members of a PDF set in parallel.
+    ///
+    /// # Arguments
+    ///
+    /// * `pdf_name` - The name of the PDF set (e.g., "NNPDF40_nnlo_as_01180").
+    ///
+    /// # Returns
+    ///
+    /// A `PDFSet` instance containing all the members of the set.
+    pub fn load(pdf_name: &str) -> Self {
+        Self {
+            members: PDF::load_pdfs(pdf_name),
+        }
+    }
+
+    /// Loads all the members of a PDF set, sharing the instance with all the other loads of
+    /// the same set.
+    ///
+    /// The sets are registered by the canonical path of their files, such that different names
+    /// resolving to the same files share one instance. The first call loads the set as
+    /// [`PDFSet::load`], and the concurrent calls for the same set wait for it to complete. The
+    /// loaded sets stay registered for the lifetime of the program.
+    ///
+    /// # Arguments
+    ///
+    /// * `pdf_name` - The name of the PDF set (e.g., "NNPDF40_nnlo_as_01180").
+    ///
+    /// # Returns
+    ///
+    /// The shared `PDFSet` instance.
+    pub fn load_shared(pdf_name: &str) -> Arc<Self> {
+        let format = if pdf_name.ends_with(".neopdf.lz4") {
+            PdfSetFormat::Neopdf
+        } else {
+            PdfSetFormat::Lhapdf
+        };
+        let manager = ManageData::new(pdf_name, format);
+        let path = manager
+            .set_path()
+            .canonicalize()
+            .unwrap_or_else(|_| manager.set_path().to_path_buf());
+
+        let slot = SHARED_SETS
+            .get_or_init(Default::default)
+            .lock()
+            .unwrap()
+            .entry(path)
+            .or_default()
+            .clone();
+
+        slot.get_or_init(|| Arc::new(Self::load(pdf_name))).clone()
+    }
+
+    /// Creates a `PDFSet` from already loaded members.
+    ///
+    /// # Arguments
+    ///
+    /// * `members` - The members of the set, the first one being the central member.
+    ///
+    /// # Returns
+    ///
+    /// A `PDFSet` instance, or an error if `members` is empty.
+    pub fn from_members(members: Vec<PDF>) -> Result<Self, SetError> {
+        if members.is_empty() {
+            return Err(SetError::EmptySet);
+        }
+
+        Ok(Self { members })
+    }
+
+    /// Returns the metadata of the set, taken from its central member.
+    pub fn metadata(&self) -> &MetaData {
+        self.members[0].metadata()
+    }
+
+    /// Returns the number of members in the set, as [`PDFSet::len`].
+    pub fn num_members(&self) -> usize {
+        self.len()
+    }
+
+    /// Returns the number of members in the set.
+    pub fn len(&self) -> usize {
+        self.members.len()
+    }
+
+    /// Checks whether the set has no member, which is never the case since every set has a
+    /// central member.
+    pub fn is_empty(&self) -> bool {
+        self.members.is_empty()
+    }
+
+    /// Returns the central member of the set, i.e. the member `0`.
+    pub fn central(&self) -> &PDF {
+        &self.members[0]
+    }
+
+    /// Iterates over the members of the set, starting with the central member.
+    ///
+    /// The members are borrowed, such that the iteration does not copy any grid.
+    pub fn iter_members(&self) -> impl ExactSizeIterator<Item = &PDF> + '_ {
+        self.members.iter()
+    }
+
+    /// Returns a reference to the member at the given index.
+    ///
+    /// # Arguments
+    ///
+    /// * `idx` - The index of the member, `0` being the central member.
+    ///
+    /// # Returns
+    ///
+    /// The member, or `SetError::MemberOutOfRange` if `idx` is not smaller than
+    /// [`PDFSet::num_members`].
+    pub fn member(&self, idx: usize) -> Result<&PDF, SetError> {
+        self.members.get(idx).ok_or(SetError::MemberOutOfRange {
+            requested: idx,
+            available: self.members.len(),
+        })
+    }
+
+    /// Returns the annotations of a given member, if the set provides them.
+    ///
+    /// # Arguments
+    ///
+    /// * `idx` - The index of the member.
+    ///
+    /// # Returns
+    ///
+    /// The `MemberInfo` of the member, or `None` if the member is not annotated.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    pub fn member_info(&self, idx: usize) -> Option<&MemberInfo> {
+        self.members[idx].metadata().member_info()
+    }
+
+    /// Dumps the axes of the subgrids of a given member as JSON.
+    ///
+    /// Abstraction to the `PDF::dump_axes_json` method.
+    ///
+    /// # Arguments
+    ///
+    /// * `member` - The index of the member.
+    ///
+    /// # Returns
+    ///
+    /// A JSON array with one object per subgrid containing its axes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `member` is out of bounds.
+    pub fn dump_axes_json(&self, member: usize) -> String {
+        self.members[member].dump_axes_json()
+    }
+
+    /// Iterates over the `Q2` knots of a given member, yielding the values of all the flavors
+    /// and `x` knots at each of them.
+    ///
+    /// Abstraction to the `PDF::q2_slices` method.
+    ///
+    /// # Arguments
+    ///
+    /// * `member` - The index of the member.
+    ///
+    /// # Returns
+    ///
+    /// An iterator over the pairs `(q2, values)`, where `values` has the shape `(nflav, nx)`,
+    /// or an error if the grid is not 2D.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `member` is out of bounds.
+    pub fn q2_slices(
+        &self,
+        member: usize,
+    ) -> Result<impl Iterator<Item = (f64, Array2<f64>)> + '_, GridError> {
+        self.members[member].q2_slices()
+    }
+
+    /// Returns the representation of the PDF uncertainties of the set.
+    pub fn error_type(&self) -> Result<ErrorType, SetError> {
+        self.metadata().error_type.parse()
+    }
+
+    /// Returns the confidence level, in percent, of the uncertainties of the set.
+    ///
+    /// This is the `ErrorConfLevel` entry of the metadata, which defaults to
+    /// [`ONE_SIGMA_CL`] as in `LHAPDF`.
+    pub fn error_conf_level(&self) -> f64 {
+        self.metadata()
+            .get_entry("ErrorConfLevel")
+            .and_then(|value| value.as_f64())
+            .unwrap_or(ONE_SIGMA_CL)
+    }
+
+    /// Computes the central value and the uncertainties of a quantity at a given confidence
+    /// level.
+    ///
+    /// The uncertainties of [`PDFSet::uncertainty`] are rescaled from the confidence level of
+    /// the set, see [`PDFSet::error_conf_level`], to `cl` assuming Gaussian statistics. This
+    /// matches `LHAPDF`, whose default is [`ONE_SIGMA_CL`].
+    ///
+    /// # Arguments
+    ///
+    /// * `values` - The values of the quantity for each member of the set.
+    /// * `cl` - The requested confidence level, in percent.
+    /// * `mode` - The formula used for asymmetric Hessian uncertainties.
+    ///
+    /// # Returns
+    ///
+    /// The rescaled `Uncertainty` of the quantity, or an error if the values do not match the
+    /// members or if a confidence level is not in `(0, 100)`.
+    pub fn uncertainty_at_cl(
+        &self,
+        values: &[f64],
+        cl: f64,
+        mode: AsymMode,
+    ) -> Result<Uncertainty, SetError> {
+        let set_cl = self.error_conf_level();
+        for level in [cl, set_cl] {
+            if !(level > 0.0 && level < 100.0) {
+                return Err(SetError::InvalidConfidenceLevel(level));
+            }
+        }
+
+        let unc = self.uncertainty(values, mode)?;
+        if cl == set_cl {
+            return Ok(unc);
+        }
+
+        let quantile = |level: f64| normal_quantile(0.5 + level / 200.0);
+        let scale = quantile(cl) / quantile(set_cl);
+        Ok(Uncertainty {
+            errplus: scale * unc.errplus,
+            errminus: scale * unc.errminus,
+            errsymm: scale * unc.errsymm,
+            scale,
+            ..unc
+        })
+    }
+
+    /// Computes the central value and the uncertainties of a quantity over the set.
+    ///
+    /// The formulas follow the ones of `LHAPDF` for the error type of the set: the average
+    /// and standard deviation for replicas, and the (a)symmetric master formulas for Hessian
+    /// sets, in which case `mode` selects the asymmetric formula.
+    ///
+    /// # Arguments
+    ///
+    /// * `values` - The values of the quantity for each member of the set.
+    /// * `mode` - The formula used for asymmetric Hessian uncertainties.
+    ///
+    /// # Returns
+    ///
+    /// The `Uncertainty` of the quantity, at the confidence level of the set, or an error if
+    /// the values do not match the members.
+    pub fn uncertainty(&self, values: &[f64], mode: AsymMode) -> Result<Uncertainty, SetError> {
+        if values.len() != self.num_members() {
+            return Err(SetError::MismatchedMembers {
+                expected: self.num_members(),
+                got: values.len(),
+            });
+        }
+
+        let error_type = self.error_type()?;
+        let invalid_count = match error_type {
+            ErrorType::Replicas => values.len() < 3,
+            ErrorType::SymmHessian => values.len() < 2,
+            ErrorType::Hessian => values.len() < 3 || values.len() % 2 == 0,
+        };
+        if invalid_count {
+            return Err(SetError::InvalidMemberCount {
+                error_type,
+                members: values.len(),
+            });
+        }
+
+        let (central, errplus, errminus, errsymm) = match error_type {
+            ErrorType::Replicas => {
+                let replicas = &values[1..];
+                let n = replicas.len() as f64;
+                let mean = replicas.iter().sum::<f64>() / n;
+                let mean_sq = replicas.iter().map(|v| v * v).sum::<f64>() / n;
+                let std = (n / (n - 1.0) * (mean_sq - mean * mean)).max(0.0).sqrt();
+                (mean, std, std, std)
+            }
+            ErrorType::SymmHessian => {
+                let central = values[0];
+                let err = values[1..]
+                    .iter()
+                    .map(|v| (v - central).powi(2))
+                    .sum::<f64>()
+                    .sqrt();
+                (central, err, err, err)
+            }
+            ErrorType::Hessian => {
+                let central = values[0];
+                let pairs: Vec<(f64, f64)> = values[1..]
+                    .chunks_exact(2)
+                    .map(|pair| (pair[0] - central, pair[1] - central))
+                    .collect();
+
+                let (errplus, errminus) = match mode {
+                    AsymMode::PairFormula => pairs.iter().fold((0.0, 0.0), |(p, m), (a, b)| {
+                        let up = a.max(*b).max(0.0);
+                        let down = (-a).max(-b).max(0.0);
+                        (p + up * up, m + down * down)
+                    }),
+                    AsymMode::MasterFormula => pairs
+                        .iter()
+                        .flat_map(|&(a, b)| [a, b])
+                        .fold((0.0, 0.0), |(p, m), d| {
+                            (p + d.max(0.0).powi(2), m + (-d).max(0.0).powi(2))
+                        }),
+                };
+                let errsymm = 0.5
+                    * pairs
+                        .iter()
+                        .map(|(a, b)| (a - b).powi(2))
+                        .sum::<f64>()
+                        .sqrt();
+
+                (central, errplus.sqrt(), errminus.sqrt(), errsymm)
+            }
+        };
+
+        Ok(Uncertainty {
+            central,
+            errplus,
+            errminus,
+            errsymm,
+            scale: 1.0,
+        })
+    }
+
+    /// Computes the correlation between two quantities over the set.
+    ///
+    /// The formulas follow the ones of `LHAPDF` for the error type of the set, and use the
+    /// symmetrized uncertainties of the quantities.
+    ///
+    /// # Arguments
+    ///
+    /// * `values_a` - The values of the first quantity for each member of the set.
+    /// * `values_b` - The values of the second quantity for each member of the set.
+    ///
+    /// # Returns
+    ///
+    /// The correlation coefficient, in `[-1, 1]`, or an error if the values do not match the
+    /// members.
+    pub fn correlation(&self, values_a: &[f64], values_b: &[f64]) -> Result<f64, SetError> {
+        let unc_a = self.uncertainty(values_a, AsymMode::default())?;
+        let unc_b = self.uncertainty(values_b, AsymMode::default())?;
+
+        let covariance = match self.error_type()? {
+            ErrorType::Replicas => {
+                let n = (values_a.len() - 1) as f64;
+                values_a[1..]
+                    .iter()
+                    .zip(&values_b[1..])
+                    .map(|(a, b)| (a - unc_a.central) * (b - unc_b.central))
+                    .sum::<f64>()
+                    / (n - 1.0)
+            }
+            ErrorType::SymmHessian => values_a[1..]
+                .iter()
+                .zip(&values_b[1..])
+                .map(|(a, b)| (a - values_a[0]) * (b - values_b[0]))
+                .sum(),
+            ErrorType::Hessian => {
+                0.25 * values_a[1..]
+                    .chunks_exact(2)
+                    .zip(values_b[1..].chunks_exact(2))
+                    .map(|(a, b)| (a[0] - a[1]) * (b[0] - b[1]))
+                    .sum::<f64>()
+            }
+        };
+
+        Ok(covariance / (unc_a.errsymm * unc_b.errsymm))
+    }
+
+    /// Assigns a weight to each replica of the set, e.g. from a Bayesian reweighting.
+    ///
+    /// # Arguments
+    ///
+    /// * `weights` - The non-negative weights, one per member. The weight of the member `0`,
+    ///   which is the average of the replicas, is ignored.
+    ///
+    /// # Returns
+    ///
+    /// A `ReweightedSet` computing weighted statistics, or an error if the set does not
+    /// consist of replicas or if the weights are not valid.
+    pub fn reweight(&self, weights: &[f64]) -> Result<ReweightedSet<'_>, SetError> {
+        let error_type = self.error_type()?;
+        if error_type != ErrorType::Replicas {
+            return Err(SetError::UnsupportedErrorType(
+                self.metadata().error_type.clone(),
+            ));
+        }
+        if weights.len() != self.num_members() {
+            return Err(SetError::MismatchedMembers {
+                expected: self.num_members(),
+                got: weights.len(),
+            });
+        }
+        if self.num_members() < 3 {
+            return Err(SetError::InvalidMemberCount {
+                error_type,
+                members: self.num_members(),
+            });
+        }
+        if let Some((member, &weight)) = weights
+            .iter()
+            .enumerate()
+            .skip(1)
+            .find(|(_, w)| !(w.is_finite() && **w >= 0.0))
+        {
+            return Err(SetError::InvalidWeight { member, weight });
+        }
+
+        // Normalize the weights such that they sum up to the number of replicas.
+        let total: f64 = weights[1..].iter().sum();
+        if total == 0.0 {
+            return Err(SetError::VanishingWeights);
+        }
+        let num_replicas = (weights.len() - 1) as f64;
+        let weights = weights[1..]
+            .iter()
+            .map(|w| w * num_replicas / total)
+            .collect();
+
+        Ok(ReweightedSet { set: self, weights })
+    }
+
+    /// Computes the envelope of a quantity over the set, i.e. the minimum and maximum of
+    /// its values over all the members.
+    ///
+    /// Contrary to [`PDFSet::uncertainty`], this does not depend on the error type of the set.
+    ///
+    /// # Arguments
+    ///
+    /// * `values` - The values of the quantity for each member of the set.
+    ///
+    /// # Returns
+    ///
+    /// A tuple `(min, max)` with the lower and upper edges of the band.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of values does not match the number of members.
+    pub fn envelope(&self, values: &[f64]) -> (f64, f64) {
+        assert_eq!(
+            values.len(),
+            self.num_members(),
+            "Expected one value per member"
+        );
+
+        values
+            .iter()
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), &v| {
+                (min.min(v), max.max(v))
+            })
+    }
+
+    /// Evaluates `xf(x, Q2)` for all the members of the set.
+    ///
+    /// # Arguments
+    ///
+    /// * `pid` - The PDG ID of the parton.
+    /// * `points` - The point to interpolate on, as for `PDF::xfxq2`.
+    ///
+    /// # Returns
+    ///
+    /// The values of all the members, in the order of the members.
+    pub fn xfxq2_all_members(&self, pid: i32, points: &[f64]) -> Vec<f64> {
+        self.members
+            .iter()
+            .map(|member| member.xfxq2(pid, points))
+            .collect()
+    }
+
+    /// Applies `f` to all the members, in parallel with the `rayon` feature.
+    ///
+    /// The results are collected in the order of the members, such that the output does not
+    /// depend on the number of threads.
+    fn map_members<T, F>(&self, f: F) -> Vec<T>
+    where
+        T: Send,
+        F: Fn(&PDF) -> T + Send + Sync,
+    {
+        #[cfg(feature = "rayon")]
+        let values = self.members.par_iter().map(f).collect();
+        #[cfg(not(feature = "rayon"))]
+        let values = self.members.iter().map(f).collect();
+
+        values
+    }
+
+    /// Evaluates `xf` for all the members on a collection of points.
+    ///
+    /// With the `rayon` feature the members are distributed over the `rayon` threads,
+    /// otherwise they are evaluated serially. In both cases the results are collected in the
+    /// order of the members, such that the output does not depend on the number of threads.
+    ///
+    /// # Arguments
+    ///
+    /// * `pid` - The PDG ID of the parton.
+    /// * `points` - The points to interpolate on, with the coordinates required by the
+    ///   subgrids of the set.
+    ///
+    /// # Returns
+    ///
+    /// One vector per member, in the order of the members, holding the values at `points`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a point misses a coordinate required by the subgrids, see
+    /// [`Point::to_ordered`].
+    pub fn xfx_all_members(&self, pid: i32, points: &[Point]) -> Vec<Vec<f64>> {
+        self.map_members(|member| {
+            let config = member.subgrid(0).interpolation_config();
+            points
+                .iter()
+                .map(|point| member.xfxq2(pid, &point.to_ordered(config)))
+                .collect()
+        })
+    }
+
+    /// Evaluates `xf(x, Q2)` for all the members on a batch of paired `(x, Q2)` points.
+    ///
+    /// With the `rayon` feature the members are evaluated in parallel, such that this is the
+    /// efficient input for the computation of uncertainty bands over a kinematic scan.
+    ///
+    /// # Arguments
+    ///
+    /// * `pid` - The PDG ID of the parton.
+    /// * `xs` - The momentum fractions `x`.
+    /// * `q2s` - The energy scales `Q2`, one per `x` value.
+    ///
+    /// # Returns
+    ///
+    /// A 2D array with shape `[num_members, xs.len()]`, or an error if `xs` and `q2s` do not
+    /// have the same length.
+    pub fn xfxq2_all_members_batch(
+        &self,
+        pid: i32,
+        xs: &[f64],
+        q2s: &[f64],
+    ) -> Result<Array2<f64>, GridError> {
+        let rows = self
+            .map_members(|member| member.xfxq2_batch(pid, xs, q2s))
+            .into_iter()
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(
+            Array2::from_shape_vec((rows.len(), xs.len()), rows.concat())
+                .expect("Each member provides one value per point"),
+        )
+    }
+
+    /// Evaluates `xf(x, Q2)` for all the members and returns their envelope.
+    ///
+    /// # Arguments
+    ///
+    /// * `pid` - The PDG ID of the parton.
+    /// * `x` - The momentum fraction.
+    /// * `q2` - The energy scale squared.
+    ///
+    /// # Returns
+    ///
+    /// A tuple `(min, max)` with the lower and upper edges of the band.
+    pub fn envelope_at(&self, pid: i32, x: f64, q2: f64) -> (f64, f64) {
+        self.envelope(&self.xfxq2_all_members(pid, &[x, q2]))
+    }
+}
+
+/// A replica set in which each replica carries a weight, see [`PDFSet::reweight`].
+pub struct ReweightedSet<'a> {
+    set: &'a PDFSet,
+    weights: Vec<f64>,
+}
+
+impl ReweightedSet<'_> {
+    /// Returns the weights of the replicas, normalized such that they sum up to the number of
+    /// replicas.
+    pub fn weights(&self) -> &[f64] {
+        &self.weights
+    }
+
+    /// Computes the weighted average of a quantity over the replicas.
+    ///
+    /// # Arguments
+    ///
+    /// * `values` - The values of the quantity for each member of the set.
+    ///
+    /// # Returns
+    ///
+    /// The weighted average, or an error if the values do not match the members.
+    pub fn central(&self, values: &[f64]) -> Result<f64, SetError> {
+        Ok(self.uncertainty(values)?.central)
+    }
+
+    /// Computes the weighted average and standard deviation of a quantity over the replicas.
+    ///
+    /// With uniform weights, this reproduces [`PDFSet::uncertainty`].
+    ///
+    /// # Arguments
+    ///
+    /// * `values` - The values of the quantity for each member of the set.
+    ///
+    /// # Returns
+    ///
+    /// The `Uncertainty` of the quantity, or an error if the values do not match the members.
+    pub fn uncertainty(&self, values: &[f64]) -> Result<Uncertainty, SetError> {
+        if values.len() != self.set.num_members() {
+            return Err(SetError::MismatchedMembers {
+                expected: self.set.num_members(),
+                got: values.len(),
+            });
+        }
+
+        let replicas = &values[1..];
+        let n = replicas.len() as f64;
+        let mean = replicas
+            .iter()
+            .zip(&self.weights)
+            .map(|(v, w)| w * v)
+            .sum::<f64>()
+            / n;
+        let variance = replicas
+            .iter()
+            .zip(&self.weights)
+            .map(|(v, w)| w * (v - mean).powi(2))
+            .sum::<f64>()
+            / (n - 1.0);
+        let std = variance.sqrt();
+
+        Ok(Uncertainty {
+            central: mean,
+            errplus: std,
+            errminus: std,
+            errsymm: std,
+            scale: 1.0,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gridpdf::GridArray;
+    use crate::metadata::MetaDataV1;
+    use crate::test_support::xq2_subgrid_data;
+
+    const PRECISION: f64 = 1e-14;
+
+    fn test_set(error_type: &str, num_members: usize) -> PDFSet {
+        let info = MetaData::new_v1(MetaDataV1 {
+            num_members: num_members as u32,
+            flavors: vec![21],
+            error_type: error_type.to_string(),
+            ..Default::default()
+        });
+        test_set_with_info(info, num_members)
+    }
+
+    fn test_set_with_info(info: MetaData, num_members: usize) -> PDFSet {
+        let xs = vec![1e-3, 1e-2, 1e-1, 1.0];
+        let q2s = vec![1.0, 10.0, 100.0, 1000.0];
+        let grid_data = xs
+            .iter()
+            .flat_map(|&x| q2s.iter().map(move |_| x * (1.0 - x)))
+            .collect();
+        let subgrid_data = vec![xq2_subgrid_data(xs, q2s, grid_data)];
+        let knot_array = GridArray::new(subgrid_data, vec![21]);
+
+        let members = (0..num_members)
+            .map(|_| PDF::from_parts(info.clone(), knot_array.clone()))
+            .collect();
+        PDFSet::from_members(members).unwrap()
+    }
+
+    #[test]
+    fn test_uncertainty_replicas_and_symmhessian() {
+        let set = test_set("replicas", 5);
+        let unc = set
+            .uncertainty(&[0.0, 1.0, 2.0, 3.0, 4.0], AsymMode::default())
+            .unwrap();
+        assert!((unc.central - 2.5).abs() < PRECISION);
+        assert!((unc.errsymm - (5.0f64 / 3.0).sqrt()).abs() < PRECISION);
+        assert_eq!(unc.errplus, unc.errminus);
+
+        let set = test_set("symmhessian", 3);
+        let unc = set
+            .uncertainty(&[1.0, 1.3, 0.6], AsymMode::default())
+            .unwrap();
+        assert_eq!(unc.central, 1.0);
+        assert!((unc.errsymm - 0.5).abs() < PRECISION);
+    }
+
+    #[test]
+    fn test_member_out_of_range() {
+        let set = test_set("replicas", 3);
+        assert!(set.member(2).is_ok());
+        assert!(matches!(
+            set.member(3),
+            Err(SetError::MemberOutOfRange {
+                requested: 3,
+                available: 3
+            })
+        ));
+        let Err(error) = set.member(10) else {
+            panic!("member 10 is out of range");
+        };
+        assert_eq!(
+            error.to_string(),
+            "Member 10 requested but the set has 3 members"
+        );
+    }
+
+    #[test]
+    fn test_iter_members() {
+        let set = test_set("replicas", 4);
+        assert_eq!(set.len(), set.metadata().num_members as usize);
+        assert!(!set.is_empty());
+        assert!(std::ptr::eq(set.central(), set.member(0).unwrap()));
+
+        let members = set.iter_members();
+        assert_eq!(members.len(), 4);
+        let mut count = 0;
+        for (idx, member) in members.enumerate() {
+            assert!(std::ptr::eq(member, set.member(idx).unwrap()));
+            count += 1;
+        }
+        assert_eq!(count, set.metadata().num_members as usize);
+    }
+
+    #[test]
+    fn test_uncertainty_at_cl() {
+        let info: MetaData = serde_yaml::from_str(
+            "SetDesc: CL90\nSetIndex: 0\nNumMembers: 5\nXMin: 1e-3\nXMax: 1\nQMin: 1\n\
+             QMax: 1000\nFlavors: [21]\nFormat: lhagrid1\nErrorType: hessian\n\
+             ErrorConfLevel: 90\n",
+        )
+        .unwrap();
+        let set = test_set_with_info(info, 5);
+        assert_eq!(set.error_conf_level(), 90.0);
+
+        // Each eigenvector shifts the quantity by the same amount in both directions
+        let values = [1.0, 1.3, 0.7, 1.4, 0.6];
+        let at_90 = set.uncertainty(&values, AsymMode::default()).unwrap();
+        assert!((at_90.errplus - 0.5).abs() < PRECISION);
+        assert!((at_90.errminus - 0.5).abs() < PRECISION);
+        assert_eq!(at_90.scale, 1.0);
+
+        let at_68 = set
+            .uncertainty_at_cl(&values, ONE_SIGMA_CL, AsymMode::default())
+            .unwrap();
+        let scale = 1.0 / 1.6448536269514722;
+        assert!((at_68.scale - scale).abs() < 1e-8);
+        assert!((at_68.errplus - 0.5 * scale).abs() < 1e-8);
+        assert!((at_68.errminus - 0.5 * scale).abs() < 1e-8);
+        assert!((at_68.errsymm - 0.5 * scale).abs() < 1e-8);
+        assert_eq!(at_68.central, 1.0);
+
+        let at_set_cl = set
+            .uncertainty_at_cl(&values, 90.0, AsymMode::default())
+            .unwrap();
+        assert_eq!(at_set_cl, at_90);
+        assert!(matches!(
+            set.uncertainty_at_cl(&values, 100.0, AsymMode::default()),
+            Err(SetError::InvalidConfidenceLevel(_))
+        ));
+
+        let replicas = test_set("replicas", 5);
+        assert_eq!(replicas.error_conf_level(), ONE_SIGMA_CL);
+        let unc = replicas
+            .uncertainty_at_cl(&[0.0, 1.0, 2.0, 3.0, 4.0], 95.0, AsymMode::default())
+            .unwrap();
+        assert!((unc.errsymm / unc.scale - (5.0f64 / 3.0).sqrt()).abs() < PRECISION);
+        assert!((unc.scale - 1.959963984540054).abs() < 1e-8);
+    }
+
+    #[test]
+    fn test_correlation() {
+        for (error_type, values) in [
+            ("replicas", vec![0.0, 1.0, 2.5, 3.0, 4.5]),
+            ("symmhessian", vec![1.0, 1.3, 0.6, 1.1]),
+            ("hessian", vec![1.0, 1.2, 0.9, 1.1, 1.05]),
+        ] {
+            let set = test_set(error_type, values.len());
+            let scaled: Vec<f64> = values.iter().map(|v| 3.0 * v + 1.0).collect();
+            let flipped: Vec<f64> = values.iter().map(|v| 2.0 - 0.5 * v).collect();
+
+            let correlated = set.correlation(&values, &scaled).unwrap();
+            let anti_correlated = set.correlation(&values, &flipped).unwrap();
+            assert!((correlated - 1.0).abs() < 1e-12, "{error_type}");
+            assert!((anti_correlated + 1.0).abs() < 1e-12, "{error_type}");
+        }
+
+        let set = test_set("hessian", 5);
+        assert!(matches!(
+            set.correlation(&[1.0; 5], &[1.0; 3]),
+            Err(SetError::MismatchedMembers {
+                expected: 5,
+                got: 3
+            })
+        ));
+    }
+
+    #[test]
+    fn test_uncertainty_hessian_asym_modes() {
+        let set = test_set("hessian", 5);
+        // The first eigenvector is quadratic, the second shifts upwards in both directions.
+        let values = [1.0, 1.2, 0.9, 1.1, 1.05];
+
+        let pair = set.uncertainty(&values, AsymMode::PairFormula).unwrap();
+        assert!((pair.errplus - 0.05f64.sqrt()).abs() < PRECISION);
+        assert!((pair.errminus - 0.1).abs() < PRECISION);
+
+        let master = set.uncertainty(&values, AsymMode::MasterFormula).unwrap();
+        assert!((master.errplus - 0.0525f64.sqrt()).abs() < PRECISION);
+        assert!((master.errminus - 0.1).abs() < PRECISION);
+
+        let errsymm = 0.5 * (0.3f64.powi(2) + 0.05f64.powi(2)).sqrt();
+        assert!((pair.errsymm - errsymm).abs() < PRECISION);
+        assert_eq!(pair.errsymm, master.errsymm);
+        assert_eq!(pair.central, master.central);
+
+        assert!(matches!(
+            set.uncertainty(&values[..4], AsymMode::PairFormula),
+            Err(SetError::MismatchedMembers {
+                expected: 5,
+                got: 4
+            })
+        ));
+        assert!(matches!(
+            test_set("hessian", 4).uncertainty(&values[..4], AsymMode::PairFormula),
+            Err(SetError::InvalidMemberCount { .. })
+        ));
+    }
+
+    #[test]
+    fn test_member_info() {
+        use crate::metadata::{InterpolatorType, MemberType, MetaDataV2};
+
+        let dir = tempfile::tempdir().unwrap();
+        let set_path = dir.path().join("AnnotatedSet");
+        std::fs::create_dir(&set_path).unwrap();
+
+        let info = MetaDataV2::from(MetaDataV1 {
+            num_members: 3,
+            flavors: vec![21],
+            interpolator_type: InterpolatorType::LogBilinear,
+            ..Default::default()
+        });
+        std::fs::write(
+            set_path.join("AnnotatedSet.info"),
+            serde_yaml::to_string(&info).unwrap(),
+        )
+        .unwrap();
+        let headers = [
+            "PdfType: central\nPdfDesc: best fit\n",
+            "PdfType: error\nPdfDesc: 68% boundary\n",
+            "Format: lhagrid1\n",
+        ];
+        for (member, header) in headers.iter().enumerate() {
+            std::fs::write(
+                set_path.join(format!("AnnotatedSet_{member:04}.dat")),
+                format!("{header}---\n1e-3 1.0\n1.0 10.0\n21\n1.0\n2.0\n3.0\n4.0\n---\n"),
+            )
+            .unwrap();
+        }
+
+        let set = PDFSet::load(set_path.to_str().unwrap());
+        let central = set.member_info(0).unwrap();
+        assert_eq!(central.description, "best fit");
+        assert_eq!(central.member_type, MemberType::Central);
+        let error = set.member_info(1).unwrap();
+        assert_eq!(error.description, "68% boundary");
+        assert_eq!(error.member_type, MemberType::ErrorPlus);
+        assert!(set.member_info(2).is_none());
+    }
+
+    #[test]
+    fn test_reweight() {
+        let set = test_set("replicas", 5);
+        let values = [0.0, 1.0, 2.0, 3.0, 4.0];
+
+        let uniform = set.reweight(&[0.0, 2.0, 2.0, 2.0, 2.0]).unwrap();
+        let reference = set.uncertainty(&values, AsymMode::default()).unwrap();
+        let unc = uniform.uncertainty(&values).unwrap();
+        assert!((unc.central - reference.central).abs() < PRECISION);
+        assert!((unc.errsymm - reference.errsymm).abs() < PRECISION);
+
+        let skewed = set.reweight(&[1.0, 0.0, 0.0, 1.0, 1.0]).unwrap();
+        assert_eq!(skewed.weights(), [0.0, 0.0, 2.0, 2.0]);
+        assert!((skewed.central(&values).unwrap() - 3.5).abs() < PRECISION);
+        let unc = skewed.uncertainty(&values).unwrap();
+        assert!((unc.errsymm - (2.0 * 0.25 * 2.0 / 3.0f64).sqrt()).abs() < PRECISION);
+
+        assert!(matches!(
+            set.reweight(&[0.0, 1.0, -1.0, 1.0, 1.0]),
+            Err(SetError::InvalidWeight { member: 2, .. })
+        ));
+        assert!(matches!(
+            set.reweight(&[1.0, 0.0, 0.0, 0.0, 0.0]),
+            Err(SetError::VanishingWeights)
+        ));
+        assert!(matches!(
+            test_set("hessian", 5).reweight(&[1.0; 5]),
+            Err(SetError::UnsupportedErrorType(_))
+        ));
+    }
+
+    #[test]
+    fn test_xfxq2_all_members_batch() {
+        let mut members = test_set("replicas", 3).members;
+        members[1] = members[1].scale_flavor(21, 2.0).unwrap();
+        members[2] = members[2].scale_flavor(21, 0.5).unwrap();
+        let set = PDFSet::from_members(members).unwrap();
+
+        let xs = [1e-3, 2e-2, 0.3, 0.9];
+        let q2s = [1.0, 5.0, 80.0, 1000.0];
+        let values = set.xfxq2_all_members_batch(21, &xs, &q2s).unwrap();
+        assert_eq!(values.dim(), (3, 4));
+        for member in 0..3 {
+            let row = set
+                .member(member)
+                .unwrap()
+                .xfxq2_batch(21, &xs, &q2s)
+                .unwrap();
+            assert_eq!(values.row(member).to_vec(), row);
+        }
+        assert_eq!(
+            values.column(2).to_vec(),
+            set.xfxq2_all_members(21, &[xs[2], q2s[2]])
+        );
+
+        assert!(set.xfxq2_all_members_batch(21, &xs, &q2s[1..]).is_err());
+    }
+
+    #[test]
+    fn test_xfx_all_members() {
+        let mut members = test_set("replicas", 4).members;
+        for (i, member) in members.iter_mut().enumerate() {
+            *member = member.scale_flavor(21, 1.0 + 0.25 * i as f64).unwrap();
+        }
+        let set = PDFSet::from_members(members).unwrap();
+
+        let points: Vec<Point> = (0..20)
+            .map(|i| Point::new(10f64.powf(-3.0 + 0.15 * i as f64), 1.0 + 40.0 * i as f64))
+            .collect();
+
+        let serial: Vec<Vec<f64>> = (0..set.num_members())
+            .map(|m| {
+                points
+                    .iter()
+                    .map(|p| set.member(m).unwrap().xfxq2(21, &[p.x, p.q2]))
+                    .collect()
+            })
+            .collect();
+        assert_eq!(set.xfx_all_members(21, &points), serial);
+
+        #[cfg(feature = "rayon")]
+        for num_threads in [1, 2, 4] {
+            let pool = rayon::ThreadPoolBuilder::new()
+                .num_threads(num_threads)
+                .build()
+                .unwrap();
+            let parallel = pool.install(|| set.xfx_all_members(21, &points));
+            assert_eq!(parallel, serial);
+        }
+
+        assert!(set.xfx_all_members(21, &[]).iter().all(Vec::is_empty));
+    }
+
+    #[test]
+    fn test_load_shared() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<PDFSet>();
+
+        let dir = tempfile::tempdir().unwrap();
+        let set_dir = dir.path().join("SharedSet");
+        std::fs::create_dir(&set_dir).unwrap();
+        let info = "SetDesc: shared\nFormat: lhagrid1\nNumMembers: 2\nFlavors: [21]\n\
+            ErrorType: replicas\nXMin: 1e-3\nXMax: 1\nQMin: 1\nQMax: 1000\n\
+            AlphaS_Qs: [1.0, 10.0, 100.0, 1000.0]\nAlphaS_Vals: [0.3, 0.2, 0.15, 0.1]\n";
+        std::fs::write(set_dir.join("SharedSet.info"), info).unwrap();
+        let values: String = (1..=16).map(|v| format!("{v}.0\n")).collect();
+        let member = format!(
+            "PdfType: central\nFormat: lhagrid1\n---\n1e-3 1e-2 1e-1 1.0\n\
+             1.0 10.0 100.0 1000.0\n21\n{values}---\n"
+        );
+        for idx in 0..2 {
+            std::fs::write(set_dir.join(format!("SharedSet_{idx:04}.dat")), &member).unwrap();
+        }
+
+        let name = set_dir.to_str().unwrap().to_string();
+        let sets: Vec<Arc<PDFSet>> = std::thread::scope(|scope| {
+            let handles: Vec<_> = (0..8)
+                .map(|_| scope.spawn(|| PDFSet::load_shared(&name)))
+                .collect();
+            handles.into_iter().map(|h| h.join().unwrap()).collect()
+        });
+
+        assert_eq!(sets[0].num_members(), 2);
+        assert!(sets.iter().all(|set| Arc::ptr_eq(set, &sets[0])));
+
+        // Another name resolving to the same files shares the instance as well.
+        let alias = set_dir.join("..").join("SharedSet");
+        let shared = PDFSet::load_shared(alias.to_str().unwrap());
+        assert!(Arc::ptr_eq(&shared, &sets[0]));
+    }
+
+    #[test]
+    fn test_envelope() {
+        let set = test_set("replicas", 5);
+        assert_eq!(set.envelope(&[1.0, 0.8, 1.4, -0.2, 1.1]), (-0.2, 1.4));
+
+        let value = set.member(0).unwrap().xfxq2(21, &[0.05, 50.0]);
+        assert_eq!(set.envelope_at(21, 0.05, 50.0), (value, value));
+    }
+}
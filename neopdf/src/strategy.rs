@@ -13,6 +13,11 @@
 //!
 //! All interpolation strategies are designed to work with `ninterp`'s data structures and traits,
 //! ensuring compatibility and extensibility.
+//!
+//! On targets with FMA instructions, the cubic polynomials of the `LogBicubic` and
+//! `LogTricubic` coefficients are evaluated with fused multiply-adds. The `deterministic`
+//! feature evaluates them with explicit multiplications and additions instead, which `rustc`
+//! never contracts, such that the results do not depend on the FMA support of the target CPU.
 
 use ndarray::{Array2, Axis, Data, RawDataClone};
 use ninterp::data::{InterpData1D, InterpData2D, InterpData3D};
@@ -23,6 +28,27 @@ use std::f64::consts::PI;
 
 use super::utils;
 
+/// Evaluates the cubic polynomial `coeffs[0] t^3 + coeffs[1] t^2 + coeffs[2] t + coeffs[3]`.
+///
+/// With FMA instructions and without the `deterministic` feature, Horner's scheme is evaluated
+/// with fused multiply-adds, which round differently from the explicit operations.
+#[inline]
+fn cubic_polynomial(t: f64, coeffs: &[f64; 4]) -> f64 {
+    #[cfg(all(target_feature = "fma", not(feature = "deterministic")))]
+    let value = coeffs[0]
+        .mul_add(t, coeffs[1])
+        .mul_add(t, coeffs[2])
+        .mul_add(t, coeffs[3]);
+    #[cfg(not(all(target_feature = "fma", not(feature = "deterministic"))))]
+    let value = {
+        let t2 = t * t;
+        let t3 = t2 * t;
+        coeffs[0] * t3 + coeffs[1] * t2 + coeffs[2] * t + coeffs[3]
+    };
+
+    value
+}
+
 /// Implements bilinear interpolation for 2D data.
 ///
 /// This strategy performs linear interpolation sequentially along two dimensions.
@@ -221,12 +247,104 @@ impl LogBicubicInterpolation {
         Ok(i)
     }
 
+    /// Finds the interval for bicubic interpolation, trying the interval `hint` first.
+    ///
+    /// The result is the same as the one of `find_bicubic_interval`, the binary search being
+    /// skipped when `x` lies in the interval `hint`. Hints that are not valid intervals of
+    /// `coords` are ignored.
+    fn find_bicubic_interval_from(
+        coords: &[f64],
+        x: f64,
+        hint: Option<usize>,
+    ) -> Result<usize, InterpolateError> {
+        if let Some(i) = hint.filter(|&i| i + 1 < coords.len()) {
+            let is_last = i + 2 == coords.len();
+            if coords[i] <= x && (x < coords[i + 1] || (is_last && x == coords[i + 1])) {
+                return Ok(i);
+            }
+        }
+        Self::find_bicubic_interval(coords, x)
+    }
+
+    /// Interpolates a batch of points, reusing the interval searches in between points.
+    ///
+    /// The intervals of the previous point are tried first, such that the binary searches are
+    /// skipped for the consecutive points falling in the same cells, as is typically the case
+    /// when scanning a kinematic range. Sorting the points instead was found to cost more than
+    /// the searches it saves. The results are identical to the ones of
+    /// [`Strategy2D::interpolate`].
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - The data the strategy was initialized with.
+    /// * `points` - The points, in the coordinates of `data`.
+    ///
+    /// # Returns
+    ///
+    /// The interpolated values, or an `InterpolateError` if a point is out of bounds.
+    pub fn interpolate_batch<D>(
+        &self,
+        data: &InterpData2D<D>,
+        points: &[[f64; 2]],
+    ) -> Result<Vec<f64>, InterpolateError>
+    where
+        D: Data<Elem = f64> + RawDataClone + Clone,
+    {
+        let mut cell = [None; 2];
+        points
+            .iter()
+            .map(|point| self.interpolate_from(data, point, &mut cell))
+            .collect()
+    }
+
+    /// Interpolates a point, trying the interpolation cell of a previous point first.
+    ///
+    /// The results are identical to the ones of [`Strategy2D::interpolate`].
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - The data the strategy was initialized with.
+    /// * `point` - The point, in the coordinates of `data`.
+    /// * `cell` - The lower knot indices of the cell of the previous point, or `None`, which
+    ///   are updated with the ones of `point`.
+    ///
+    /// # Returns
+    ///
+    /// The interpolated value, or an `InterpolateError` if the point is out of bounds.
+    pub fn interpolate_from<D>(
+        &self,
+        data: &InterpData2D<D>,
+        point: &[f64; 2],
+        cell: &mut [Option<usize>; 2],
+    ) -> Result<f64, InterpolateError>
+    where
+        D: Data<Elem = f64> + RawDataClone + Clone,
+    {
+        let [x, y] = *point;
+
+        let x_coords = data.grid[0].as_slice().unwrap();
+        let y_coords = data.grid[1].as_slice().unwrap();
+
+        let i = Self::find_bicubic_interval_from(x_coords, x, cell[0])?;
+        let j = Self::find_bicubic_interval_from(y_coords, y, cell[1])?;
+        *cell = [Some(i), Some(j)];
+
+        let dx = x_coords[i + 1] - x_coords[i];
+        let dy = y_coords[j + 1] - y_coords[j];
+
+        if dx == 0.0 || dy == 0.0 {
+            return Err(InterpolateError::Other("Grid spacing is zero".to_string()));
+        }
+
+        let u = (x - x_coords[i]) / dx;
+        let v = (y - y_coords[j]) / dy;
+
+        Ok(self.interpolate_with_coeffs(data, i, j, u, v))
+    }
+
     /// Cubic interpolation using a passed array of coefficients (a*x^3 + b*x^2 + c*x + d)
     pub fn hermite_cubic_interpolate_from_coeffs(t: f64, coeffs: &[f64; 4]) -> f64 {
-        let x = t;
-        let x2 = x * x;
-        let x3 = x2 * x;
-        coeffs[0] * x3 + coeffs[1] * x2 + coeffs[2] * x + coeffs[3]
+        cubic_polynomial(t, coeffs)
     }
 
     /// Calculates the derivative with respect to x at a given knot.
@@ -300,6 +418,94 @@ impl LogBicubicInterpolation {
         coeffs
     }
 
+    /// Gathers the polynomial coefficients of the four `Q2` rows surrounding the cell `(ix, iq2)`.
+    ///
+    /// The rows `iq2 - 1..=iq2 + 2` are shifted inwards at the edges of the grid, such that the
+    /// stencil always holds four rows. The coefficients are stored transposed, `stencil[k][r]`
+    /// being the `k`-th coefficient of the `r`-th row, so that the rows map onto SIMD lanes.
+    ///
+    /// # Returns
+    ///
+    /// The index of the first row of the stencil and the transposed coefficients.
+    fn gather_stencil(&self, nq2knots: usize, ix: usize, iq2: usize) -> (usize, [[f64; 4]; 4]) {
+        let first = iq2.saturating_sub(1).min(nq2knots - 4);
+        let mut stencil = [[0.0; 4]; 4];
+        for (r, iq2) in (first..first + 4).enumerate() {
+            let base_idx = (ix * nq2knots + iq2) * 4;
+            for (k, &coeff) in self.coeffs[base_idx..base_idx + 4].iter().enumerate() {
+                stencil[k][r] = coeff;
+            }
+        }
+        (first, stencil)
+    }
+
+    /// Evaluates the four cubic polynomials of a stencil at `t`.
+    ///
+    /// With the `simd` feature the rows are evaluated as SIMD lanes, otherwise one after the
+    /// other with [`Self::apply_weights_scalar`].
+    ///
+    /// # Arguments
+    ///
+    /// * `stencil` - The transposed coefficients, `stencil[k][r]` being the coefficient of
+    ///   `t^(3 - k)` of the `r`-th polynomial.
+    /// * `t` - The point at which the polynomials are evaluated.
+    ///
+    /// # Returns
+    ///
+    /// The values of the four polynomials.
+    pub fn apply_weights(stencil: &[[f64; 4]; 4], t: f64) -> [f64; 4] {
+        #[cfg(feature = "simd")]
+        let values = Self::apply_weights_simd(stencil, t);
+        #[cfg(not(feature = "simd"))]
+        let values = Self::apply_weights_scalar(stencil, t);
+
+        values
+    }
+
+    /// Evaluates the four cubic polynomials of a stencil at `t`, one after the other.
+    ///
+    /// This is the reference for [`Self::apply_weights`], each polynomial being evaluated with
+    /// [`Self::hermite_cubic_interpolate_from_coeffs`].
+    pub fn apply_weights_scalar(stencil: &[[f64; 4]; 4], t: f64) -> [f64; 4] {
+        std::array::from_fn(|r| {
+            Self::hermite_cubic_interpolate_from_coeffs(
+                t,
+                &[stencil[0][r], stencil[1][r], stencil[2][r], stencil[3][r]],
+            )
+        })
+    }
+
+    /// Evaluates the four cubic polynomials of a stencil at `t` as SIMD lanes.
+    ///
+    /// As `std::simd` is not available on stable Rust, the lanes are written as `[f64; 4]`
+    /// arrays combined element-wise, which the compiler lowers to packed instructions. The
+    /// operations are the ones of [`Self::apply_weights_scalar`] in the same order, including
+    /// the fused multiply-adds, such that the results are identical.
+    #[cfg(feature = "simd")]
+    pub fn apply_weights_simd(stencil: &[[f64; 4]; 4], t: f64) -> [f64; 4] {
+        #[cfg(all(target_feature = "fma", not(feature = "deterministic")))]
+        let values = {
+            let fma = |a: [f64; 4], b: [f64; 4]| -> [f64; 4] {
+                std::array::from_fn(|r| a[r].mul_add(t, b[r]))
+            };
+            fma(fma(fma(stencil[0], stencil[1]), stencil[2]), stencil[3])
+        };
+        #[cfg(not(all(target_feature = "fma", not(feature = "deterministic"))))]
+        let values = {
+            let t2 = t * t;
+            let t3 = t2 * t;
+
+            let mul = |lanes: [f64; 4], w: f64| lanes.map(|c| c * w);
+            let add =
+                |a: [f64; 4], b: [f64; 4]| -> [f64; 4] { std::array::from_fn(|r| a[r] + b[r]) };
+
+            let cubic = add(mul(stencil[0], t3), mul(stencil[1], t2));
+            add(add(cubic, mul(stencil[2], t)), stencil[3])
+        };
+
+        values
+    }
+
     /// Performs bicubic interpolation using pre-computed coefficients.
     fn interpolate_with_coeffs<D>(
         &self,
@@ -313,21 +519,14 @@ impl LogBicubicInterpolation {
         D: Data<Elem = f64> + RawDataClone + Clone,
     {
         let nq2knots = data.grid[1].len();
-
-        let base_idx_vl = (ix * nq2knots + iq2) * 4;
-        let coeffs_vl: [f64; 4] = self.coeffs[base_idx_vl..base_idx_vl + 4]
-            .try_into()
-            .unwrap();
-        let vl = Self::hermite_cubic_interpolate_from_coeffs(u, &coeffs_vl);
-
-        let base_idx_vh = (ix * nq2knots + iq2 + 1) * 4;
-        let coeffs_vh: [f64; 4] = self.coeffs[base_idx_vh..base_idx_vh + 4]
-            .try_into()
-            .unwrap();
-        let vh = Self::hermite_cubic_interpolate_from_coeffs(u, &coeffs_vh);
-
         let q2_grid: &[f64] = data.grid[1].as_slice().unwrap();
 
+        let (first, stencil) = self.gather_stencil(nq2knots, ix, iq2);
+        let rows = Self::apply_weights(&stencil, u);
+        let offset = iq2 - first;
+        let vl = rows[offset];
+        let vh = rows[offset + 1];
+
         let dq_1 = q2_grid[iq2 + 1] - q2_grid[iq2];
 
         let vdl: f64;
@@ -335,35 +534,19 @@ impl LogBicubicInterpolation {
 
         if iq2 == 0 {
             vdl = vh - vl;
-            let vhh_base_idx = (ix * nq2knots + iq2 + 2) * 4;
-            let coeffs_vhh: [f64; 4] = self.coeffs[vhh_base_idx..vhh_base_idx + 4]
-                .try_into()
-                .unwrap();
-            let vhh = Self::hermite_cubic_interpolate_from_coeffs(u, &coeffs_vhh);
+            let vhh = rows[offset + 2];
             let dq_2 = 1.0 / (q2_grid[iq2 + 2] - q2_grid[iq2 + 1]);
             vdh = (vdl + (vhh - vh) * dq_1 * dq_2) * 0.5;
         } else if iq2 == nq2knots - 2 {
             vdh = vh - vl;
-            let vll_base_idx = (ix * nq2knots + iq2 - 1) * 4;
-            let coeffs_vll: [f64; 4] = self.coeffs[vll_base_idx..vll_base_idx + 4]
-                .try_into()
-                .unwrap();
-            let vll = Self::hermite_cubic_interpolate_from_coeffs(u, &coeffs_vll);
+            let vll = rows[offset - 1];
             let dq_0 = 1.0 / (q2_grid[iq2] - q2_grid[iq2 - 1]);
             vdl = (vdh + (vl - vll) * dq_1 * dq_0) * 0.5;
         } else {
-            let vll_base_idx = (ix * nq2knots + iq2 - 1) * 4;
-            let coeffs_vll: [f64; 4] = self.coeffs[vll_base_idx..vll_base_idx + 4]
-                .try_into()
-                .unwrap();
-            let vll = Self::hermite_cubic_interpolate_from_coeffs(u, &coeffs_vll);
+            let vll = rows[offset - 1];
             let dq_0 = 1.0 / (q2_grid[iq2] - q2_grid[iq2 - 1]);
 
-            let vhh_base_idx = (ix * nq2knots + iq2 + 2) * 4;
-            let coeffs_vhh: [f64; 4] = self.coeffs[vhh_base_idx..vhh_base_idx + 4]
-                .try_into()
-                .unwrap();
-            let vhh = Self::hermite_cubic_interpolate_from_coeffs(u, &coeffs_vhh);
+            let vhh = rows[offset + 2];
             let dq_2 = 1.0 / (q2_grid[iq2 + 2] - q2_grid[iq2 + 1]);
 
             vdl = ((vh - vl) + (vl - vll) * dq_1 * dq_0) * 0.5;
@@ -397,27 +580,7 @@ where
         data: &InterpData2D<D>,
         point: &[f64; 2],
     ) -> Result<f64, InterpolateError> {
-        let [x, y] = *point;
-
-        let x_coords = data.grid[0].as_slice().unwrap();
-        let y_coords = data.grid[1].as_slice().unwrap();
-
-        let i = Self::find_bicubic_interval(x_coords, x)?;
-        let j = Self::find_bicubic_interval(y_coords, y)?;
-
-        let dx = x_coords[i + 1] - x_coords[i];
-        let dy = y_coords[j + 1] - y_coords[j];
-
-        if dx == 0.0 || dy == 0.0 {
-            return Err(InterpolateError::Other("Grid spacing is zero".to_string()));
-        }
-
-        let u = (x - x_coords[i]) / dx;
-        let v = (y - y_coords[j]) / dy;
-
-        let result = self.interpolate_with_coeffs(data, i, j, u, v);
-
-        Ok(result)
+        self.interpolate_from(data, point, &mut [None; 2])
     }
 
     fn allow_extrapolate(&self) -> bool {
@@ -448,10 +611,7 @@ impl LogTricubicInterpolation {
 
     /// Cubic interpolation using a passed array of coefficients (a*x^3 + b*x^2 + c*x + d)
     pub fn hermite_cubic_interpolate_from_coeffs(t: f64, coeffs: &[f64; 4]) -> f64 {
-        let x = t;
-        let x2 = x * x;
-        let x3 = x2 * x;
-        coeffs[0] * x3 + coeffs[1] * x2 + coeffs[2] * x + coeffs[3]
+        cubic_polynomial(t, coeffs)
     }
 
     /// Calculates the derivative with respect to x at a given knot.
@@ -829,6 +989,41 @@ where
     }
 }
 
+/// The tolerance on the knots, mapped onto `[-1, 1]`, with respect to the Chebyshev-Gauss-Lobatto
+/// points expected by the Chebyshev interpolations.
+const CHEBYSHEV_NODE_TOLERANCE: f64 = 1e-8;
+
+/// Returns the `n` Chebyshev-Gauss-Lobatto points `cos(pi * j / (n - 1))` in increasing order.
+///
+/// # Panics
+///
+/// Panics if `n` is smaller than 2.
+pub fn chebyshev_lobatto_points(n: usize) -> Vec<f64> {
+    assert!(n >= 2, "At least 2 Chebyshev points are required");
+    (0..n)
+        .map(|j| (PI * (n - 1 - j) as f64 / (n - 1) as f64).cos())
+        .collect()
+}
+
+/// Checks that the knots are Chebyshev-Gauss-Lobatto points once mapped onto `[-1, 1]`, and
+/// returns these points.
+fn chebyshev_t_coords(coords: &[f64]) -> Result<Vec<f64>, ValidateError> {
+    let t_coords = chebyshev_lobatto_points(coords.len());
+    let (min, max) = (coords[0], coords[coords.len() - 1]);
+    let compatible = coords.iter().zip(&t_coords).all(|(&coord, &t)| {
+        (2.0 * (coord - min) / (max - min) - 1.0 - t).abs() <= CHEBYSHEV_NODE_TOLERANCE
+    });
+    if !compatible {
+        return Err(ValidateError::Other(
+            "The knots are not Chebyshev-Gauss-Lobatto points in log space; resample the \
+             subgrid with `SubGrid::to_chebyshev_nodes`."
+                .to_string(),
+        ));
+    }
+
+    Ok(t_coords)
+}
+
 /// Implements a global N-dimensional interpolation using Chebyshev polynomials with logarithmic
 /// coordinate scaling.
 ///
@@ -971,9 +1166,7 @@ where
             ));
         }
 
-        self.t_coords[0] = (0..n)
-            .map(|j| (PI * (n - 1 - j) as f64 / (n - 1) as f64).cos())
-            .collect();
+        self.t_coords[0] = chebyshev_t_coords(x_coords)?;
 
         self.weights[0] = Self::compute_barycentric_weights(n);
 
@@ -1024,9 +1217,7 @@ where
                         .to_string(),
                 ));
             }
-            self.t_coords[dim] = (0..n)
-                .map(|j| (PI * (n - 1 - j) as f64 / (n - 1) as f64).cos())
-                .collect();
+            self.t_coords[dim] = chebyshev_t_coords(x_coords)?;
             self.weights[dim] = Self::compute_barycentric_weights(n);
         }
         Ok(())
@@ -1081,9 +1272,7 @@ where
                         .to_string(),
                 ));
             }
-            self.t_coords[dim] = (0..n)
-                .map(|j| (PI * (n - 1 - j) as f64 / (n - 1) as f64).cos())
-                .collect();
+            self.t_coords[dim] = chebyshev_t_coords(x_coords)?;
             self.weights[dim] = Self::compute_barycentric_weights(n);
         }
         Ok(())
@@ -1262,9 +1451,7 @@ impl LogChebyshevBatchInterpolation<1> {
             ));
         }
 
-        self.t_coords[0] = (0..n)
-            .map(|j| (PI * (n - 1 - j) as f64 / (n - 1) as f64).cos())
-            .collect();
+        self.t_coords[0] = chebyshev_t_coords(x_coords)?;
 
         self.weights[0] = Self::compute_barycentric_weights(n);
 
@@ -1311,9 +1498,7 @@ impl LogChebyshevBatchInterpolation<2> {
                         .to_string(),
                 ));
             }
-            self.t_coords[dim] = (0..n)
-                .map(|j| (PI * (n - 1 - j) as f64 / (n - 1) as f64).cos())
-                .collect();
+            self.t_coords[dim] = chebyshev_t_coords(x_coords)?;
             self.weights[dim] = Self::compute_barycentric_weights(n);
         }
 
@@ -1366,9 +1551,7 @@ impl LogChebyshevBatchInterpolation<3> {
                         .to_string(),
                 ));
             }
-            self.t_coords[dim] = (0..n)
-                .map(|j| (PI * (n - 1 - j) as f64 / (n - 1) as f64).cos())
-                .collect();
+            self.t_coords[dim] = chebyshev_t_coords(x_coords)?;
             self.weights[dim] = Self::compute_barycentric_weights(n);
         }
         Ok(())
@@ -1690,6 +1873,54 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_log_bicubic_stencil() {
+        let knots: Vec<f64> = (0..6).map(|i| f64::from(i).exp()).collect();
+        let values = (0..36).map(|i| f64::from(i).sin()).collect();
+        let data = create_test_data_2d(knots.clone(), knots, values);
+
+        let mut log_bicubic = LogBicubicInterpolation::default();
+        log_bicubic.init(&data).unwrap();
+
+        // The stencil is shifted inwards at the edges of the grid
+        for (iq2, expected_first) in [(0, 0), (1, 0), (2, 1), (3, 2), (4, 2)] {
+            let (first, stencil) = log_bicubic.gather_stencil(6, 2, iq2);
+            assert_eq!(first, expected_first);
+            for r in 0..4 {
+                let base_idx = (2 * 6 + first + r) * 4;
+                let coeffs = &log_bicubic.coeffs[base_idx..base_idx + 4];
+                assert!(stencil.iter().zip(coeffs).all(|(lanes, &c)| lanes[r] == c));
+            }
+        }
+    }
+
+    #[test]
+    fn test_apply_weights_parity() {
+        let stencil = [
+            [0.3, -1.2, 2.5e-3, 7.0],
+            [-2.0, 0.45, 1e4, -0.1],
+            [1.0 / 3.0, 8.5, -6.25, 1e-7],
+            [4.0, -0.75, 0.0, 2.0 / 7.0],
+        ];
+
+        for t in [0.0, 0.1, 1.0 / 3.0, 0.5, 0.999, 1.0, -0.2, 1.3] {
+            let scalar = LogBicubicInterpolation::apply_weights_scalar(&stencil, t);
+            for (r, &value) in scalar.iter().enumerate() {
+                let coeffs = [stencil[0][r], stencil[1][r], stencil[2][r], stencil[3][r]];
+                let expected =
+                    LogBicubicInterpolation::hermite_cubic_interpolate_from_coeffs(t, &coeffs);
+                assert_eq!(value, expected);
+            }
+            assert_eq!(LogBicubicInterpolation::apply_weights(&stencil, t), scalar);
+
+            #[cfg(feature = "simd")]
+            assert_eq!(
+                LogBicubicInterpolation::apply_weights_simd(&stencil, t),
+                scalar
+            );
+        }
+    }
+
     #[test]
     fn test_log_bicubic_interpolation() {
         let target_data = create_target_data_2d(4);
@@ -1714,6 +1945,34 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_log_bicubic_boundary_nodes() {
+        let knots = vec![1.0f64.ln(), 10.0f64.ln(), 100.0f64.ln(), 1000.0f64.ln()];
+        let data = create_test_data_2d(knots.clone(), knots.clone(), create_target_data_2d(4));
+
+        let mut log_bicubic = LogBicubicInterpolation::default();
+        log_bicubic.init(&data).unwrap();
+
+        let (first, last) = (knots[0], knots[3]);
+        let test_cases = [
+            ([first, last], 4.0),
+            ([knots[2], last], 12.0),
+            ([last, last], 16.0),
+            ([first, first], 1.0),
+            ([first, knots[2]], 3.0),
+        ];
+        for (point, expected) in test_cases {
+            let result = log_bicubic.interpolate(&data, &point).unwrap();
+            assert_close(result, expected, EPSILON);
+        }
+
+        // The values grow with Q2, including in the last cell
+        let below = log_bicubic
+            .interpolate(&data, &[first, last - 1e-6])
+            .unwrap();
+        assert!(below.is_finite() && below < 4.0);
+    }
+
     #[test]
     fn test_ddlogq_derivatives() {
         let data = create_test_data_1d(
@@ -1852,6 +2111,21 @@ mod tests {
         assert_close(result, expected, EPSILON);
     }
 
+    #[test]
+    fn test_log_chebyshev_rejects_non_chebyshev_knots() {
+        let coords: Vec<f64> = (0..6).map(|i| f64::from(i) / 5.0).collect();
+        let values = vec![1.0; 36];
+        let data = create_test_data_2d(coords.clone(), coords, values);
+
+        let mut cheby = LogChebyshevInterpolation::<2>::default();
+        assert!(matches!(
+            cheby.init(&data),
+            Err(ValidateError::Other(message)) if message.contains("to_chebyshev_nodes")
+        ));
+        let mut batch = LogChebyshevBatchInterpolation::<2>::default();
+        assert!(batch.init(&data).is_err());
+    }
+
     #[test]
     fn test_log_chebyshev_interpolation_3d() {
         let n = 7;
@@ -5,14 +5,153 @@
 //! - [`ParamRange`], [`RangeParameters`]: Parameter range types for grid axes.
 //! - [`SubGrid`]: Represents a region of phase space with a consistent grid and provides
 //!   methods for subgrid logic.
+//! - [`SubGridBuilder`]: Incremental construction of a subgrid from blocks of values.
+//! - [`ShapeError`]: Inconsistencies between the grid values and the axes of a subgrid.
+//! - [`SerializationError`]: Failures of the binary (de)serialization of a subgrid.
+//! - [`Spacing`]: The spacing of the knots of an axis.
+//! - [`select_subgrid`]: Selection of the subgrid used to evaluate a point.
 
-use ndarray::{s, Array1, Array6, ArrayView2};
+use ndarray::{s, Array1, Array2, Array6, ArrayView2};
+use ninterp::data::InterpData2D;
+use ninterp::interpolator::{Extrapolate, Interp2D};
+use ninterp::prelude::*;
+use ninterp::strategy::traits::Strategy2D;
 use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::io::{self, BufRead, Write};
+use thiserror::Error as ThisError;
 
-use super::interpolator::InterpolationConfig;
+use super::gridpdf::Error;
+use super::interpolator::{Axis, InterpolationConfig};
+use super::strategy::{chebyshev_lobatto_points, LogBicubicInterpolation};
+
+/// Errors reported by [`SubGrid::try_new`], [`SubGrid::validate_grid_shape`],
+/// [`SubGrid::validate`] and [`SubGridBuilder`].
+#[derive(Debug, PartialEq, Eq, ThisError)]
+pub enum ShapeError {
+    /// Error indicating that the grid does not have one entry per knot along an axis.
+    #[error("The {axis:?} axis has {knots} knots but the grid has {entries} entries along it")]
+    AxisMismatch {
+        /// The mismatched axis.
+        axis: Axis,
+        /// The number of knots of the axis.
+        knots: usize,
+        /// The number of entries of the grid along the axis.
+        entries: usize,
+    },
+    /// Error indicating that the grid does not hold any flavor.
+    #[error("The grid does not hold any flavor")]
+    NoFlavors,
+    /// Error indicating that the number of values does not match the size of the grid.
+    #[error("The grid holds {expected} values but {found} were provided")]
+    ElementCount {
+        /// The number of values of the grid.
+        expected: usize,
+        /// The number of values provided.
+        found: usize,
+    },
+    /// Error indicating that the knots of an axis are not increasing.
+    #[error("The {axis:?} knot at index {index} is not larger than the previous one")]
+    NonMonotonicAxis {
+        /// The axis with decreasing (or NaN) knots.
+        axis: Axis,
+        /// The index of the first offending knot.
+        index: usize,
+    },
+    /// Error indicating that an axis contains the same knot twice in a row.
+    #[error("The {axis:?} knot at index {index} duplicates the previous one")]
+    DuplicateKnot {
+        /// The axis with duplicate knots.
+        axis: Axis,
+        /// The index of the first duplicate knot.
+        index: usize,
+    },
+    /// Error indicating that the grid holds a NaN or infinite value.
+    #[error("The grid value at {index:?} is not finite")]
+    NonFiniteValue {
+        /// The index of the value, in the layout `[nucleons, alphas, pids, kT, x, Q²]`.
+        index: [usize; 6],
+    },
+    /// Error indicating that the flat grid data does not fit the axes of a new subgrid.
+    #[error(
+        "The grid of shape {shape:?} (nucleons, alphas, kT, x, Q², flavors) holds {expected} \
+         values but {found} were provided"
+    )]
+    DataLength {
+        /// The shape of the grid, in the order of the flat grid data.
+        shape: [usize; 6],
+        /// The number of values of the grid.
+        expected: usize,
+        /// The number of values provided.
+        found: usize,
+    },
+}
+
+/// Errors reported by the binary (de)serialization of a [`SubGrid`].
+#[derive(Debug, ThisError)]
+pub enum SerializationError {
+    /// Error indicating that the subgrid could not be encoded or decoded with bincode.
+    #[error("Failed to (de)serialize the subgrid: {0}")]
+    Bincode(#[from] bincode::Error),
+    /// Error indicating that the subgrid could not be compressed or decompressed.
+    #[cfg(feature = "compression")]
+    #[error("Failed to (de)compress the subgrid: {0}")]
+    Compression(#[from] std::io::Error),
+}
+
+/// Errors reported by [`SubGrid::locate_point`] for points outside of a subgrid.
+#[derive(Debug, Clone, Copy, PartialEq, ThisError)]
+pub enum OutOfRange {
+    /// Error indicating that the point does not have one coordinate per axis of the subgrid.
+    #[error("The subgrid expects {expected} coordinates but the point has {found}")]
+    Dimensions {
+        /// The number of axes of the subgrid.
+        expected: usize,
+        /// The number of coordinates of the point.
+        found: usize,
+    },
+    /// Error indicating that a coordinate is outside of the range of its axis.
+    #[error("{axis:?} = {value} is outside of the subgrid range [{}, {}]", range.min, range.max)]
+    Axis {
+        /// The first axis along which the point is outside of the subgrid.
+        axis: Axis,
+        /// The coordinate of the point along `axis`.
+        value: f64,
+        /// The range of the subgrid along `axis`.
+        range: ParamRange,
+    },
+}
+
+/// The axes of a subgrid, in the order of the columns of [`SubGrid::to_flat_text`].
+const FLAT_TEXT_AXES: [Axis; 5] = [Axis::Nucleons, Axis::AlphaS, Axis::Kt, Axis::X, Axis::Q2];
+
+/// Returns the name of an axis in the header of [`SubGrid::to_flat_text`].
+fn flat_text_name(axis: Axis) -> &'static str {
+    match axis {
+        Axis::Nucleons => "nucleons",
+        Axis::AlphaS => "alphas",
+        Axis::Kt => "kt",
+        Axis::X => "x",
+        Axis::Q2 => "q2",
+    }
+}
+
+/// The relative tolerance on the steps between knots used by [`SubGrid::axis_spacing`].
+const SPACING_TOLERANCE: f64 = 1e-6;
+
+/// Represents the spacing of the knots of an axis, see [`SubGrid::axis_spacing`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Spacing {
+    /// The knots are equally spaced.
+    Linear,
+    /// The logarithms of the knots are equally spaced.
+    Log,
+    /// The knots follow neither a linear nor a logarithmic spacing.
+    Irregular,
+}
 
 /// Represents the valid range of a parameter, with a minimum and maximum value.
-#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize, Serialize)]
 pub struct ParamRange {
     /// The minimum value of the parameter.
     pub min: f64,
@@ -43,6 +182,38 @@ impl ParamRange {
     pub fn contains(&self, value: f64) -> bool {
         value >= self.min && value <= self.max
     }
+
+    /// Restricts a value to the parameter range.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The value to clamp.
+    ///
+    /// # Returns
+    ///
+    /// The closest value within the range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is inverted, i.e. if `min > max`.
+    pub fn clamp(&self, value: f64) -> f64 {
+        value.clamp(self.min, self.max)
+    }
+
+    /// Computes the overlap of two parameter ranges.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The range to intersect with.
+    ///
+    /// # Returns
+    ///
+    /// The common range, which is a single point for touching ranges, or `None` if the
+    /// ranges are disjoint.
+    pub fn intersect(&self, other: &ParamRange) -> Option<ParamRange> {
+        let (min, max) = (self.min.max(other.min), self.max.min(other.max));
+        (min <= max).then(|| ParamRange::new(min, max))
+    }
 }
 
 /// Represents the parameter ranges for `x` and `q2`.
@@ -90,7 +261,7 @@ impl RangeParameters {
 ///
 /// A subgrid represents a region of the phase space with a consistent
 /// grid of `x` and `Q²` values.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SubGrid {
     /// Array of `x` values (momentum fraction).
     pub xs: Array1<f64>,
@@ -131,7 +302,8 @@ impl SubGrid {
     ///
     /// # Panics
     ///
-    /// Panics if the grid data cannot be reshaped to the expected dimensions.
+    /// Panics if the grid data cannot be reshaped to the expected dimensions and, in debug
+    /// builds, if the subgrid is not valid, see [`SubGrid::try_new`] for the fallible version.
     pub fn new(
         nucleon_numbers: Vec<f64>,
         alphas_values: Vec<f64>,
@@ -140,6 +312,108 @@ impl SubGrid {
         q2_subgrid: Vec<f64>,
         nflav: usize,
         grid_data: Vec<f64>,
+    ) -> Self {
+        let built = Self::from_flat_data(
+            nucleon_numbers,
+            alphas_values,
+            kt_subgrid,
+            x_subgrid,
+            q2_subgrid,
+            nflav,
+            grid_data,
+        )
+        .unwrap_or_else(|err| panic!("Failed to create grid: {err}"));
+        debug_assert_eq!(built.validate(), Ok(()), "Invalid subgrid");
+
+        built
+    }
+
+    /// Creates a new `SubGrid` from vectors of data, checking the grid data and the knots.
+    ///
+    /// # Arguments
+    ///
+    /// The same as for [`SubGrid::new`].
+    ///
+    /// # Returns
+    ///
+    /// The `SubGrid`, `ShapeError::DataLength` with the shape of the grid if `grid_data`
+    /// does not hold exactly one value per knot and flavor, or the error of
+    /// [`SubGrid::validate`] if the subgrid cannot be interpolated.
+    pub fn try_new(
+        nucleon_numbers: Vec<f64>,
+        alphas_values: Vec<f64>,
+        kt_subgrid: Vec<f64>,
+        x_subgrid: Vec<f64>,
+        q2_subgrid: Vec<f64>,
+        nflav: usize,
+        grid_data: Vec<f64>,
+    ) -> Result<Self, ShapeError> {
+        let built = Self::from_flat_data(
+            nucleon_numbers,
+            alphas_values,
+            kt_subgrid,
+            x_subgrid,
+            q2_subgrid,
+            nflav,
+            grid_data,
+        )?;
+        built.validate()?;
+
+        Ok(built)
+    }
+
+    /// Creates a new `SubGrid` from vectors of data, only checking the length of the grid data.
+    fn from_flat_data(
+        nucleon_numbers: Vec<f64>,
+        alphas_values: Vec<f64>,
+        kt_subgrid: Vec<f64>,
+        x_subgrid: Vec<f64>,
+        q2_subgrid: Vec<f64>,
+        nflav: usize,
+        grid_data: Vec<f64>,
+    ) -> Result<Self, ShapeError> {
+        let shape = [
+            nucleon_numbers.len(),
+            alphas_values.len(),
+            kt_subgrid.len(),
+            x_subgrid.len(),
+            q2_subgrid.len(),
+            nflav,
+        ];
+        let expected = shape.iter().product();
+        if grid_data.len() != expected {
+            return Err(ShapeError::DataLength {
+                shape,
+                expected,
+                found: grid_data.len(),
+            });
+        }
+
+        let subgrid = Array6::from_shape_vec(shape, grid_data)
+            .expect("The length of the grid data has been checked")
+            .permuted_axes([0, 1, 5, 2, 3, 4])
+            .as_standard_layout()
+            .to_owned();
+
+        Ok(Self::from_axes(
+            nucleon_numbers,
+            alphas_values,
+            kt_subgrid,
+            x_subgrid,
+            q2_subgrid,
+            subgrid,
+        ))
+    }
+
+    /// Creates a new `SubGrid` from its axes and its grid, already in the internal layout
+    /// `[nucleons, alphas, pids, kT, x, Q²]`.
+    fn from_axes(
+        nucleon_numbers: Vec<f64>,
+        alphas_values: Vec<f64>,
+        kt_subgrid: Vec<f64>,
+        x_subgrid: Vec<f64>,
+        q2_subgrid: Vec<f64>,
+        subgrid: Array6<f64>,
     ) -> Self {
         let xs_range = ParamRange::new(*x_subgrid.first().unwrap(), *x_subgrid.last().unwrap());
         let q2s_range = ParamRange::new(*q2_subgrid.first().unwrap(), *q2_subgrid.last().unwrap());
@@ -153,22 +427,6 @@ impl SubGrid {
             *alphas_values.last().unwrap(),
         );
 
-        let subgrid = Array6::from_shape_vec(
-            (
-                nucleon_numbers.len(),
-                alphas_values.len(),
-                kt_subgrid.len(),
-                x_subgrid.len(),
-                q2_subgrid.len(),
-                nflav,
-            ),
-            grid_data,
-        )
-        .expect("Failed to create grid")
-        .permuted_axes([0, 1, 5, 2, 3, 4])
-        .as_standard_layout()
-        .to_owned();
-
         Self {
             xs: Array1::from_vec(x_subgrid),
             q2s: Array1::from_vec(q2_subgrid),
@@ -186,6 +444,8 @@ impl SubGrid {
 
     /// Checks if a point (..., `x`, `q2`) is within the boundaries of this subgrid.
     ///
+    /// See [`SubGrid::locate_point`] to find out why a point is not contained.
+    ///
     /// # Arguments
     ///
     /// * `points` - A slice of coordinates. The order is assumed to be
@@ -196,29 +456,37 @@ impl SubGrid {
     ///
     /// `true` if the point is within the subgrid, `false` otherwise.
     pub fn contains_point(&self, points: &[f64]) -> bool {
-        let (expected_len, ranges) = match self.interpolation_config() {
-            InterpolationConfig::TwoD => (2, vec![]),
-            InterpolationConfig::ThreeDNucleons => (3, vec![&self.nucleons_range]),
-            InterpolationConfig::ThreeDAlphas => (3, vec![&self.alphas_range]),
-            InterpolationConfig::ThreeDKt => (3, vec![&self.kt_range]),
-            InterpolationConfig::FourDNucleonsAlphas => {
-                (4, vec![&self.nucleons_range, &self.alphas_range])
-            }
-            InterpolationConfig::FourDNucleonsKt => (4, vec![&self.nucleons_range, &self.kt_range]),
-            InterpolationConfig::FourDAlphasKt => (4, vec![&self.alphas_range, &self.kt_range]),
-            InterpolationConfig::FiveD => (
-                5,
-                vec![&self.nucleons_range, &self.alphas_range, &self.kt_range],
-            ),
-        };
+        self.locate_point(points).is_ok()
+    }
 
-        points.len() == expected_len
-            && self.x_range.contains(points[expected_len - 2])
-            && self.q2_range.contains(points[expected_len - 1])
-            && ranges
-                .iter()
-                .zip(points)
-                .all(|(range, &point)| range.contains(point))
+    /// Checks if a point (..., `x`, `q2`) is within the boundaries of this subgrid, reporting
+    /// the offending axis otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `points` - A slice of coordinates, in the order of [`SubGrid::contains_point`].
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if the point is within the subgrid, or an `OutOfRange` naming the first axis
+    /// along which it is outside, with the coordinate and the range of the subgrid.
+    pub fn locate_point(&self, points: &[f64]) -> Result<(), OutOfRange> {
+        let axes = self.interpolation_config().axis_order();
+        if points.len() != axes.len() {
+            return Err(OutOfRange::Dimensions {
+                expected: axes.len(),
+                found: points.len(),
+            });
+        }
+
+        axes.into_iter().zip(points).try_for_each(|(axis, &value)| {
+            let range = self.axis_range(axis);
+            if range.contains(value) {
+                Ok(())
+            } else {
+                Err(OutOfRange::Axis { axis, value, range })
+            }
+        })
     }
 
     /// Calculates the squared distance from a point to the subgrid's bounding box.
@@ -226,32 +494,31 @@ impl SubGrid {
         self.parameter_ranges()
             .iter()
             .zip(points)
-            .map(|(range, &point)| match point {
-                p if p < range.min => (range.min - p) * (range.min - p),
-                p if p > range.max => (p - range.max) * (p - range.max),
-                _ => 0.0,
+            .map(|(range, &point)| {
+                let distance = point - range.clamp(point);
+                distance * distance
             })
             .sum()
     }
 
     /// Gathers the parameter ranges for the subgrid based on its configuration.
     fn parameter_ranges(&self) -> Vec<ParamRange> {
-        let mut ranges = match self.interpolation_config() {
-            InterpolationConfig::TwoD => vec![],
-            InterpolationConfig::ThreeDNucleons => vec![self.nucleons_range],
-            InterpolationConfig::ThreeDAlphas => vec![self.alphas_range],
-            InterpolationConfig::ThreeDKt => vec![self.kt_range],
-            InterpolationConfig::FourDNucleonsAlphas => {
-                vec![self.nucleons_range, self.alphas_range]
-            }
-            InterpolationConfig::FourDNucleonsKt => vec![self.nucleons_range, self.kt_range],
-            InterpolationConfig::FourDAlphasKt => vec![self.alphas_range, self.kt_range],
-            InterpolationConfig::FiveD => {
-                vec![self.nucleons_range, self.alphas_range, self.kt_range]
-            }
-        };
-        ranges.extend([self.x_range, self.q2_range]);
-        ranges
+        self.interpolation_config()
+            .axis_order()
+            .into_iter()
+            .map(|axis| self.axis_range(axis))
+            .collect()
+    }
+
+    /// Returns the range of the subgrid along an axis.
+    fn axis_range(&self, axis: Axis) -> ParamRange {
+        match axis {
+            Axis::Nucleons => self.nucleons_range,
+            Axis::AlphaS => self.alphas_range,
+            Axis::Kt => self.kt_range,
+            Axis::X => self.x_range,
+            Axis::Q2 => self.q2_range,
+        }
     }
 
     /// Gets the interpolation configuration for this subgrid.
@@ -259,6 +526,395 @@ impl SubGrid {
         InterpolationConfig::from_dimensions(self.nucleons.len(), self.alphas.len(), self.kts.len())
     }
 
+    /// Interpolates a flavor of a 2D subgrid at a batch of points with the log-bicubic
+    /// interpolation.
+    ///
+    /// The log-bicubic coefficients are computed once and the interval searches are reused
+    /// in between consecutive points, which is faster than interpolating them one by one.
+    /// The results are identical to the ones of the `LogBicubic` interpolator built by
+    /// [`InterpolatorFactory::create`], including the clamping of the points outside of
+    /// the subgrid.
+    ///
+    /// [`InterpolatorFactory::create`]: crate::interpolator::InterpolatorFactory::create
+    ///
+    /// # Arguments
+    ///
+    /// * `pid_index` - The index of the flavor.
+    /// * `points` - The `(x, q2)` points.
+    ///
+    /// # Returns
+    ///
+    /// The values at `points`, in the same order, or `Error::InterpolationError` if the
+    /// subgrid is not 2D or has fewer than four knots in `x` or `q2`.
+    pub fn interpolate_batch(
+        &self,
+        pid_index: usize,
+        points: &[[f64; 2]],
+    ) -> Result<Vec<f64>, Error> {
+        if !matches!(self.interpolation_config(), InterpolationConfig::TwoD) {
+            return Err(Error::InterpolationError(
+                "batch interpolation is only available for 2D subgrids".to_string(),
+            ));
+        }
+
+        let data = InterpData2D::new(
+            self.xs.mapv(f64::ln),
+            self.q2s.mapv(f64::ln),
+            self.grid_slice(pid_index).to_owned(),
+        )
+        .map_err(|e| Error::InterpolationError(e.to_string()))?;
+        let mut strategy = LogBicubicInterpolation::default();
+        strategy
+            .init(&data)
+            .map_err(|e| Error::InterpolationError(e.to_string()))?;
+
+        let (ln_x, ln_q2) = (&data.grid[0], &data.grid[1]);
+        let clamp =
+            |value: f64, knots: &Array1<f64>| value.ln().clamp(knots[0], knots[knots.len() - 1]);
+        let log_points: Vec<[f64; 2]> = points
+            .iter()
+            .map(|&[x, q2]| [clamp(x, ln_x), clamp(q2, ln_q2)])
+            .collect();
+
+        strategy
+            .interpolate_batch(&data, &log_points)
+            .map_err(|e| Error::InterpolationError(e.to_string()))
+    }
+
+    /// Returns the knots of the subgrid along an axis.
+    pub(crate) fn knots(&self, axis: Axis) -> &Array1<f64> {
+        match axis {
+            Axis::Nucleons => &self.nucleons,
+            Axis::AlphaS => &self.alphas,
+            Axis::Kt => &self.kts,
+            Axis::X => &self.xs,
+            Axis::Q2 => &self.q2s,
+        }
+    }
+
+    /// Determines the spacing of the knots along an axis.
+    ///
+    /// The log-cubic interpolators assume log-spaced knots in `x` and `Q2`, which can be
+    /// checked with this method before picking an interpolator. The steps between the knots
+    /// (or between their logarithms) are considered constant if they agree within a relative
+    /// tolerance of `1e-6`. Axes with fewer than three knots are reported as `Linear`.
+    ///
+    /// # Arguments
+    ///
+    /// * `axis` - The axis to inspect.
+    ///
+    /// # Returns
+    ///
+    /// The `Spacing` of the knots along `axis`.
+    pub fn axis_spacing(&self, axis: Axis) -> Spacing {
+        let knots = self.knots(axis);
+
+        let equally_spaced = |values: &[f64]| {
+            let steps: Vec<f64> = values.windows(2).map(|pair| pair[1] - pair[0]).collect();
+            let mean = steps.iter().sum::<f64>() / steps.len() as f64;
+            steps
+                .iter()
+                .all(|step| (step - mean).abs() <= SPACING_TOLERANCE * mean.abs())
+        };
+
+        if knots.len() < 3 || equally_spaced(&knots.to_vec()) {
+            Spacing::Linear
+        } else if knots.iter().all(|&knot| knot > 0.0)
+            && equally_spaced(&knots.mapv(f64::ln).to_vec())
+        {
+            Spacing::Log
+        } else {
+            Spacing::Irregular
+        }
+    }
+
+    /// Returns the number of bytes held by the grid values and the knots of this subgrid.
+    ///
+    /// The fixed-size fields (e.g. the parameter ranges) are not included, such that the
+    /// footprint scales with the number of knots.
+    pub fn memory_footprint(&self) -> usize {
+        let num_values = self.grid.len()
+            + self.xs.len()
+            + self.q2s.len()
+            + self.kts.len()
+            + self.nucleons.len()
+            + self.alphas.len();
+        num_values * std::mem::size_of::<f64>()
+    }
+
+    /// Serializes this subgrid with bincode.
+    ///
+    /// The layout is the same for every dimensionality of the subgrid, since the axes that
+    /// are not interpolated are stored as single knots.
+    ///
+    /// # Returns
+    ///
+    /// The serialized bytes, or a `SerializationError` if the encoding fails.
+    pub fn to_bincode(&self) -> Result<Vec<u8>, SerializationError> {
+        Ok(bincode::serialize(self)?)
+    }
+
+    /// Deserializes a subgrid written by [`SubGrid::to_bincode`].
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - The serialized subgrid.
+    ///
+    /// # Returns
+    ///
+    /// The `SubGrid`, or a `SerializationError` if the bytes do not hold a valid subgrid.
+    pub fn from_bincode(bytes: &[u8]) -> Result<Self, SerializationError> {
+        Ok(bincode::deserialize(bytes)?)
+    }
+
+    /// Serializes this subgrid with bincode and compresses the result with zstd.
+    ///
+    /// # Arguments
+    ///
+    /// * `level` - The zstd compression level, where `0` selects the default level.
+    ///
+    /// # Returns
+    ///
+    /// The compressed bytes, or a `SerializationError` if the encoding fails.
+    #[cfg(feature = "compression")]
+    pub fn to_bincode_zstd(&self, level: i32) -> Result<Vec<u8>, SerializationError> {
+        let mut encoder = zstd::Encoder::new(Vec::new(), level)?;
+        bincode::serialize_into(&mut encoder, self)?;
+        Ok(encoder.finish()?)
+    }
+
+    /// Deserializes a subgrid written by [`SubGrid::to_bincode_zstd`].
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - The compressed subgrid.
+    ///
+    /// # Returns
+    ///
+    /// The `SubGrid`, or a `SerializationError` if the bytes do not hold a valid subgrid.
+    #[cfg(feature = "compression")]
+    pub fn from_bincode_zstd(bytes: &[u8]) -> Result<Self, SerializationError> {
+        let decoder = zstd::Decoder::new(bytes)?;
+        Ok(bincode::deserialize_from(decoder)?)
+    }
+
+    /// Writes this subgrid as flat ASCII text, one grid value per row.
+    ///
+    /// The text starts with a header of `#` lines holding the knots of every axis, the number
+    /// of flavors, and the names of the columns. Each row then holds, separated by spaces:
+    ///
+    /// 1. the coordinates along the interpolated axes other than `x` and `Q2`, in the order
+    ///    `nucleons alphas kt` (the axes with a single knot are only listed in the header),
+    /// 2. `x` and `q2`,
+    /// 3. the index of the flavor in the grid (the subgrid does not know the PDG IDs),
+    /// 4. the value.
+    ///
+    /// The rows are ordered as the flat `grid_data` of [`SubGrid::new`], with the flavor
+    /// running fastest, and the numbers are written such that they are parsed back exactly.
+    ///
+    /// # Arguments
+    ///
+    /// * `writer` - The destination of the text.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())`, or the `io::Error` of the writer.
+    pub fn to_flat_text(&self, mut writer: impl Write) -> io::Result<()> {
+        let leading: Vec<Axis> = self
+            .interpolation_config()
+            .axis_order()
+            .into_iter()
+            .filter(|axis| !matches!(axis, Axis::X | Axis::Q2))
+            .collect();
+
+        writeln!(writer, "# NeoPDF subgrid")?;
+        for axis in FLAT_TEXT_AXES {
+            write!(writer, "# {}:", flat_text_name(axis))?;
+            for knot in self.knots(axis) {
+                write!(writer, " {knot:e}")?;
+            }
+            writeln!(writer)?;
+        }
+        writeln!(writer, "# flavors: {}", self.grid.dim().2)?;
+        write!(writer, "# columns:")?;
+        for &axis in &leading {
+            write!(writer, " {}", flat_text_name(axis))?;
+        }
+        writeln!(writer, " x q2 flavor value")?;
+
+        // Iterates in the order of the rows, with the flavor running fastest.
+        let values = self.grid.view().permuted_axes([0, 1, 3, 4, 5, 2]);
+        for ((ia, ias, ikt, ix, iq2, flavor), value) in values.indexed_iter() {
+            for &axis in &leading {
+                let knot = match axis {
+                    Axis::Nucleons => self.nucleons[ia],
+                    Axis::AlphaS => self.alphas[ias],
+                    _ => self.kts[ikt],
+                };
+                write!(writer, "{knot:e} ")?;
+            }
+            writeln!(
+                writer,
+                "{:e} {:e} {flavor} {value:e}",
+                self.xs[ix], self.q2s[iq2]
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Reads a subgrid written by [`SubGrid::to_flat_text`].
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - The source of the text.
+    ///
+    /// # Returns
+    ///
+    /// The `SubGrid`, or an `io::Error` of kind `InvalidData` if the header is incomplete or
+    /// the rows do not match it.
+    pub fn from_flat_text(reader: impl BufRead) -> io::Result<Self> {
+        let invalid = |message: String| io::Error::new(io::ErrorKind::InvalidData, message);
+        let parse = |field: &str| {
+            field
+                .parse::<f64>()
+                .map_err(|_| invalid(format!("Invalid number `{field}`")))
+        };
+
+        let mut knots: [Option<Vec<f64>>; 5] = Default::default();
+        let mut n_flavors = None;
+        let mut columns: Option<Vec<String>> = None;
+        let mut rows = Vec::new();
+        for line in reader.lines() {
+            let line = line?;
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            if let Some(header) = line.strip_prefix('#') {
+                let Some((key, value)) = header.split_once(':') else {
+                    continue;
+                };
+                match key.trim() {
+                    "flavors" => {
+                        n_flavors = Some(value.trim().parse::<usize>().map_err(|_| {
+                            invalid(format!("Invalid number of flavors `{}`", value.trim()))
+                        })?);
+                    }
+                    "columns" => {
+                        columns = Some(value.split_whitespace().map(str::to_owned).collect());
+                    }
+                    name => {
+                        if let Some(i) = FLAT_TEXT_AXES
+                            .iter()
+                            .position(|&axis| flat_text_name(axis) == name)
+                        {
+                            knots[i] = Some(
+                                value
+                                    .split_whitespace()
+                                    .map(parse)
+                                    .collect::<io::Result<_>>()?,
+                            );
+                        }
+                    }
+                }
+                continue;
+            }
+            rows.push(
+                line.split_whitespace()
+                    .map(parse)
+                    .collect::<io::Result<Vec<f64>>>()?,
+            );
+        }
+
+        let mut axes = Vec::with_capacity(FLAT_TEXT_AXES.len());
+        for (axis, knots) in FLAT_TEXT_AXES.into_iter().zip(knots) {
+            match knots {
+                Some(knots) if !knots.is_empty() => axes.push(knots),
+                _ => {
+                    return Err(invalid(format!(
+                        "Missing the knots of the {} axis",
+                        flat_text_name(axis)
+                    )))
+                }
+            }
+        }
+        let n_flavors = n_flavors
+            .filter(|&n| n > 0)
+            .ok_or_else(|| invalid("Missing the number of flavors".to_owned()))?;
+        let columns = columns.ok_or_else(|| invalid("Missing the column names".to_owned()))?;
+
+        let (leading, trailing) = columns.split_at(columns.len().saturating_sub(4));
+        if trailing != ["x", "q2", "flavor", "value"] {
+            return Err(invalid(format!(
+                "The columns must end with `x q2 flavor value`, got `{}`",
+                columns.join(" ")
+            )));
+        }
+        // The positions of the coordinates in the rows, `None` for the axes that only appear in
+        // the header.
+        let mut positions = [None; 5];
+        for (position, name) in columns.iter().enumerate().take(leading.len() + 2) {
+            let i = FLAT_TEXT_AXES
+                .iter()
+                .position(|&axis| flat_text_name(axis) == name)
+                .ok_or_else(|| invalid(format!("Unknown column `{name}`")))?;
+            positions[i] = Some(position);
+        }
+        for ((axis, knots), position) in FLAT_TEXT_AXES.into_iter().zip(&axes).zip(positions) {
+            if knots.len() > 1 && position.is_none() {
+                return Err(invalid(format!(
+                    "The {} axis has several knots but no column",
+                    flat_text_name(axis)
+                )));
+            }
+        }
+
+        let expected = axes.iter().map(Vec::len).product::<usize>() * n_flavors;
+        if rows.len() != expected {
+            return Err(invalid(format!(
+                "The header describes {expected} rows but {} were found",
+                rows.len()
+            )));
+        }
+
+        // The rows must follow the order of `to_flat_text`, with the flavor running fastest.
+        let mut indices = [0; 5];
+        let mut grid_data = Vec::with_capacity(expected);
+        for (number, row) in rows.iter().enumerate() {
+            if row.len() != columns.len() {
+                return Err(invalid(format!(
+                    "Row {number} has {} columns instead of {}",
+                    row.len(),
+                    columns.len()
+                )));
+            }
+            let flavor = number % n_flavors;
+            let mut remainder = number / n_flavors;
+            for i in (0..indices.len()).rev() {
+                indices[i] = remainder % axes[i].len();
+                remainder /= axes[i].len();
+            }
+            let coordinates_match =
+                positions
+                    .iter()
+                    .zip(&axes)
+                    .zip(indices)
+                    .all(|((position, knots), index)| {
+                        position.is_none_or(|position| row[position] == knots[index])
+                    });
+            if !coordinates_match || row[columns.len() - 2] != flavor as f64 {
+                return Err(invalid(format!("Row {number} is out of order")));
+            }
+            grid_data.push(row[columns.len() - 1]);
+        }
+
+        let [nucleons, alphas, kts, xs, q2s]: [Vec<f64>; 5] =
+            axes.try_into().expect("one entry per axis");
+        SubGrid::try_new(nucleons, alphas, kts, xs, q2s, n_flavors, grid_data)
+            .map_err(|err| invalid(err.to_string()))
+    }
+
     /// Gets the parameter ranges for this subgrid.
     pub fn ranges(&self) -> RangeParameters {
         RangeParameters::new(
@@ -270,28 +926,1050 @@ impl SubGrid {
         )
     }
 
-    /// Gets a 2D slice of the grid for interpolation.
+    /// Extracts the part of the subgrid covering a box in `x` and `q2`.
     ///
-    /// This method is only valid for 2D interpolation configurations.
+    /// The extracted subgrid contains the knots inside the box, the knots bracketing it, and
+    /// one additional knot on each side (when available) such that the derivatives estimated
+    /// by the cubic interpolators are the same as in the parent subgrid. The interpolation
+    /// of the extracted subgrid is therefore identical to the parent one within the box.
     ///
     /// # Arguments
     ///
-    /// * `pid_index` - The index of the particle ID (flavor).
+    /// * `x` - The range in the momentum fraction `x`.
+    /// * `q2` - The range in the energy scale `q2`.
+    ///
+    /// # Returns
+    ///
+    /// A new `SubGrid` covering the box.
+    pub fn extract_box(&self, x: ParamRange, q2: ParamRange) -> SubGrid {
+        let knot_bounds = |knots: &Array1<f64>, range: ParamRange| {
+            // A single knot is kept as is, without any bracketing knot.
+            let last = knots.len().saturating_sub(1);
+            let lo = knots.iter().rposition(|&k| k <= range.min).unwrap_or(0);
+            let hi = knots.iter().position(|&k| k >= range.max).unwrap_or(last);
+            let hi = hi.max(lo + 1).min(last);
+            (
+                lo.min(hi.saturating_sub(1)).saturating_sub(1),
+                (hi + 1).min(last),
+            )
+        };
+        let (ix_lo, ix_hi) = knot_bounds(&self.xs, x);
+        let (iq2_lo, iq2_hi) = knot_bounds(&self.q2s, q2);
+
+        let xs = self.xs.slice(s![ix_lo..=ix_hi]).to_owned();
+        let q2s = self.q2s.slice(s![iq2_lo..=iq2_hi]).to_owned();
+        let grid = self
+            .grid
+            .slice(s![.., .., .., .., ix_lo..=ix_hi, iq2_lo..=iq2_hi])
+            .to_owned();
+
+        Self {
+            x_range: ParamRange::new(xs[0], xs[xs.len() - 1]),
+            q2_range: ParamRange::new(q2s[0], q2s[q2s.len() - 1]),
+            xs,
+            q2s,
+            kts: self.kts.clone(),
+            grid,
+            nucleons: self.nucleons.clone(),
+            alphas: self.alphas.clone(),
+            nucleons_range: self.nucleons_range,
+            alphas_range: self.alphas_range,
+            kt_range: self.kt_range,
+        }
+    }
+
+    /// Returns a coarser copy of the subgrid, e.g. for fast previews.
+    ///
+    /// Every `x_stride`-th knot in `x` and every `q2_stride`-th knot in `q2` are kept, the
+    /// first and last knots always being retained such that the ranges of the subgrid are
+    /// unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `x_stride` - The stride along the `x` knots.
+    /// * `q2_stride` - The stride along the `q2` knots.
+    ///
+    /// # Returns
+    ///
+    /// A new `SubGrid` holding the retained knots.
     ///
     /// # Panics
     ///
-    /// Panics if called on a subgrid that is not 2D.
-    pub fn grid_slice(&self, pid_index: usize) -> ArrayView2<'_, f64> {
-        match self.interpolation_config() {
-            InterpolationConfig::TwoD => self.grid.slice(s![0, 0, pid_index, 0, .., ..]),
-            _ => panic!("grid_slice only valid for 2D interpolation"),
+    /// Panics if one of the strides is zero.
+    pub fn downsample(&self, x_stride: usize, q2_stride: usize) -> SubGrid {
+        assert!(
+            x_stride > 0 && q2_stride > 0,
+            "The strides must be positive"
+        );
+
+        let retained = |n: usize, stride: usize| {
+            let mut indices: Vec<usize> = (0..n).step_by(stride).collect();
+            if indices.last() != Some(&(n - 1)) {
+                indices.push(n - 1);
+            }
+            indices
+        };
+        let ix = retained(self.xs.len(), x_stride);
+        let iq2 = retained(self.q2s.len(), q2_stride);
+
+        let xs = self.xs.select(ndarray::Axis(0), &ix);
+        let q2s = self.q2s.select(ndarray::Axis(0), &iq2);
+        let grid = self
+            .grid
+            .select(ndarray::Axis(4), &ix)
+            .select(ndarray::Axis(5), &iq2);
+
+        Self {
+            x_range: ParamRange::new(xs[0], xs[xs.len() - 1]),
+            q2_range: ParamRange::new(q2s[0], q2s[q2s.len() - 1]),
+            xs,
+            q2s,
+            kts: self.kts.clone(),
+            grid,
+            nucleons: self.nucleons.clone(),
+            alphas: self.alphas.clone(),
+            nucleons_range: self.nucleons_range,
+            alphas_range: self.alphas_range,
+            kt_range: self.kt_range,
         }
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// Resamples the subgrid onto Chebyshev-Gauss-Lobatto points in `ln(x)` and `ln(q2)`, as
+    /// required by the `LogChebyshev` interpolator.
+    ///
+    /// The new knots span the same ranges as the current ones, and the values at the new knots
+    /// are obtained with a log-bicubic interpolation of the current ones.
+    ///
+    /// # Arguments
+    ///
+    /// * `n_x` - The number of knots in `x`.
+    /// * `n_q2` - The number of knots in `q2`.
+    ///
+    /// # Returns
+    ///
+    /// A new `SubGrid` on Chebyshev points, or `Error::InterpolationError` if fewer than two
+    /// knots are requested or if the subgrid has fewer than four knots in `x` or `q2`.
+    pub fn to_chebyshev_nodes(&self, n_x: usize, n_q2: usize) -> Result<SubGrid, Error> {
+        if n_x < 2 || n_q2 < 2 {
+            return Err(Error::InterpolationError(
+                "At least 2 Chebyshev knots are required per axis".to_string(),
+            ));
+        }
+
+        let nodes = |knots: &Array1<f64>, n: usize| {
+            let (min, max) = (knots[0], knots[knots.len() - 1]);
+            let (u_min, u_max) = (min.ln(), max.ln());
+            let mut nodes: Vec<f64> = chebyshev_lobatto_points(n)
+                .into_iter()
+                .map(|t| (u_min + (u_max - u_min) * (t + 1.0) / 2.0).exp())
+                .collect();
+            // Keep the boundaries free of round-off, such that the ranges are unchanged.
+            nodes[0] = min;
+            nodes[n - 1] = max;
+            Array1::from_vec(nodes)
+        };
+        let xs = nodes(&self.xs, n_x);
+        let q2s = nodes(&self.q2s, n_q2);
+
+        let shape = self.grid.shape();
+        let mut grid = Array6::zeros((shape[0], shape[1], shape[2], shape[3], n_x, n_q2));
+        for ((a, alpha, flav, kt), _) in self.grid.slice(s![.., .., .., .., 0, 0]).indexed_iter() {
+            let interp = Interp2D::new(
+                self.xs.mapv(f64::ln),
+                self.q2s.mapv(f64::ln),
+                self.grid.slice(s![a, alpha, flav, kt, .., ..]).to_owned(),
+                LogBicubicInterpolation::default(),
+                Extrapolate::Clamp,
+            )
+            .map_err(|e| Error::InterpolationError(e.to_string()))?;
+
+            for (i, &x) in xs.iter().enumerate() {
+                for (j, &q2) in q2s.iter().enumerate() {
+                    grid[[a, alpha, flav, kt, i, j]] = interp
+                        .interpolate(&[x.ln(), q2.ln()])
+                        .map_err(|e| Error::InterpolationError(e.to_string()))?;
+                }
+            }
+        }
+
+        Ok(Self {
+            xs,
+            q2s,
+            grid,
+            kts: self.kts.clone(),
+            nucleons: self.nucleons.clone(),
+            alphas: self.alphas.clone(),
+            nucleons_range: self.nucleons_range,
+            alphas_range: self.alphas_range,
+            kt_range: self.kt_range,
+            x_range: self.x_range,
+            q2_range: self.q2_range,
+        })
+    }
+
+    /// Stacks subgrids along the nucleons axis, e.g. to assemble a nuclear PDF family.
+    ///
+    /// The subgrids are sorted by their nucleon numbers, which must all be distinct.
+    ///
+    /// # Arguments
+    ///
+    /// * `grids` - The subgrids to stack, sharing the same `x`, `q2`, `alphas` and `kT` axes
+    ///   and the same number of flavors.
+    ///
+    /// # Returns
+    ///
+    /// A new `SubGrid` whose nucleons axis contains the nucleon numbers of all the subgrids,
+    /// or `Error::IncompatibleGrids` naming the first subgrid and axis that differ if the
+    /// subgrids cannot be stacked.
+    pub fn concat_nucleons(grids: &[&SubGrid]) -> Result<SubGrid, Error> {
+        let Some(first) = grids.first() else {
+            return Err(Error::IncompatibleGrids(
+                "no subgrids to concatenate".to_string(),
+            ));
+        };
+        for (index, sg) in grids.iter().enumerate().skip(1) {
+            let differing = [
+                ("x", sg.xs != first.xs),
+                ("Q2", sg.q2s != first.q2s),
+                ("kT", sg.kts != first.kts),
+                ("alphas", sg.alphas != first.alphas),
+                ("flavor", sg.grid.shape()[2] != first.grid.shape()[2]),
+            ]
+            .into_iter()
+            .find_map(|(axis, differs)| differs.then_some(axis));
+            if let Some(axis) = differing {
+                return Err(Error::IncompatibleGrids(format!(
+                    "subgrid {index} differs from the first one along the {axis} axis"
+                )));
+            }
+        }
+
+        let mut sorted = grids.to_vec();
+        sorted.sort_by(|a, b| a.nucleons[0].total_cmp(&b.nucleons[0]));
+        let nucleons: Vec<f64> = sorted
+            .iter()
+            .flat_map(|sg| sg.nucleons.iter().copied())
+            .collect();
+        if nucleons.windows(2).any(|pair| pair[0] >= pair[1]) {
+            return Err(Error::IncompatibleGrids(
+                "the nucleon numbers must be distinct".to_string(),
+            ));
+        }
+
+        let views: Vec<_> = sorted.iter().map(|sg| sg.grid.view()).collect();
+        let grid = ndarray::concatenate(ndarray::Axis(0), &views)
+            .expect("The subgrids have the same shapes");
+
+        Ok(Self {
+            nucleons_range: ParamRange::new(nucleons[0], nucleons[nucleons.len() - 1]),
+            nucleons: Array1::from_vec(nucleons),
+            grid,
+            xs: first.xs.clone(),
+            q2s: first.q2s.clone(),
+            kts: first.kts.clone(),
+            alphas: first.alphas.clone(),
+            alphas_range: first.alphas_range,
+            kt_range: first.kt_range,
+            x_range: first.x_range,
+            q2_range: first.q2_range,
+        })
+    }
+
+    /// Checks that the grid values are consistent with the axes of the subgrid.
+    ///
+    /// The grid must have the layout `[nucleons, alphas, pids, kT, x, Q²]`, with one entry
+    /// per knot along each axis and at least one flavor. This is guaranteed by
+    /// [`SubGrid::new`] but not for subgrids assembled by other means.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if the grid is consistent, or the first `ShapeError` found otherwise.
+    pub fn validate_grid_shape(&self) -> Result<(), ShapeError> {
+        let shape = self.grid.shape();
+        let axes = [
+            (Axis::Nucleons, self.nucleons.len(), shape[0]),
+            (Axis::AlphaS, self.alphas.len(), shape[1]),
+            (Axis::Kt, self.kts.len(), shape[3]),
+            (Axis::X, self.xs.len(), shape[4]),
+            (Axis::Q2, self.q2s.len(), shape[5]),
+        ];
+        if let Some(&(axis, knots, entries)) =
+            axes.iter().find(|(_, knots, entries)| knots != entries)
+        {
+            return Err(ShapeError::AxisMismatch {
+                axis,
+                knots,
+                entries,
+            });
+        }
+        if shape[2] == 0 {
+            return Err(ShapeError::NoFlavors);
+        }
+
+        Ok(())
+    }
+
+    /// Checks that the subgrid can be interpolated.
+    ///
+    /// On top of the checks of [`SubGrid::validate_grid_shape`], the knots of every axis must
+    /// be strictly increasing and all the grid values must be finite. This is checked by
+    /// [`SubGrid::try_new`] and, in debug builds only, asserted by [`SubGrid::new`].
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if the subgrid is valid, or the first `ShapeError` found.
+    pub fn validate(&self) -> Result<(), ShapeError> {
+        self.validate_grid_shape()?;
+
+        let axes = [
+            (Axis::Nucleons, &self.nucleons),
+            (Axis::AlphaS, &self.alphas),
+            (Axis::Kt, &self.kts),
+            (Axis::X, &self.xs),
+            (Axis::Q2, &self.q2s),
+        ];
+        for (axis, knots) in axes {
+            for (index, pair) in knots.windows(2).into_iter().enumerate() {
+                let index = index + 1;
+                // NaN knots are incomparable, and rejected as non-monotonic.
+                match pair[1].partial_cmp(&pair[0]) {
+                    Some(Ordering::Greater) => {}
+                    Some(Ordering::Equal) => {
+                        return Err(ShapeError::DuplicateKnot { axis, index });
+                    }
+                    _ => return Err(ShapeError::NonMonotonicAxis { axis, index }),
+                }
+            }
+        }
+
+        if let Some((index, _)) = self
+            .grid
+            .indexed_iter()
+            .find(|(_, value)| !value.is_finite())
+        {
+            return Err(ShapeError::NonFiniteValue {
+                index: [index.0, index.1, index.2, index.3, index.4, index.5],
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Gets a 2D slice of the grid for interpolation.
+    ///
+    /// This method is only valid for 2D interpolation configurations.
+    ///
+    /// # Arguments
+    ///
+    /// * `pid_index` - The index of the particle ID (flavor).
+    ///
+    /// # Panics
+    ///
+    /// Panics if called on a subgrid that is not 2D.
+    pub fn grid_slice(&self, pid_index: usize) -> ArrayView2<'_, f64> {
+        match self.interpolation_config() {
+            InterpolationConfig::TwoD => self.grid.slice(s![0, 0, pid_index, 0, .., ..]),
+            _ => panic!("grid_slice only valid for 2D interpolation"),
+        }
+    }
+
+    /// Extracts the `x`-`Q²` grid of a flavor for fixed values of the other parameters.
+    ///
+    /// Contrary to [`SubGrid::grid_slice`], this method supports all the interpolation
+    /// configurations.
+    ///
+    /// # Arguments
+    ///
+    /// * `pid_index` - The index of the particle ID (flavor).
+    /// * `fixed` - The knot indices of the active parameters other than `x` and `Q²`, in
+    ///   the order nucleons, `alpha_s` and `kT`. Parameters with a single knot are skipped.
+    ///
+    /// # Returns
+    ///
+    /// The owned grid of shape `(xs.len(), q2s.len())`, or `Error::InterpolationError` if the
+    /// number of fixed indices does not match the active parameters or if an index is out
+    /// of bounds.
+    pub fn flavor_grid(&self, pid_index: usize, fixed: &[usize]) -> Result<Array2<f64>, Error> {
+        let shape = self.grid.shape();
+        if pid_index >= shape[2] {
+            return Err(Error::InterpolationError(format!(
+                "flavor index {pid_index} is out of bounds for {} flavors",
+                shape[2]
+            )));
+        }
+
+        let knots = [shape[0], shape[1], shape[3]];
+        let active: Vec<usize> = (0..knots.len()).filter(|&i| knots[i] > 1).collect();
+        if fixed.len() != active.len() {
+            return Err(Error::InterpolationError(format!(
+                "expected {} fixed indices but got {}",
+                active.len(),
+                fixed.len()
+            )));
+        }
+
+        let mut indices = [0; 3];
+        for (&i, &index) in active.iter().zip(fixed) {
+            if index >= knots[i] {
+                return Err(Error::InterpolationError(format!(
+                    "index {index} is out of bounds for an axis with {} knots",
+                    knots[i]
+                )));
+            }
+            indices[i] = index;
+        }
+
+        let [nucleon_idx, alpha_idx, kt_idx] = indices;
+        Ok(self
+            .grid
+            .slice(s![nucleon_idx, alpha_idx, pid_index, kt_idx, .., ..])
+            .to_owned())
+    }
+}
+
+/// Builds a [`SubGrid`] from blocks of values, without holding a second copy of the grid.
+///
+/// The values are expected in the same order as the flat `grid_data` of [`SubGrid::new`],
+/// i.e. `[nucleons, alphas, kT, x, Q², pids]` with the flavor running fastest, and can be
+/// pushed in blocks of any size, e.g. one slab per nucleon number. Each block is directly
+/// written into the final grid.
+#[derive(Debug, Clone)]
+pub struct SubGridBuilder {
+    nucleons: Vec<f64>,
+    alphas: Vec<f64>,
+    kts: Vec<f64>,
+    xs: Vec<f64>,
+    q2s: Vec<f64>,
+    grid: Array6<f64>,
+    filled: usize,
+}
+
+impl SubGridBuilder {
+    /// Creates a new builder for the given axes.
+    ///
+    /// # Arguments
+    ///
+    /// * `nucleon_numbers` - A vector of nucleon numbers.
+    /// * `alphas_values` - A vector of alpha_s values.
+    /// * `kt_subgrid` - A vector of `kT` values.
+    /// * `x_subgrid` - A vector of `x` values.
+    /// * `q2_subgrid` - A vector of `q2` values.
+    /// * `nflav` - The number of flavors.
+    pub fn new(
+        nucleon_numbers: Vec<f64>,
+        alphas_values: Vec<f64>,
+        kt_subgrid: Vec<f64>,
+        x_subgrid: Vec<f64>,
+        q2_subgrid: Vec<f64>,
+        nflav: usize,
+    ) -> Self {
+        let grid = Array6::zeros((
+            nucleon_numbers.len(),
+            alphas_values.len(),
+            nflav,
+            kt_subgrid.len(),
+            x_subgrid.len(),
+            q2_subgrid.len(),
+        ));
+
+        Self {
+            nucleons: nucleon_numbers,
+            alphas: alphas_values,
+            kts: kt_subgrid,
+            xs: x_subgrid,
+            q2s: q2_subgrid,
+            grid,
+            filled: 0,
+        }
+    }
+
+    /// Appends a block of values, following the ones pushed previously.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - The values, in the order of the flat `grid_data` of [`SubGrid::new`].
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())`, or `ShapeError::ElementCount` if the block exceeds the size of the grid, in
+    /// which case no value is written.
+    pub fn push_block(&mut self, data: &[f64]) -> Result<(), ShapeError> {
+        let expected = self.grid.len();
+        if self.filled + data.len() > expected {
+            return Err(ShapeError::ElementCount {
+                expected,
+                found: self.filled + data.len(),
+            });
+        }
+
+        let [na, nas, nflav, nkt, nx, nq2] = *self.grid.shape() else {
+            unreachable!("The grid has six dimensions");
+        };
+        for (offset, &value) in data.iter().enumerate() {
+            let mut index = self.filled + offset;
+            let mut unravel = |len: usize| {
+                let idx = index % len;
+                index /= len;
+                idx
+            };
+            // The flavor runs fastest, followed by `Q2`, `x`, `kT`, `alpha_s` and `A`.
+            let flav = unravel(nflav);
+            let q2 = unravel(nq2);
+            let x = unravel(nx);
+            let kt = unravel(nkt);
+            let alpha = unravel(nas);
+            let nucleon = unravel(na);
+            self.grid[[nucleon, alpha, flav, kt, x, q2]] = value;
+        }
+        self.filled += data.len();
+
+        Ok(())
+    }
+
+    /// Returns the completed subgrid.
+    ///
+    /// # Returns
+    ///
+    /// The `SubGrid`, identical to the one of [`SubGrid::try_new`] for the same values,
+    /// `ShapeError::ElementCount` if not all the values were pushed, or the error of
+    /// [`SubGrid::validate`] if the subgrid cannot be interpolated.
+    pub fn finish(self) -> Result<SubGrid, ShapeError> {
+        if self.filled != self.grid.len() {
+            return Err(ShapeError::ElementCount {
+                expected: self.grid.len(),
+                found: self.filled,
+            });
+        }
+
+        let built = SubGrid::from_axes(
+            self.nucleons,
+            self.alphas,
+            self.kts,
+            self.xs,
+            self.q2s,
+            self.grid,
+        );
+        built.validate()?;
+
+        Ok(built)
+    }
+}
+
+/// Selects the subgrid used to evaluate a point.
+///
+/// The first subgrid containing the point is preferred. Otherwise, for points in a gap
+/// between subgrids or outside of all of them, the subgrid with the smallest
+/// [`SubGrid::distance_to_point`] is selected, the lowest index winning ties.
+///
+/// # Arguments
+///
+/// * `subgrids` - The candidate subgrids.
+/// * `point` - The coordinates of the point.
+///
+/// # Returns
+///
+/// The selected subgrid, or `None` if `subgrids` is empty.
+pub fn select_subgrid<'a>(subgrids: &'a [SubGrid], point: &[f64]) -> Option<&'a SubGrid> {
+    select_subgrid_index(subgrids, point).map(|idx| &subgrids[idx])
+}
+
+/// Returns the index of the subgrid chosen by [`select_subgrid`].
+pub(crate) fn select_subgrid_index(subgrids: &[SubGrid], point: &[f64]) -> Option<usize> {
+    subgrids
+        .iter()
+        .position(|sg| sg.contains_point(point))
+        .or_else(|| {
+            subgrids
+                .iter()
+                .enumerate()
+                .min_by(|(_, a), (_, b)| {
+                    a.distance_to_point(point)
+                        .total_cmp(&b.distance_to_point(point))
+                })
+                .map(|(idx, _)| idx)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interpolator::InterpolatorFactory;
+    use crate::metadata::InterpolatorType;
+
+    #[test]
+    fn test_select_subgrid() {
+        let subgrid = |q2s: Vec<f64>| {
+            SubGrid::new(
+                vec![0.0],
+                vec![0.0],
+                vec![0.0],
+                vec![1e-3, 1e-2, 1e-1, 1.0],
+                q2s,
+                1,
+                vec![1.0; 12],
+            )
+        };
+        let subgrids = [
+            subgrid(vec![1.0, 2.0, 4.0]),
+            subgrid(vec![4.0, 6.0, 8.0]),
+            subgrid(vec![10.0, 20.0, 40.0]),
+        ];
+        let selected = |point: &[f64]| {
+            let sg = select_subgrid(&subgrids, point).unwrap();
+            subgrids.iter().position(|other| std::ptr::eq(sg, other))
+        };
+
+        // Contained, including on the boundary shared by the first two subgrids
+        assert_eq!(selected(&[0.1, 6.0]), Some(1));
+        assert_eq!(selected(&[0.1, 4.0]), Some(0));
+        // In the gap between the second and third subgrids
+        assert_eq!(selected(&[0.1, 8.5]), Some(1));
+        assert_eq!(selected(&[0.1, 9.5]), Some(2));
+        // Equidistant from the second and third subgrids
+        assert_eq!(selected(&[0.1, 9.0]), Some(1));
+        assert!(select_subgrid(&[], &[0.1, 9.0]).is_none());
+        // A NaN coordinate is not contained in any subgrid, and does not panic
+        assert_eq!(selected(&[0.1, f64::NAN]), Some(0));
+    }
+
+    #[test]
+    fn test_param_range_clamp_and_intersect() {
+        let range = ParamRange::new(1.0, 10.0);
+        assert_eq!(range.clamp(0.5), 1.0);
+        assert_eq!(range.clamp(5.0), 5.0);
+        assert_eq!(range.clamp(20.0), 10.0);
+
+        let disjoint = ParamRange::new(20.0, 30.0);
+        assert_eq!(range.intersect(&disjoint), None);
+        assert_eq!(disjoint.intersect(&range), None);
+
+        let touching = ParamRange::new(10.0, 30.0);
+        assert_eq!(
+            range.intersect(&touching),
+            Some(ParamRange::new(10.0, 10.0))
+        );
+
+        let nested = ParamRange::new(2.0, 3.0);
+        assert_eq!(range.intersect(&nested), Some(nested));
+        assert_eq!(nested.intersect(&range), Some(nested));
+
+        let overlapping = ParamRange::new(5.0, 30.0);
+        assert_eq!(
+            range.intersect(&overlapping),
+            Some(ParamRange::new(5.0, 10.0))
+        );
+    }
+
+    #[test]
+    fn test_flavor_grid() {
+        let xs = vec![1e-3, 1e-2, 1e-1, 1.0];
+        let q2s = vec![1.0, 10.0, 100.0];
+        let grid_data: Vec<f64> = (0..24).map(f64::from).collect();
+        let two_d = SubGrid::new(
+            vec![0.0],
+            vec![0.0],
+            vec![0.0],
+            xs.clone(),
+            q2s.clone(),
+            2,
+            grid_data,
+        );
+        let gluon = two_d.flavor_grid(0, &[]).unwrap();
+        assert_eq!(gluon, two_d.grid_slice(0));
+        assert!(two_d.flavor_grid(0, &[0]).is_err());
+        assert!(two_d.flavor_grid(2, &[]).is_err());
+
+        // [nucleons, alphas, pids, kT, x, Q2] = [2, 1, 2, 3, 4, 3]
+        let grid_data: Vec<f64> = (0..144).map(f64::from).collect();
+        let four_d = SubGrid::new(
+            vec![1.0, 4.0],
+            vec![0.118],
+            vec![0.0, 1.0, 2.0],
+            xs,
+            q2s,
+            2,
+            grid_data,
+        );
+        let gluon = four_d.flavor_grid(0, &[1, 2]).unwrap();
+        assert_eq!(gluon.dim(), (4, 3));
+        assert_eq!(gluon, four_d.grid.slice(s![1, 0, 0, 2, .., ..]));
+        assert!(four_d.flavor_grid(0, &[1]).is_err());
+        assert!(four_d.flavor_grid(0, &[2, 0]).is_err());
+        assert!(four_d.flavor_grid(0, &[0, 3]).is_err());
+    }
+
+    #[test]
+    fn test_try_new() {
+        let axes = || {
+            (
+                vec![1.0],
+                vec![0.118],
+                vec![0.0],
+                vec![1e-3, 1e-1, 1.0],
+                vec![1.0, 10.0],
+            )
+        };
+
+        let (nucleons, alphas, kts, xs, q2s) = axes();
+        let err = SubGrid::try_new(nucleons, alphas, kts, xs, q2s, 2, vec![1.0; 11]).unwrap_err();
+        assert_eq!(
+            err,
+            ShapeError::DataLength {
+                shape: [1, 1, 1, 3, 2, 2],
+                expected: 12,
+                found: 11
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "The grid of shape [1, 1, 1, 3, 2, 2] (nucleons, alphas, kT, x, Q², flavors) holds 12 \
+             values but 11 were provided"
+        );
+
+        let (nucleons, alphas, kts, xs, q2s) = axes();
+        let subgrid = SubGrid::try_new(nucleons, alphas, kts, xs, q2s, 2, vec![1.0; 12]).unwrap();
+        assert_eq!(subgrid.grid.shape(), &[1, 1, 2, 1, 3, 2]);
+
+        // Invalid subgrids are reported instead of asserted, also in debug builds
+        let (nucleons, alphas, kts, xs, q2s) = axes();
+        let mut grid_data = vec![1.0; 12];
+        grid_data[3] = f64::NAN;
+        assert_eq!(
+            SubGrid::try_new(nucleons, alphas, kts, xs, q2s, 2, grid_data.clone()).unwrap_err(),
+            ShapeError::NonFiniteValue {
+                index: [0, 0, 1, 0, 0, 1]
+            }
+        );
+        let (nucleons, alphas, kts, _, q2s) = axes();
+        let xs = vec![1e-3, 1e-3, 1.0];
+        assert_eq!(
+            SubGrid::try_new(nucleons, alphas, kts, xs, q2s, 2, vec![1.0; 12]).unwrap_err(),
+            ShapeError::DuplicateKnot {
+                axis: Axis::X,
+                index: 1
+            }
+        );
+
+        let (nucleons, alphas, kts, xs, q2s) = axes();
+        let mut builder = SubGridBuilder::new(nucleons, alphas, kts, xs, q2s, 2);
+        builder.push_block(&grid_data).unwrap();
+        assert!(matches!(
+            builder.finish(),
+            Err(ShapeError::NonFiniteValue { .. })
+        ));
+    }
+
+    #[test]
+    fn test_subgrid_builder() {
+        let (nucleons, alphas, kts) = (vec![1.0, 4.0, 12.0], vec![0.118], vec![0.0, 1.0]);
+        let (xs, q2s) = (vec![1e-3, 1e-2, 1e-1, 1.0], vec![1.0, 10.0, 100.0]);
+        let grid_data: Vec<f64> = (0..216).map(|i| (f64::from(i) * 0.37).sin()).collect();
+        let reference = SubGrid::new(
+            nucleons.clone(),
+            alphas.clone(),
+            kts.clone(),
+            xs.clone(),
+            q2s.clone(),
+            3,
+            grid_data.clone(),
+        );
+
+        let builder = || {
+            SubGridBuilder::new(
+                nucleons.clone(),
+                alphas.clone(),
+                kts.clone(),
+                xs.clone(),
+                q2s.clone(),
+                3,
+            )
+        };
+        let mut by_nucleon = builder();
+        for slab in grid_data.chunks(72) {
+            by_nucleon.push_block(slab).unwrap();
+        }
+        assert_eq!(by_nucleon.finish().unwrap(), reference);
+
+        let mut uneven = builder();
+        for block in grid_data.chunks(50) {
+            uneven.push_block(block).unwrap();
+        }
+        assert_eq!(uneven.finish().unwrap(), reference);
+
+        let mut incomplete = builder();
+        incomplete.push_block(&grid_data[..100]).unwrap();
+        assert_eq!(
+            incomplete.push_block(&grid_data[..200]),
+            Err(ShapeError::ElementCount {
+                expected: 216,
+                found: 300
+            })
+        );
+        assert_eq!(
+            incomplete.finish().unwrap_err(),
+            ShapeError::ElementCount {
+                expected: 216,
+                found: 100
+            }
+        );
+    }
+
+    #[test]
+    fn test_memory_footprint() {
+        let xs = vec![1e-3, 1e-2, 1e-1, 1.0];
+        let q2s = vec![1.0, 10.0, 100.0];
+        let subgrid = SubGrid::new(
+            vec![1.0, 4.0],
+            vec![0.0],
+            vec![0.0],
+            xs,
+            q2s,
+            3,
+            vec![1.0; 72],
+        );
+        // 2 x 1 x 3 x 1 x 4 x 3 grid values and 4 + 3 + 1 + 2 + 1 knots
+        assert_eq!(subgrid.memory_footprint(), (72 + 11) * 8);
+    }
+
+    #[test]
+    fn test_validate_grid_shape() {
+        let xs = vec![1e-3, 1e-2, 1e-1, 1.0];
+        let q2s = vec![1.0, 10.0, 100.0];
+        let subgrid = SubGrid::new(vec![0.0], vec![0.0], vec![0.0], xs, q2s, 2, vec![1.0; 24]);
+        assert_eq!(subgrid.validate_grid_shape(), Ok(()));
+
+        let mut misshaped = subgrid.clone();
+        misshaped.q2s = Array1::from_vec(vec![1.0, 10.0, 100.0, 1000.0]);
+        assert_eq!(
+            misshaped.validate_grid_shape(),
+            Err(ShapeError::AxisMismatch {
+                axis: Axis::Q2,
+                knots: 4,
+                entries: 3
+            })
+        );
+
+        let mut empty = subgrid;
+        empty.grid = Array6::zeros((1, 1, 0, 1, 4, 3));
+        assert_eq!(empty.validate_grid_shape(), Err(ShapeError::NoFlavors));
+    }
+
+    #[test]
+    fn test_axis_spacing() {
+        let subgrid = representative_subgrid();
+        assert_eq!(subgrid.axis_spacing(Axis::X), Spacing::Log);
+        assert_eq!(subgrid.axis_spacing(Axis::Q2), Spacing::Log);
+        // Single-knot axes.
+        assert_eq!(subgrid.axis_spacing(Axis::Kt), Spacing::Linear);
+
+        let xs: Vec<f64> = (1..=10).map(|i| 0.1 * f64::from(i)).collect();
+        let linear = SubGrid::new(
+            vec![0.0],
+            vec![0.0],
+            vec![0.0],
+            xs,
+            vec![1.0, 10.0, 100.0],
+            1,
+            vec![1.0; 30],
+        );
+        assert_eq!(linear.axis_spacing(Axis::X), Spacing::Linear);
+
+        let mut irregular = subgrid;
+        irregular.xs[20] *= 1.01;
+        assert_eq!(irregular.axis_spacing(Axis::X), Spacing::Irregular);
+    }
+
+    #[test]
+    fn test_to_chebyshev_nodes() {
+        let subgrid = representative_subgrid();
+        let cheby = subgrid.to_chebyshev_nodes(20, 12).unwrap();
+
+        assert_eq!((cheby.xs.len(), cheby.q2s.len()), (20, 12));
+        assert_eq!(cheby.x_range, subgrid.x_range);
+        assert_eq!(cheby.q2_range, subgrid.q2_range);
+        assert_eq!(cheby.validate(), Ok(()));
+        assert_eq!(cheby.axis_spacing(Axis::X), Spacing::Irregular);
+
+        // The resampled grid is accepted by the Chebyshev interpolator, which rejects the
+        // geometric knots of the original one.
+        InterpolatorFactory::create(InterpolatorType::LogChebyshev, &cheby, 0);
+        let rejected = std::panic::catch_unwind(|| {
+            InterpolatorFactory::create(InterpolatorType::LogChebyshev, &subgrid, 0)
+        });
+        assert!(rejected.is_err());
+
+        // The large-x region, where `(1 - x)^3` varies quickly in between the geometric knots,
+        // is skipped.
+        for (i, &x) in cheby.xs.iter().enumerate().filter(|(_, &x)| x < 0.5) {
+            for (j, &q2) in cheby.q2s.iter().enumerate() {
+                let expected = x.powf(-0.2) * (1.0 - x).powi(3) * (1.0 + 0.01 * q2.ln());
+                let value = cheby.grid[[0, 0, 0, 0, i, j]];
+                assert!((value / expected - 1.0).abs() < 1e-3);
+                assert_eq!(cheby.grid[[0, 0, 1, 0, i, j]], 0.0);
+            }
+        }
+
+        assert!(subgrid.to_chebyshev_nodes(1, 12).is_err());
+    }
+
+    #[test]
+    fn test_interpolate_batch() {
+        let subgrid = representative_subgrid();
+        let interpolator = InterpolatorFactory::create(InterpolatorType::LogBicubic, &subgrid, 2);
+
+        // Unsorted points, including knots, the upper boundaries and points to be clamped.
+        let mut points = vec![[1.0, 1e5], [1e-6, 1.0], [1e-9, 50.0], [0.3, 1e7]];
+        for i in 0..40 {
+            let x = 10f64.powf(-5.5 + 0.13 * f64::from((i * 7) % 40));
+            let q2 = 10f64.powf(0.11 * f64::from((i * 11) % 40));
+            points.push([x, q2]);
+        }
+
+        let batch = subgrid.interpolate_batch(2, &points).unwrap();
+        assert_eq!(batch.len(), points.len());
+        for (&[x, q2], value) in points.iter().zip(batch) {
+            let expected = interpolator.interpolate_point(&[x.ln(), q2.ln()]).unwrap();
+            assert_eq!(value, expected);
+        }
+
+        assert!(subgrid.interpolate_batch(2, &[]).unwrap().is_empty());
+        let nuclear = SubGrid::concat_nucleons(&[&subgrid, &{
+            let mut other = subgrid.clone();
+            other.nucleons[0] = 1.0;
+            other
+        }])
+        .unwrap();
+        assert!(nuclear.interpolate_batch(2, &points).is_err());
+    }
+
+    #[test]
+    fn test_validate() {
+        let subgrid = representative_subgrid();
+        assert_eq!(subgrid.validate(), Ok(()));
+
+        let mut shuffled = subgrid.clone();
+        shuffled.xs.swap(10, 11);
+        assert_eq!(
+            shuffled.validate(),
+            Err(ShapeError::NonMonotonicAxis {
+                axis: Axis::X,
+                index: 11
+            })
+        );
+
+        let mut duplicate = subgrid.clone();
+        duplicate.q2s[5] = duplicate.q2s[4];
+        assert_eq!(
+            duplicate.validate(),
+            Err(ShapeError::DuplicateKnot {
+                axis: Axis::Q2,
+                index: 5
+            })
+        );
+
+        let mut nan = subgrid;
+        nan.grid[[0, 0, 3, 0, 7, 2]] = f64::NAN;
+        assert_eq!(
+            nan.validate(),
+            Err(ShapeError::NonFiniteValue {
+                index: [0, 0, 3, 0, 7, 2]
+            })
+        );
+    }
+
+    fn representative_subgrid() -> SubGrid {
+        let xs: Vec<f64> = (0..50)
+            .map(|i| 10f64.powf(-6.0 + 6.0 * f64::from(i) / 49.0))
+            .collect();
+        let q2s: Vec<f64> = (0..40).map(|i| 10f64.powf(f64::from(i) / 8.0)).collect();
+        let nflav = 11;
+        let mut values = Vec::with_capacity(xs.len() * q2s.len() * nflav);
+        for &x in &xs {
+            for &q2 in &q2s {
+                for flav in 0..nflav {
+                    let shape = x.powf(-0.2) * (1.0 - x).powi(3) * (1.0 + 0.01 * q2.ln());
+                    values.push(if flav % 2 == 0 { shape } else { 0.0 });
+                }
+            }
+        }
+        SubGrid::new(vec![0.0], vec![0.0], vec![0.0], xs, q2s, nflav, values)
+    }
+
+    #[test]
+    fn test_bincode_roundtrip() {
+        let subgrid = representative_subgrid();
+        let bytes = subgrid.to_bincode().unwrap();
+        assert_eq!(SubGrid::from_bincode(&bytes).unwrap(), subgrid);
+
+        assert!(matches!(
+            SubGrid::from_bincode(&bytes[..bytes.len() / 2]),
+            Err(SerializationError::Bincode(_))
+        ));
+    }
+
+    #[cfg(feature = "compression")]
+    #[test]
+    fn test_bincode_zstd_roundtrip() {
+        let subgrid = representative_subgrid();
+        let compressed = subgrid.to_bincode_zstd(0).unwrap();
+        assert_eq!(SubGrid::from_bincode_zstd(&compressed).unwrap(), subgrid);
+        assert!(compressed.len() < subgrid.to_bincode().unwrap().len());
+
+        assert!(SubGrid::from_bincode_zstd(&compressed[..compressed.len() / 2]).is_err());
+    }
+
+    #[test]
+    fn test_flat_text_roundtrip() {
+        let roundtrip = |subgrid: &SubGrid| {
+            let mut text = Vec::new();
+            subgrid.to_flat_text(&mut text).unwrap();
+            let parsed = SubGrid::from_flat_text(text.as_slice()).unwrap();
+            assert_eq!(&parsed, subgrid);
+            String::from_utf8(text).unwrap()
+        };
+
+        let text = roundtrip(&representative_subgrid());
+        assert!(text
+            .lines()
+            .any(|line| line == "# columns: x q2 flavor value"));
+        assert_eq!(
+            text.lines().filter(|line| !line.starts_with('#')).count(),
+            50 * 40 * 11
+        );
+
+        let (nucleons, alphas) = (vec![1.0, 208.0], vec![0.116, 0.118, 0.12]);
+        let (xs, q2s) = (vec![1e-3, 0.1, 0.7], vec![1.65, 10.0]);
+        let grid_data: Vec<f64> = (0..72).map(|i| (f64::from(i) * 0.37).sin()).collect();
+        let subgrid = SubGrid::new(nucleons, alphas, vec![0.0], xs, q2s, 2, grid_data);
+        let text = roundtrip(&subgrid);
+        let mut lines = text.lines().skip_while(|line| line.starts_with('#'));
+        assert_eq!(lines.next(), Some("1e0 1.16e-1 1e-3 1.65e0 0 0e0"));
+        assert_eq!(
+            lines.next(),
+            Some(format!("1e0 1.16e-1 1e-3 1.65e0 1 {:e}", 0.37f64.sin()).as_str())
+        );
+        assert!(text.contains("# kt: 0e0\n"));
+        assert!(text.contains("# columns: nucleons alphas x q2 flavor value\n"));
+
+        let truncated = &text[..text.rfind("1e0 1.16e-1").unwrap()];
+        assert!(SubGrid::from_flat_text(truncated.as_bytes()).is_err());
+        let shuffled = text.replacen("1e0 1.16e-1 1e-3 1.65e0 0", "1e0 1.16e-1 1e-3 1e1 0", 1);
+        let error = SubGrid::from_flat_text(shuffled.as_bytes()).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(error.to_string(), "Row 0 is out of order");
+
+        let nan = text.replacen(
+            "1e0 1.16e-1 1e-3 1.65e0 0 0e0",
+            "1e0 1.16e-1 1e-3 1.65e0 0 NaN",
+            1,
+        );
+        let error = SubGrid::from_flat_text(nan.as_bytes()).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(
+            error.to_string(),
+            "The grid value at [0, 0, 0, 0, 0, 0] is not finite"
+        );
+    }
 
     #[test]
     fn test_param_range() {
@@ -299,4 +1977,208 @@ mod tests {
         assert!(range.contains(5.0));
         assert!(!range.contains(15.0));
     }
+
+    #[test]
+    fn test_locate_point() {
+        let (xs, q2s) = (vec![1e-3, 1e-2, 1e-1, 1.0], vec![1.0, 10.0, 100.0, 1000.0]);
+        let subgrid = SubGrid::new(
+            vec![1.0, 208.0],
+            vec![0.11, 0.12],
+            vec![0.5, 2.0],
+            xs,
+            q2s,
+            1,
+            vec![1.0; 2 * 2 * 2 * 4 * 4],
+        );
+        assert!(matches!(
+            subgrid.interpolation_config(),
+            InterpolationConfig::FiveD
+        ));
+
+        let inside = [4.0, 0.118, 1.0, 0.05, 50.0];
+        assert_eq!(subgrid.locate_point(&inside), Ok(()));
+        assert!(subgrid.contains_point(&inside));
+
+        let cases = [
+            (Axis::Nucleons, 0, 0.5, subgrid.nucleons_range),
+            (Axis::AlphaS, 1, 0.13, subgrid.alphas_range),
+            (Axis::Kt, 2, 3.0, subgrid.kt_range),
+            (Axis::X, 3, 1e-4, subgrid.x_range),
+            (Axis::Q2, 4, 2000.0, subgrid.q2_range),
+        ];
+        for (axis, index, value, range) in cases {
+            let mut point = inside;
+            point[index] = value;
+            assert_eq!(
+                subgrid.locate_point(&point),
+                Err(OutOfRange::Axis { axis, value, range })
+            );
+            assert!(!subgrid.contains_point(&point));
+        }
+
+        let error = subgrid
+            .locate_point(&[4.0, 0.118, 1.0, 0.05, 2000.0])
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Q2 = 2000 is outside of the subgrid range [1, 1000]"
+        );
+        assert_eq!(
+            subgrid.locate_point(&[0.05, 50.0]),
+            Err(OutOfRange::Dimensions {
+                expected: 5,
+                found: 2
+            })
+        );
+    }
+
+    #[test]
+    fn test_concat_nucleons() {
+        use ninterp::prelude::*;
+
+        let xs = vec![1e-3, 1e-2, 1e-1, 1.0];
+        let q2s = vec![1.0, 10.0, 100.0, 1000.0];
+        let nucleus = |a: f64| {
+            let grid_data = xs
+                .iter()
+                .flat_map(|&x| q2s.iter().map(move |&q2: &f64| a * x * (1.0 + q2.ln())))
+                .collect();
+            SubGrid::new(
+                vec![a],
+                vec![0.0],
+                vec![0.0],
+                xs.clone(),
+                q2s.clone(),
+                1,
+                grid_data,
+            )
+        };
+        let (proton, helium) = (nucleus(1.0), nucleus(4.0));
+
+        let stacked = SubGrid::concat_nucleons(&[&helium, &proton]).unwrap();
+        assert_eq!(stacked.nucleons.to_vec(), vec![1.0, 4.0]);
+        assert_eq!(
+            (stacked.nucleons_range.min, stacked.nucleons_range.max),
+            (1.0, 4.0)
+        );
+        assert!(matches!(
+            stacked.interpolation_config(),
+            InterpolationConfig::ThreeDNucleons
+        ));
+
+        let interp = Interp3D::new(
+            stacked.nucleons.clone(),
+            stacked.xs.clone(),
+            stacked.q2s.clone(),
+            stacked.grid.slice(s![.., 0, 0, 0, .., ..]).to_owned(),
+            ninterp::strategy::Linear,
+            Extrapolate::Error,
+        )
+        .unwrap();
+        for (&x, &q2) in xs.iter().zip(&q2s) {
+            let value = interp.interpolate(&[2.5, x, q2]).unwrap();
+            assert!((value - 2.5 * x * (1.0 + q2.ln())).abs() < 1e-14);
+        }
+
+        let lead = nucleus(208.0);
+        let family = SubGrid::concat_nucleons(&[&lead, &proton, &helium]).unwrap();
+        assert_eq!(family.nucleons.len(), 3);
+        assert_eq!(family.nucleons.to_vec(), vec![1.0, 4.0, 208.0]);
+        assert_eq!(family.nucleons_range, ParamRange::new(1.0, 208.0));
+        assert_eq!(
+            family.grid.slice(s![2, .., .., .., .., ..]),
+            lead.grid.slice(s![0, .., .., .., .., ..])
+        );
+
+        assert!(SubGrid::concat_nucleons(&[&proton, &proton]).is_err());
+        let mut lead = nucleus(208.0);
+        lead.q2s[3] = 500.0;
+        match SubGrid::concat_nucleons(&[&proton, &helium, &lead]) {
+            Err(Error::IncompatibleGrids(message)) => {
+                assert_eq!(
+                    message,
+                    "subgrid 2 differs from the first one along the Q2 axis"
+                );
+            }
+            _ => panic!("the Q2 axes differ"),
+        }
+        assert!(SubGrid::concat_nucleons(&[]).is_err());
+    }
+
+    #[test]
+    fn test_extract_box() {
+        let xs: Vec<f64> = (0..=10)
+            .map(|i| 10f64.powf(-5.0 + 0.5 * i as f64))
+            .collect();
+        let q2s: Vec<f64> = (0..=8).map(|i| 10f64.powf(0.5 * i as f64)).collect();
+        let grid_data = xs
+            .iter()
+            .flat_map(|&x| {
+                q2s.iter()
+                    .map(move |&q2: &f64| x.powf(-0.3) * (1.0 - x).powi(3) * q2.ln().sin())
+            })
+            .collect();
+        let subgrid = SubGrid::new(vec![1.0], vec![0.118], vec![0.0], xs, q2s, 1, grid_data);
+
+        let (x_box, q2_box) = (ParamRange::new(2e-3, 5e-2), ParamRange::new(20.0, 500.0));
+        let extracted = subgrid.extract_box(x_box, q2_box);
+        assert!(extracted.xs.len() < subgrid.xs.len());
+        assert!(extracted.q2s.len() < subgrid.q2s.len());
+        assert!(extracted.x_range.min <= x_box.min && extracted.x_range.max >= x_box.max);
+        assert!(extracted.q2_range.min <= q2_box.min && extracted.q2_range.max >= q2_box.max);
+
+        let parent = InterpolatorFactory::create(InterpolatorType::LogBicubic, &subgrid, 0);
+        let child = InterpolatorFactory::create(InterpolatorType::LogBicubic, &extracted, 0);
+        for i in 0..=10 {
+            for j in 0..=10 {
+                let x = x_box.min * (x_box.max / x_box.min).powf(i as f64 / 10.0);
+                let q2 = q2_box.min * (q2_box.max / q2_box.min).powf(j as f64 / 10.0);
+                let point = [x.ln(), q2.ln()];
+                assert_eq!(
+                    parent.interpolate_point(&point).unwrap(),
+                    child.interpolate_point(&point).unwrap()
+                );
+            }
+        }
+
+        // An axis with a single knot is kept as is
+        let single = SubGrid::new(
+            vec![0.0],
+            vec![0.0],
+            vec![0.0],
+            vec![1e-3, 1e-2, 1e-1, 1.0],
+            vec![10.0],
+            1,
+            vec![1.0; 4],
+        );
+        let extracted = single.extract_box(x_box, q2_box);
+        assert_eq!(extracted.q2s, single.q2s);
+        assert_eq!(extracted.xs, single.xs);
+        assert_eq!(extracted.grid.shape(), &[1, 1, 1, 1, 4, 1]);
+    }
+
+    #[test]
+    fn test_downsample() {
+        let subgrid = representative_subgrid();
+        let coarse = subgrid.downsample(2, 3);
+
+        // 50 knots: the 25 even ones and the last one.
+        assert_eq!(coarse.xs.len(), 26);
+        // 40 knots: every third one, which already includes the last one.
+        assert_eq!(coarse.q2s.len(), 14);
+        assert_eq!(coarse.x_range, subgrid.x_range);
+        assert_eq!(coarse.q2_range, subgrid.q2_range);
+        assert_eq!(coarse.grid.shape(), &[1, 1, 11, 1, 26, 14]);
+        assert_eq!(
+            coarse.grid[[0, 0, 4, 0, 3, 2]],
+            subgrid.grid[[0, 0, 4, 0, 6, 6]]
+        );
+        assert_eq!(
+            coarse.grid[[0, 0, 2, 0, 25, 13]],
+            subgrid.grid[[0, 0, 2, 0, 49, 39]]
+        );
+        assert!(coarse.validate_grid_shape().is_ok());
+
+        assert_eq!(subgrid.downsample(1, 1), subgrid);
+    }
 }
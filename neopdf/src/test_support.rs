@@ -0,0 +1,175 @@
+//! Deterministic synthetic grids shared by the tests and the benchmarks.
+//!
+//! The grids follow a smooth PDF-like shape, perturbed by a pseudo-random noise drawn from a
+//! fixed seed, such that the same seed always produces the same grid. Outside of the unit
+//! tests of the crate, this module is only available with the `testing` feature.
+
+use crate::interpolator::{Axis, InterpolationConfig};
+use crate::parser::SubgridData;
+use crate::subgrid::SubGrid;
+
+/// The nucleon numbers of the grids depending on `A`.
+const NUCLEONS: [f64; 4] = [1.0, 4.0, 56.0, 208.0];
+/// The `alpha_s` values of the grids depending on `alpha_s`.
+const ALPHAS: [f64; 5] = [0.112, 0.115, 0.118, 0.121, 0.124];
+/// The `kT` values of the grids depending on `kT`.
+const KTS: [f64; 4] = [0.5, 1.0, 2.0, 5.0];
+/// The number of knots in `x`, between `1e-5` and `1`.
+const NUM_XS: usize = 40;
+/// The number of knots in `Q²`, between `2` and `1e5`.
+const NUM_Q2S: usize = 30;
+
+/// A SplitMix64 generator, which is enough to produce reproducible noise.
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    /// Returns the next number, uniformly distributed in `[0, 1)`.
+    fn next_f64(&mut self) -> f64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^= z >> 31;
+        // Keep the 53 bits that fit in the mantissa of an `f64`.
+        (z >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+fn logspace(min: f64, max: f64, num: usize) -> Vec<f64> {
+    let (ln_min, ln_max) = (min.ln(), max.ln());
+    (0..num)
+        .map(|i| (ln_min + (ln_max - ln_min) * i as f64 / (num - 1) as f64).exp())
+        .collect()
+}
+
+/// Returns the knots of `axis` in a grid with the configuration `config`.
+fn axis_knots(config: InterpolationConfig, axis: Axis) -> Vec<f64> {
+    let active = config.axis_order().contains(&axis);
+    match axis {
+        Axis::Nucleons if active => NUCLEONS.to_vec(),
+        Axis::AlphaS if active => ALPHAS.to_vec(),
+        Axis::Kt if active => KTS.to_vec(),
+        Axis::Nucleons | Axis::AlphaS | Axis::Kt => vec![0.0],
+        Axis::X => logspace(1e-5, 1.0, NUM_XS),
+        Axis::Q2 => logspace(2.0, 1e5, NUM_Q2S),
+    }
+}
+
+/// Builds a subgrid with the configuration `config`, filled with a noisy PDF-like shape.
+///
+/// # Arguments
+///
+/// * `config` - The configuration of the subgrid.
+/// * `nflav` - The number of flavors.
+/// * `seed` - The seed of the noise.
+///
+/// # Returns
+///
+/// A `SubGrid` whose `interpolation_config` is `config`. The values are strictly positive
+/// and only depend on the arguments.
+pub fn synthetic_subgrid(config: InterpolationConfig, nflav: usize, seed: u64) -> SubGrid {
+    let [nucleons, alphas, kts, xs, q2s] =
+        [Axis::Nucleons, Axis::AlphaS, Axis::Kt, Axis::X, Axis::Q2]
+            .map(|axis| axis_knots(config, axis));
+    let mut rng = SplitMix64(seed);
+
+    let mut grid_data =
+        Vec::with_capacity(nucleons.len() * alphas.len() * kts.len() * xs.len() * q2s.len());
+    for &a in &nucleons {
+        for &alphas in &alphas {
+            for &kt in &kts {
+                for &x in &xs {
+                    for &q2 in &q2s {
+                        for flavor in 0..nflav {
+                            let shape = x.powf(-0.2 - 0.01 * flavor as f64)
+                                * (1.0 - x).powi(3)
+                                * (1.0 + alphas * q2.ln())
+                                * (1.0 + 0.01 * a)
+                                / (1.0 + kt * kt);
+                            let noise = 1.0 + 0.01 * (rng.next_f64() - 0.5);
+                            // Keep the values strictly positive at `x = 1` for the log
+                            // interpolations.
+                            grid_data.push(shape * noise + 1e-8);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    SubGrid::new(nucleons, alphas, kts, xs, q2s, nflav, grid_data)
+}
+
+/// Builds the data of a subgrid only depending on `x` and `Q²`.
+///
+/// # Arguments
+///
+/// * `xs` - The knots in `x`.
+/// * `q2s` - The knots in `Q²`.
+/// * `grid_data` - The values, in the order of the LHAPDF data files.
+///
+/// # Returns
+///
+/// A `SubgridData` whose `A`, `alpha_s` and `kT` axes hold a single knot at zero.
+pub fn xq2_subgrid_data(xs: Vec<f64>, q2s: Vec<f64>, grid_data: Vec<f64>) -> SubgridData {
+    SubgridData {
+        nucleons: vec![0.0],
+        alphas: vec![0.0],
+        kts: vec![0.0],
+        xs,
+        q2s,
+        grid_data,
+    }
+}
+
+/// Draws points inside of the subgrids built by [`synthetic_subgrid`].
+///
+/// # Arguments
+///
+/// * `config` - The configuration of the subgrid.
+/// * `num` - The number of points.
+/// * `seed` - The seed of the points.
+///
+/// # Returns
+///
+/// The coordinates of the points, in the order of [`InterpolationConfig::axis_order`].
+pub fn synthetic_points(config: InterpolationConfig, num: usize, seed: u64) -> Vec<Vec<f64>> {
+    let mut rng = SplitMix64(seed);
+    let mut draw = |axis| {
+        let knots = axis_knots(config, axis);
+        let (min, max) = (knots[0], knots[knots.len() - 1]);
+        (min.ln() + (max.ln() - min.ln()) * rng.next_f64()).exp()
+    };
+
+    (0..num)
+        .map(|_| config.axis_order().into_iter().map(&mut draw).collect())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_synthetic_subgrid() {
+        for config in [
+            InterpolationConfig::TwoD,
+            InterpolationConfig::ThreeDKt,
+            InterpolationConfig::FourDNucleonsAlphas,
+            InterpolationConfig::FiveD,
+        ] {
+            let subgrid = synthetic_subgrid(config, 2, 7);
+            assert_eq!(subgrid.interpolation_config(), config);
+            assert!(subgrid.grid.iter().all(|&value| value > 0.0));
+            assert_eq!(subgrid.grid, synthetic_subgrid(config, 2, 7).grid);
+            assert_ne!(subgrid.grid, synthetic_subgrid(config, 2, 8).grid);
+
+            let points = synthetic_points(config, 10, 7);
+            assert_eq!(points, synthetic_points(config, 10, 7));
+            assert!(points
+                .iter()
+                .all(|point| point.len() == config.num_active_dims()));
+            assert!(points.iter().all(|point| subgrid.contains_point(point)));
+        }
+    }
+}
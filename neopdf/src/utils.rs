@@ -1,7 +1,9 @@
 //! This module provides utility functions for interpolation and grid operations.
 //!
-//! It includes helpers for finding interval indices in coordinate arrays and for
-//! performing 1D cubic interpolation using Hermite basis functions. Finds the index
+//! It includes helpers for finding interval indices in coordinate arrays, for
+//! performing 1D cubic interpolation using Hermite basis functions, for the
+//! numerical integration of 1D functions and for the quantiles of the normal
+//! distribution. Finds the index
 //! of the interval in a sorted coordinate array that contains the given value.
 ///
 /// This function performs a binary search to efficiently locate the correct interval.
@@ -67,10 +69,109 @@ pub fn hermite_cubic_interpolate(t: f64, vl: f64, vdl: f64, vh: f64, vdh: f64) -
     p0 + m0 + p1 + m1
 }
 
+/// Integrates a function over `[a, b]` using the composite Simpson rule.
+///
+/// # Arguments
+///
+/// * `f` - The function to integrate.
+/// * `a` - The lower bound of the integration range.
+/// * `b` - The upper bound of the integration range.
+/// * `n` - The number of subintervals, rounded up to the next even number.
+///
+/// # Returns
+///
+/// The approximation of the integral, which is exact for polynomials up to the third degree.
+pub fn simpson_integrate(f: impl Fn(f64) -> f64, a: f64, b: f64, n: usize) -> f64 {
+    let n = (n.max(2) + 1) & !1;
+    let h = (b - a) / n as f64;
+
+    let inner: f64 = (1..n)
+        .map(|i| {
+            let weight = if i % 2 == 1 { 4.0 } else { 2.0 };
+            weight * f(a + i as f64 * h)
+        })
+        .sum();
+
+    h / 3.0 * (f(a) + inner + f(b))
+}
+
+/// Computes the quantile function (inverse CDF) of the standard normal distribution.
+///
+/// This uses the rational approximation of P. J. Acklam, whose relative error is below
+/// `1.15e-9` over the whole range.
+///
+/// # Arguments
+///
+/// * `p` - The probability, in `(0, 1)`.
+///
+/// # Returns
+///
+/// The value `x` such that `P(X <= x) = p`, or `NaN` if `p` is outside of `(0, 1)`.
+pub fn normal_quantile(p: f64) -> f64 {
+    const A: [f64; 6] = [
+        -3.969683028665376e+01,
+        2.209460984245205e+02,
+        -2.759285104469687e+02,
+        1.38357751867269e+02,
+        -3.066479806614716e+01,
+        2.506628277459239e+00,
+    ];
+    const B: [f64; 5] = [
+        -5.447609879822406e+01,
+        1.615858368580409e+02,
+        -1.556989798598866e+02,
+        6.680131188771972e+01,
+        -1.328068155288572e+01,
+    ];
+    const C: [f64; 6] = [
+        -7.784894002430293e-03,
+        -3.223964580411365e-01,
+        -2.400758277161838e+00,
+        -2.549732539343734e+00,
+        4.374664141464968e+00,
+        2.938163982698783e+00,
+    ];
+    const D: [f64; 4] = [
+        7.784695709041462e-03,
+        3.224671290700398e-01,
+        2.445134137142996e+00,
+        3.754408661907416e+00,
+    ];
+    const P_LOW: f64 = 0.02425;
+
+    let tail = |q: f64| {
+        (((((C[0] * q + C[1]) * q + C[2]) * q + C[3]) * q + C[4]) * q + C[5])
+            / ((((D[0] * q + D[1]) * q + D[2]) * q + D[3]) * q + 1.0)
+    };
+
+    if !(p > 0.0 && p < 1.0) {
+        f64::NAN
+    } else if p < P_LOW {
+        tail((-2.0 * p.ln()).sqrt())
+    } else if p > 1.0 - P_LOW {
+        -tail((-2.0 * (1.0 - p).ln()).sqrt())
+    } else {
+        let q = p - 0.5;
+        let r = q * q;
+        (((((A[0] * r + A[1]) * r + A[2]) * r + A[3]) * r + A[4]) * r + A[5]) * q
+            / (((((B[0] * r + B[1]) * r + B[2]) * r + B[3]) * r + B[4]) * r + 1.0)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_normal_quantile() {
+        assert_eq!(normal_quantile(0.5), 0.0);
+        assert!((normal_quantile(0.95) - 1.6448536269514722).abs() < 1e-8);
+        assert!((normal_quantile(0.841344746068543) - 1.0).abs() < 1e-8);
+        assert!((normal_quantile(1e-3) + 3.090232306167813).abs() < 1e-8);
+        assert!((normal_quantile(0.3) + normal_quantile(0.7)).abs() < 1e-12);
+        assert!(normal_quantile(0.0).is_nan() && normal_quantile(1.5).is_nan());
+    }
+
     #[test]
     fn test_find_interval_index() {
         let coords = vec![0.0, 1.0, 2.0, 3.0, 4.0];
@@ -89,4 +190,11 @@ mod tests {
         assert!(find_interval_index(&coords, -0.1).is_err());
         assert!(find_interval_index(&coords, 4.1).is_err());
     }
+
+    #[test]
+    fn test_simpson_integrate() {
+        let cubic = |x: f64| x.powi(3) - 2.0 * x;
+        assert!((simpson_integrate(cubic, 0.0, 2.0, 3) - 0.0).abs() < 1e-14);
+        assert!((simpson_integrate(f64::exp, 0.0, 1.0, 100) - (1f64.exp() - 1.0)).abs() < 1e-9);
+    }
 }
@@ -12,6 +12,8 @@
 //! - Random access to individual grid members without loading the entire collection into memory.
 //! - Extraction of metadata without full decompression.
 //! - Lazy iteration over grid members for memory-efficient processing of large sets.
+//! - Incremental writing of large sets, which can be resumed after an interruption.
+//! - Versioned serialization of individual subgrids.
 //!
 //! # Key Types
 //!
@@ -19,19 +21,24 @@
 //! - [`GridArrayCollection`]: Static interface for compressing and decompressing collections of grids.
 //! - [`GridArrayReader`]: Provides random access to individual grids in a compressed file.
 //! - [`LazyGridArrayIterator`]: Enables lazy, sequential iteration over grid members.
+//! - [`GridWriter`]: Writes grid members incrementally, with checkpoints on disk.
+//! - [`GridEnvelope`]: Tags serialized grid data with the version of its layout.
 //!
 //! See the documentation for each type for more details on available methods and usage patterns.
 use std::env;
-use std::fs::File;
-use std::io::{BufReader, BufWriter, Read, Write};
-use std::path::Path;
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufReader, BufWriter, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use git_version::git_version;
 use lz4_flex::frame::{FrameDecoder, FrameEncoder};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
 
 use super::gridpdf::GridArray;
 use super::metadata::MetaData;
+use super::subgrid::{ShapeError, SubGrid};
 
 const GIT_VERSION: &str = git_version!(
     args = ["--always", "--dirty", "--long", "--tags"],
@@ -40,6 +47,169 @@ const GIT_VERSION: &str = git_version!(
 );
 const CODE_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+/// Marker written in place of the legacy metadata header to flag versioned metadata.
+///
+/// The legacy V1 metadata starts with the length of the set description, which can never
+/// take this value.
+const VERSIONED_METADATA_MARKER: u64 = u64::MAX;
+
+/// Serializes the metadata into the binary representation stored in the compressed files.
+///
+/// V1 metadata keeps its historical bincode layout. Newer versions are written as the
+/// [`VERSIONED_METADATA_MARKER`], followed by the version number and the YAML representation
+/// of the metadata, such that new fields can be added without breaking older files.
+fn serialize_metadata(metadata: &MetaData) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    match metadata {
+        MetaData::V1(data) => Ok(bincode::serialize(data)?),
+        MetaData::V2(data) => {
+            let mut bytes = bincode::serialize(&VERSIONED_METADATA_MARKER)?;
+            bytes.extend(bincode::serialize(&2u32)?);
+            bytes.extend(bincode::serialize(&serde_yaml::to_string(data)?)?);
+            Ok(bytes)
+        }
+    }
+}
+
+/// Deserializes the metadata written by [`serialize_metadata`].
+fn deserialize_metadata(bytes: &[u8]) -> Result<MetaData, Box<dyn std::error::Error>> {
+    let mut cursor = std::io::Cursor::new(bytes);
+    let marker: u64 = bincode::deserialize_from(&mut cursor)?;
+
+    if marker != VERSIONED_METADATA_MARKER {
+        return Ok(MetaData::V1(bincode::deserialize(bytes)?));
+    }
+
+    let version: u32 = bincode::deserialize_from(&mut cursor)?;
+    let payload: String = bincode::deserialize_from(&mut cursor)?;
+    match version {
+        2 => Ok(MetaData::V2(serde_yaml::from_str(&payload)?)),
+        _ => Err(format!("Unsupported metadata version: {version}").into()),
+    }
+}
+
+/// The version of the layout of the subgrids written by [`write_subgrid`].
+///
+/// It must be incremented whenever the serialized representation of [`SubGrid`] changes.
+pub const SUBGRID_FORMAT_VERSION: u16 = 1;
+
+/// Errors that can occur when reading or writing a [`GridEnvelope`].
+#[derive(Debug, Error)]
+pub enum EnvelopeError {
+    /// Error indicating that the data was written with an unsupported layout.
+    #[error("Unsupported grid format version {found} (supported: {supported})")]
+    UnsupportedVersion {
+        /// The version of the data.
+        found: u16,
+        /// The version supported by this version of the code.
+        supported: u16,
+    },
+    /// Error indicating that the data could not be (de)serialized.
+    #[error("Failed to (de)serialize the grid: {0}")]
+    Serialization(#[from] bincode::Error),
+    /// Error indicating that a strictly read grid is invalid, e.g. holds a NaN.
+    #[error("Invalid grid: {0}")]
+    InvalidGrid(#[from] ShapeError),
+}
+
+/// Serialized grid data tagged with the version of its layout.
+///
+/// The version is serialized first, such that it can be checked before the data is
+/// deserialized.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GridEnvelope<T> {
+    /// The version of the layout of `data`.
+    pub format_version: u16,
+    /// The grid data.
+    pub data: T,
+}
+
+/// Writes a subgrid, tagged with [`SUBGRID_FORMAT_VERSION`].
+///
+/// # Arguments
+///
+/// * `writer` - The destination of the serialized subgrid.
+/// * `subgrid` - The subgrid to write.
+///
+/// # Returns
+///
+/// `Ok(())` on success, or an `EnvelopeError` if the serialization fails.
+pub fn write_subgrid<W: Write>(writer: W, subgrid: &SubGrid) -> Result<(), EnvelopeError> {
+    let envelope = GridEnvelope {
+        format_version: SUBGRID_FORMAT_VERSION,
+        data: subgrid,
+    };
+
+    Ok(bincode::serialize_into(writer, &envelope)?)
+}
+
+/// Reads a subgrid written by [`write_subgrid`].
+///
+/// The values of the subgrid are not checked, see [`read_subgrid_checked`].
+///
+/// # Arguments
+///
+/// * `reader` - The source of the serialized subgrid.
+///
+/// # Returns
+///
+/// The `SubGrid`, `EnvelopeError::UnsupportedVersion` if it was written with a different
+/// layout, or `EnvelopeError::Serialization` if the data is corrupted.
+pub fn read_subgrid<R: Read>(reader: R) -> Result<SubGrid, EnvelopeError> {
+    read_subgrid_checked(reader, false)
+}
+
+/// Reads a subgrid written by [`write_subgrid`], optionally validating it.
+///
+/// In strict mode, the subgrid is checked with [`SubGrid::validate`], such that a NaN or
+/// infinite value of a third-party grid is reported where it is stored rather than when it
+/// spoils an interpolation.
+///
+/// # Arguments
+///
+/// * `reader` - The source of the serialized subgrid.
+/// * `strict` - Whether to validate the knots and the values of the subgrid.
+///
+/// # Returns
+///
+/// The `SubGrid`, or the errors of [`read_subgrid`]. In strict mode, an invalid subgrid is
+/// reported as `EnvelopeError::InvalidGrid`, e.g. `ShapeError::NonFiniteValue` with the
+/// multi-index of the first non-finite value.
+pub fn read_subgrid_checked<R: Read>(
+    mut reader: R,
+    strict: bool,
+) -> Result<SubGrid, EnvelopeError> {
+    let format_version: u16 = bincode::deserialize_from(&mut reader)?;
+    if format_version != SUBGRID_FORMAT_VERSION {
+        return Err(EnvelopeError::UnsupportedVersion {
+            found: format_version,
+            supported: SUBGRID_FORMAT_VERSION,
+        });
+    }
+
+    let subgrid: SubGrid = bincode::deserialize_from(reader)?;
+    if strict {
+        subgrid.validate()?;
+    }
+
+    Ok(subgrid)
+}
+
+/// Serializes the metadata, stamped with the versions of the code writing the file.
+///
+/// The metadata without V2-only entries is written in the V1 layout, such that the file can
+/// still be read by the versions of NeoPDF predating the versioned metadata.
+fn serialize_stamped_metadata(metadata: &MetaData) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    let mut metadata_mut = metadata.as_latest();
+    metadata_mut.git_version = GIT_VERSION.to_string();
+    metadata_mut.code_version = CODE_VERSION.to_string();
+
+    let stamped = MetaData::new_v2(metadata_mut);
+    match stamped.try_as_v1() {
+        Ok(data) => serialize_metadata(&MetaData::new_v1(data)),
+        Err(_) => serialize_metadata(&stamped),
+    }
+}
+
 /// Container for a [`GridArray`] with a shared reference to its associated metadata.
 ///
 /// Used to bundle grid data and metadata together for convenient access after decompression
@@ -71,41 +241,46 @@ impl GridArrayCollection {
         grids: &[&GridArray],
         metadata: &MetaData,
         path: P,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let metadata_serialized = serialize_stamped_metadata(metadata)?;
+
+        // Serialize all grids first
+        let mut serialized_grids = Vec::new();
+        for grid in grids {
+            let serialized = bincode::serialize(grid)?;
+            serialized_grids.push(serialized);
+        }
+
+        Self::write_serialized(&metadata_serialized, &serialized_grids, path)
+    }
+
+    /// Writes the serialized metadata and grids to a compressed file.
+    fn write_serialized<P: AsRef<Path>>(
+        metadata_serialized: &[u8],
+        serialized_grids: &[Vec<u8>],
+        path: P,
     ) -> Result<(), Box<dyn std::error::Error>> {
         let file = File::create(path)?;
         let buf_writer = BufWriter::new(file);
         let mut encoder = FrameEncoder::new(buf_writer);
 
-        let mut metadata_mut = metadata.as_latest();
-        metadata_mut.git_version = GIT_VERSION.to_string();
-        metadata_mut.code_version = CODE_VERSION.to_string();
-
-        let updated_metadata = MetaData::new_v1(metadata_mut);
-        let metadata_serialized = bincode::serialize(&updated_metadata)?;
         let metadata_size = metadata_serialized.len() as u64;
 
         let metadata_size_bytes = bincode::serialize(&metadata_size)?;
         encoder.write_all(&metadata_size_bytes)?;
-        encoder.write_all(&metadata_serialized)?;
+        encoder.write_all(metadata_serialized)?;
 
         // Write number of grids
-        let count = grids.len() as u64;
+        let count = serialized_grids.len() as u64;
         let count_bytes = bincode::serialize(&count)?;
         encoder.write_all(&count_bytes)?;
 
-        // Serialize all grids first
-        let mut serialized_grids = Vec::new();
-        for grid in grids {
-            let serialized = bincode::serialize(grid)?;
-            serialized_grids.push(serialized);
-        }
-
         // Calculate offsets relative to start of data section
         let mut offsets = Vec::new();
         let mut current_offset = 0u64;
 
         // Each grid entry has: 8 bytes for size + data
-        for serialized in &serialized_grids {
+        for serialized in serialized_grids {
             offsets.push(current_offset);
             current_offset += 8; // size field
             current_offset += serialized.len() as u64;
@@ -122,7 +297,7 @@ impl GridArrayCollection {
         }
 
         // Write grid data
-        for serialized in &serialized_grids {
+        for serialized in serialized_grids {
             let size = serialized.len() as u64;
             let size_bytes = bincode::serialize(&size)?;
             encoder.write_all(&size_bytes)?;
@@ -160,7 +335,7 @@ impl GridArrayCollection {
         cursor.read_exact(&mut metadata_bytes)?;
 
         // Deserialize versioned metadata and convert to latest
-        let versioned_metadata: MetaData = bincode::deserialize(&metadata_bytes)?;
+        let versioned_metadata = deserialize_metadata(&metadata_bytes)?;
         let shared_metadata = Arc::new(versioned_metadata);
         let count: u64 = bincode::deserialize_from(&mut cursor)?;
 
@@ -215,7 +390,7 @@ impl GridArrayCollection {
         let metadata_size: u64 = bincode::deserialize_from(&mut cursor)?;
         let mut metadata_bytes = vec![0u8; metadata_size as usize];
         cursor.read_exact(&mut metadata_bytes)?;
-        let metadata: MetaData = bincode::deserialize(&metadata_bytes)?;
+        let metadata = deserialize_metadata(&metadata_bytes)?;
 
         Ok(metadata)
     }
@@ -256,7 +431,7 @@ impl GridArrayReader {
         let metadata_size: u64 = bincode::deserialize_from(&mut cursor)?;
         let mut metadata_bytes = vec![0u8; metadata_size as usize];
         cursor.read_exact(&mut metadata_bytes)?;
-        let metadata: MetaData = bincode::deserialize(&metadata_bytes)?;
+        let metadata = deserialize_metadata(&metadata_bytes)?;
         let shared_metadata = Arc::new(metadata);
         let count: u64 = bincode::deserialize_from(&mut cursor)?;
 
@@ -365,7 +540,7 @@ impl LazyGridArrayIterator {
         let metadata_size: u64 = bincode::deserialize_from(&mut cursor)?;
         let mut metadata_bytes = vec![0u8; metadata_size as usize];
         cursor.read_exact(&mut metadata_bytes)?;
-        let metadata: MetaData = bincode::deserialize(&metadata_bytes)?;
+        let metadata = deserialize_metadata(&metadata_bytes)?;
         let shared_metadata = Arc::new(metadata);
 
         let count: u64 = bincode::deserialize_from(&mut cursor)?;
@@ -440,13 +615,172 @@ impl Iterator for LazyGridArrayIterator {
 
 impl ExactSizeIterator for LazyGridArrayIterator {}
 
+/// Writes the members of a set incrementally, such that an interrupted write can be resumed.
+///
+/// Each member passed to [`GridWriter::push`] is immediately appended to a checkpoint file,
+/// named after the output file with an additional `.partial` extension, and flushed to disk.
+/// The checkpoint stores the serialized metadata followed by the members, each prefixed with
+/// its size. The compressed file, with its header and offset table, is only written by
+/// [`GridWriter::finish`], which then removes the checkpoint.
+///
+/// After a crash, [`GridWriter::resume`] recovers all the members that were completely
+/// flushed, discarding a possibly truncated last member, and continues from there.
+pub struct GridWriter {
+    path: PathBuf,
+    checkpoint: File,
+    count: usize,
+}
+
+impl GridWriter {
+    /// Starts writing a new set, overwriting any previous checkpoint.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Output file path.
+    /// * `metadata` - Shared metadata for all grids.
+    ///
+    /// # Returns
+    ///
+    /// A [`GridWriter`] instance on success, or an error if the checkpoint cannot be created.
+    pub fn create<P: AsRef<Path>>(
+        path: P,
+        metadata: &MetaData,
+    ) -> Result<Self, Box<dyn std::error::Error>> {
+        let path = path.as_ref().to_path_buf();
+        let mut checkpoint = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(Self::checkpoint_path(&path))?;
+        write_record(&mut checkpoint, &serialize_stamped_metadata(metadata)?)?;
+        checkpoint.sync_data()?;
+
+        Ok(Self {
+            path,
+            checkpoint,
+            count: 0,
+        })
+    }
+
+    /// Resumes the writing of a set from its checkpoint.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Output file path, as passed to [`GridWriter::create`].
+    ///
+    /// # Returns
+    ///
+    /// A [`GridWriter`] instance containing all the members flushed before the interruption,
+    /// or an error if the checkpoint cannot be read.
+    pub fn resume<P: AsRef<Path>>(path: P) -> Result<Self, Box<dyn std::error::Error>> {
+        let path = path.as_ref().to_path_buf();
+        let mut checkpoint = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .open(Self::checkpoint_path(&path))?;
+        let records = read_records(&mut checkpoint)?;
+        if records.is_empty() {
+            return Err("The checkpoint does not contain the metadata".into());
+        }
+
+        // Drop a possibly truncated last member
+        let valid_len = records.iter().map(|record| 8 + record.len() as u64).sum();
+        checkpoint.set_len(valid_len)?;
+        checkpoint.seek(SeekFrom::End(0))?;
+
+        Ok(Self {
+            path,
+            checkpoint,
+            count: records.len() - 1,
+        })
+    }
+
+    /// Appends a member to the set and flushes it to disk.
+    ///
+    /// # Arguments
+    ///
+    /// * `grid` - The grid of the next member.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` on success, or an error if writing fails.
+    pub fn push(&mut self, grid: &GridArray) -> Result<(), Box<dyn std::error::Error>> {
+        write_record(&mut self.checkpoint, &bincode::serialize(grid)?)?;
+        self.checkpoint.sync_data()?;
+        self.count += 1;
+
+        Ok(())
+    }
+
+    /// Returns the number of members flushed so far.
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    /// Returns true if no member has been flushed yet.
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Writes the compressed file from the flushed members and removes the checkpoint.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` on success, or an error if writing fails.
+    pub fn finish(mut self) -> Result<(), Box<dyn std::error::Error>> {
+        let mut records = read_records(&mut self.checkpoint)?;
+        let metadata_serialized = records.remove(0);
+
+        GridArrayCollection::write_serialized(&metadata_serialized, &records, &self.path)?;
+        fs::remove_file(Self::checkpoint_path(&self.path))?;
+
+        Ok(())
+    }
+
+    /// Returns the path of the checkpoint file associated with `path`.
+    fn checkpoint_path(path: &Path) -> PathBuf {
+        let mut checkpoint = path.as_os_str().to_owned();
+        checkpoint.push(".partial");
+        PathBuf::from(checkpoint)
+    }
+}
+
+/// Writes a record, prefixed with its size, to a checkpoint file.
+fn write_record(file: &mut File, bytes: &[u8]) -> Result<(), Box<dyn std::error::Error>> {
+    file.write_all(&bincode::serialize(&(bytes.len() as u64))?)?;
+    file.write_all(bytes)?;
+
+    Ok(())
+}
+
+/// Reads all the complete records of a checkpoint file, ignoring a truncated last record.
+fn read_records(file: &mut File) -> Result<Vec<Vec<u8>>, Box<dyn std::error::Error>> {
+    let mut data = Vec::new();
+    file.seek(SeekFrom::Start(0))?;
+    file.read_to_end(&mut data)?;
+
+    let mut records = Vec::new();
+    let mut rest = data.as_slice();
+    while rest.len() >= 8 {
+        let size: u64 = bincode::deserialize(&rest[..8])?;
+        let Some(record) = rest.get(8..8 + size as usize) else {
+            break;
+        };
+        records.push(record.to_vec());
+        rest = &rest[8 + size as usize..];
+    }
+
+    Ok(records)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use ndarray::Array1;
     use tempfile::NamedTempFile;
 
-    use crate::metadata::{InterpolatorType, MetaDataV1, SetType};
+    use crate::metadata::{InterpolatorType, MetaDataV1, MetaDataV2, SetType};
 
     #[test]
     fn test_collection_with_metadata() {
@@ -507,6 +841,163 @@ mod tests {
         assert_eq!(g_iter.count(), 2);
     }
 
+    #[test]
+    fn test_subgrid_envelope() {
+        let subgrid = SubGrid::new(
+            vec![0.0],
+            vec![0.0],
+            vec![0.0],
+            vec![1e-3, 1e-2, 1e-1, 1.0],
+            vec![1.0, 10.0, 100.0],
+            2,
+            (0..24).map(f64::from).collect(),
+        );
+
+        let mut bytes = Vec::new();
+        write_subgrid(&mut bytes, &subgrid).unwrap();
+        assert_eq!(read_subgrid(bytes.as_slice()).unwrap(), subgrid);
+
+        let future = GridEnvelope {
+            format_version: SUBGRID_FORMAT_VERSION + 1,
+            data: "a layout that does not exist yet",
+        };
+        let bytes = bincode::serialize(&future).unwrap();
+        assert!(matches!(
+            read_subgrid(bytes.as_slice()),
+            Err(EnvelopeError::UnsupportedVersion {
+                found,
+                supported: SUBGRID_FORMAT_VERSION
+            }) if found == SUBGRID_FORMAT_VERSION + 1
+        ));
+
+        assert!(matches!(
+            read_subgrid(&bytes[..1]),
+            Err(EnvelopeError::Serialization(_))
+        ));
+    }
+
+    #[test]
+    fn test_read_subgrid_strict() {
+        let mut subgrid = SubGrid::new(
+            vec![0.0],
+            vec![0.0],
+            vec![0.0],
+            vec![1e-3, 1e-2, 1e-1, 1.0],
+            vec![1.0, 10.0, 100.0],
+            2,
+            (0..24).map(f64::from).collect(),
+        );
+        subgrid.grid[[0, 0, 1, 0, 2, 1]] = f64::NAN;
+
+        let mut file = NamedTempFile::new().unwrap();
+        write_subgrid(&mut file, &subgrid).unwrap();
+        let open = || BufReader::new(File::open(file.path()).unwrap());
+
+        let lenient = read_subgrid_checked(open(), false).unwrap();
+        assert!(lenient.grid[[0, 0, 1, 0, 2, 1]].is_nan());
+        assert!(read_subgrid(open()).is_ok());
+
+        let error = read_subgrid_checked(open(), true).unwrap_err();
+        assert!(matches!(
+            error,
+            EnvelopeError::InvalidGrid(ShapeError::NonFiniteValue {
+                index: [0, 0, 1, 0, 2, 1]
+            })
+        ));
+        assert_eq!(
+            error.to_string(),
+            "Invalid grid: The grid value at [0, 0, 1, 0, 2, 1] is not finite"
+        );
+
+        subgrid.grid[[0, 0, 1, 0, 2, 1]] = 0.0;
+        let mut bytes = Vec::new();
+        write_subgrid(&mut bytes, &subgrid).unwrap();
+        assert_eq!(
+            read_subgrid_checked(bytes.as_slice(), true).unwrap(),
+            subgrid
+        );
+    }
+
+    #[test]
+    fn test_versioned_metadata() {
+        let metadata_v1 = MetaDataV1 {
+            set_desc: "Test PDF".into(),
+            ..Default::default()
+        };
+        let legacy = MetaData::new_v1(metadata_v1.clone());
+        let bytes = serialize_metadata(&legacy).unwrap();
+        assert_eq!(bytes, bincode::serialize(&metadata_v1).unwrap());
+        assert!(matches!(deserialize_metadata(&bytes), Ok(MetaData::V1(_))));
+
+        let metadata_v2 = MetaData::new_v2(MetaDataV2 {
+            base: metadata_v1,
+            alphas_mz: Some(0.118),
+            ..Default::default()
+        });
+        let bytes = serialize_metadata(&metadata_v2).unwrap();
+        let extracted = deserialize_metadata(&bytes).unwrap();
+        assert_eq!(extracted.set_desc, "Test PDF");
+        assert_eq!(extracted.alphas_mz(), Some(0.118));
+        assert_eq!(extracted.alphas_mass_reference(), None);
+
+        // The stamped metadata keeps the V1 layout unless it needs the V2 entries
+        let stamped = deserialize_metadata(&serialize_stamped_metadata(&legacy).unwrap());
+        assert!(matches!(stamped, Ok(MetaData::V1(data)) if data.code_version == CODE_VERSION));
+        let stamped = deserialize_metadata(&serialize_stamped_metadata(&metadata_v2).unwrap());
+        assert!(matches!(stamped, Ok(MetaData::V2(data)) if data.alphas_mz == Some(0.118)));
+    }
+
+    #[test]
+    fn test_grid_writer_resume() {
+        let metadata = MetaData::new_v1(MetaDataV1 {
+            set_desc: "Test PDF".into(),
+            num_members: 3,
+            ..Default::default()
+        });
+        let grids: Vec<GridArray> = (1..=3)
+            .map(|pid| GridArray {
+                pids: Array1::from(vec![pid]),
+                subgrids: vec![],
+            })
+            .collect();
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("set.neopdf.lz4");
+
+        let mut writer = GridWriter::create(&path, &metadata).unwrap();
+        writer.push(&grids[0]).unwrap();
+        writer.push(&grids[1]).unwrap();
+        // Simulate a crash in the middle of writing the third member
+        writer.checkpoint.write_all(&[42, 0, 0]).unwrap();
+        drop(writer);
+        assert!(!path.exists());
+
+        let mut writer = GridWriter::resume(&path).unwrap();
+        assert_eq!(writer.len(), 2);
+        writer.push(&grids[2]).unwrap();
+        writer.finish().unwrap();
+
+        let mut uninterrupted =
+            GridWriter::create(dir.path().join("twin.neopdf.lz4"), &metadata).unwrap();
+        for grid in &grids {
+            uninterrupted.push(grid).unwrap();
+        }
+        uninterrupted.finish().unwrap();
+        assert_eq!(
+            GridArrayReader::from_file(dir.path().join("twin.neopdf.lz4"))
+                .unwrap()
+                .len(),
+            3
+        );
+        assert!(!GridWriter::checkpoint_path(&path).exists());
+
+        let reader = GridArrayReader::from_file(&path).unwrap();
+        assert_eq!(reader.len(), 3);
+        assert_eq!(reader.metadata().set_desc, "Test PDF");
+        for (idx, grid) in grids.iter().enumerate() {
+            assert_eq!(reader.load_grid(idx).unwrap().grid.pids, grid.pids);
+        }
+    }
+
     fn test_grid() -> GridArray {
         GridArray {
             pids: Array1::from(vec![1, 2, 3]),
@@ -275,6 +275,9 @@ pub unsafe extern "C" fn neopdf_pdf_q2_max(pdf: *mut NeoPDFWrapper) -> f64 {
 
 /// Interpolates the PDF value (xf) for a given flavor, x, and Q2.
 ///
+/// Returns `NaN` if the interpolation fails, e.g. for a query outside of the grid coverage
+/// with `ExtrapolationPolicy::Error`.
+///
 /// # Panics
 ///
 /// This function will panic if the `pdf` pointer is null.
@@ -291,11 +294,14 @@ pub unsafe extern "C" fn neopdf_pdf_xfxq2(
 ) -> f64 {
     assert!(!pdf.is_null());
     let pdf_obj = unsafe { &(*pdf).0 };
-    pdf_obj.xfxq2(id, &[x, q2])
+    pdf_obj.try_xfxq2(id, &[x, q2]).unwrap_or(f64::NAN)
 }
 
 /// Interpolates the PDF value (xf) for a generic set of parameters.
 ///
+/// Returns `NaN` if the interpolation fails, e.g. for a query outside of the grid coverage
+/// with `ExtrapolationPolicy::Error`.
+///
 /// # Panics
 ///
 /// This function will panic if the `pdf` pointer is null.
@@ -314,7 +320,7 @@ pub unsafe extern "C" fn neopdf_pdf_xfxq2_nd(
     let pdf_obj = unsafe { &(*pdf).0 };
     let params = unsafe { slice::from_raw_parts(params, num_params) };
 
-    pdf_obj.xfxq2(id, params)
+    pdf_obj.try_xfxq2(id, params).unwrap_or(f64::NAN)
 }
 
 /// Interpolates PDF values for multiple points in parallel using Chebyshev batch interpolation.
@@ -1227,6 +1233,8 @@ pub unsafe extern "C" fn initpdf_(member: *const c_int) {
 
 /// Evaluates parton distribution functions at given `(x, q)` for the active member.
 ///
+/// The flavors whose interpolation fails are set to `NaN`.
+///
 /// # Safety
 ///
 /// - `f` must point to writable memory for at least 13 `c_double` values.
@@ -1252,7 +1260,7 @@ pub unsafe extern "C" fn evolvepdf(x: c_double, q: c_double, f: *mut c_double) {
 
             for (out, &pid) in out_slice.iter_mut().zip(DEFAULT_PIDS.iter()) {
                 *out = if available_pids.contains(&pid) {
-                    pdf.xfxq2(pid, &[x, q2])
+                    pdf.try_xfxq2(pid, &[x, q2]).unwrap_or(f64::NAN)
                 } else {
                     0.0
                 };
@@ -1287,7 +1295,7 @@ pub unsafe extern "C" fn evolvepdf_(x: *const c_double, q: *const c_double, f: *
 
             for (out, &pid) in out_slice.iter_mut().zip(DEFAULT_PIDS.iter()) {
                 *out = if available_pids.contains(&pid) {
-                    pdf.xfxq2(pid, &[*x, q2])
+                    pdf.try_xfxq2(pid, &[*x, q2]).unwrap_or(f64::NAN)
                 } else {
                     0.0
                 };
@@ -3,6 +3,7 @@ use pyo3::prelude::*;
 use std::sync::Mutex;
 
 use neopdf::gridpdf::ForcePositive;
+use neopdf::metadata::InterpolatorType;
 use neopdf::pdf::PDF;
 
 use super::gridpdf::PySubGrid;
@@ -157,17 +158,34 @@ impl PyPDF {
     ///     The name of the PDF set.
     /// member : int
     ///     The ID of the PDF member. Defaults to 0.
+    /// interpolator : str, optional
+    ///     The name of the interpolator (e.g. "logbicubic") overriding the
+    ///     one defined in the metadata of the set.
     ///
     /// Returns
     /// -------
     /// PDF
     ///     A new `PDF` instance.
-    #[must_use]
+    ///
+    /// # Errors
+    ///
+    /// Returns a `PyValueError` if the interpolator is unknown or not compatible with the
+    /// dimensions of the grids.
     #[staticmethod]
     #[pyo3(name = "mkPDF")]
-    #[pyo3(signature = (pdf_name, member = 0))]
-    pub fn mkpdf(pdf_name: &str, member: usize) -> Self {
-        Self::new(pdf_name, member)
+    #[pyo3(signature = (pdf_name, member = 0, interpolator = None))]
+    pub fn mkpdf(pdf_name: &str, member: usize, interpolator: Option<&str>) -> PyResult<Self> {
+        let Some(interpolator) = interpolator else {
+            return Ok(Self::new(pdf_name, member));
+        };
+
+        let interpolator_type = interpolator
+            .parse::<InterpolatorType>()
+            .map_err(pyo3::exceptions::PyValueError::new_err)?;
+        let pdf = PDF::load_with_interpolator(pdf_name, member, interpolator_type)
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+
+        Ok(Self { pdf })
     }
 
     /// Loads all members of the PDF set.
@@ -381,12 +399,12 @@ impl PyPDF {
     /// Returns
     /// -------
     /// float
-    ///     The interpolated PDF value. Returns 0.0 if extrapolation is
-    ///     attempted and not allowed.
+    ///     The interpolated PDF value. Returns NaN if the interpolation
+    ///     fails, e.g. outside of the grid with the `Error` extrapolation.
     #[must_use]
     #[pyo3(name = "xfxQ2")]
     pub fn xfxq2(&self, id: i32, x: f64, q2: f64) -> f64 {
-        self.pdf.xfxq2(id, &[x, q2])
+        self.pdf.try_xfxq2(id, &[x, q2]).unwrap_or(f64::NAN)
     }
 
     /// Interpolates the PDF value (xf) for a given set of parameters.
@@ -405,13 +423,13 @@ impl PyPDF {
     /// Returns
     /// -------
     /// float
-    ///     The interpolated PDF value. Returns 0.0 if extrapolation is
-    ///     attempted and not allowed.
+    ///     The interpolated PDF value. Returns NaN if the interpolation
+    ///     fails, e.g. outside of the grid with the `Error` extrapolation.
     #[must_use]
     #[pyo3(name = "xfxQ2_ND")]
     #[allow(clippy::needless_pass_by_value)]
     pub fn xfxq2_nd(&self, id: i32, params: Vec<f64>) -> f64 {
-        self.pdf.xfxq2(id, &params)
+        self.pdf.try_xfxq2(id, &params).unwrap_or(f64::NAN)
     }
 
     /// Interpolates the PDF value (xf) for a list containg a set of parameters.
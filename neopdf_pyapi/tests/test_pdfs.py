@@ -100,6 +100,25 @@ class TestAlphaSInterpolations:
                 np.testing.assert_equal(res, ref)
 
 
+class TestInterpolatorOverride:
+    @pytest.mark.parametrize("pdfname", ["NNPDF40_nnlo_as_01180"])
+    def test_mkpdf_interpolator(self, neo_pdf, pdfname):
+        neopdf = neo_pdf(pdfname)
+        bicubic = NeoPDF.mkPDF(pdfname, 0, interpolator="logbicubic")
+        bilinear = NeoPDF.mkPDF(pdfname, 0, interpolator="LogBilinear")
+
+        xs, q2s = np.geomspace(1e-4, 0.5, num=20), np.geomspace(4.0, 1e4, num=20)
+        for x, q2 in product(xs, q2s):
+            ref = neopdf.xfxQ2(21, x, q2)
+            np.testing.assert_equal(bicubic.xfxQ2(21, x, q2), ref)
+            np.testing.assert_allclose(bilinear.xfxQ2(21, x, q2), ref, rtol=5e-2)
+
+    @pytest.mark.parametrize("interpolator", ["logquartic", "ndlinear"])
+    def test_mkpdf_invalid_interpolator(self, interpolator):
+        with pytest.raises(ValueError):
+            NeoPDF.mkPDF("NNPDF40_nnlo_as_01180", 0, interpolator=interpolator)
+
+
 class TestLazyLoader:
     @pytest.mark.parametrize("pdfname", ["NNPDF40_nnlo_as_01180.neopdf.lz4"])
     def test_lazy_loader(self, neo_pdfs_lazy, pdfname):